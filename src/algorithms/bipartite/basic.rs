@@ -0,0 +1,199 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a two-coloring of a bipartite graph: a color, 0 or 1, for each node such that the two
+nodes of every edge have different colors. In each connected component the first node of the
+graph gets the color 1; isolated nodes get the color 0. For a directed graph the direction of
+the edges is ignored.
+
+An `ErrorKind::InvalidArgument` error is returned if the graph isn't bipartite.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new("a", "p1"), Edge::new("b", "p1"), Edge::new("b", "p2")];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let colors = bipartite::color(&graph).unwrap();
+assert_eq!(colors[&"a"], colors[&"b"]);
+assert_ne!(colors[&"a"], colors[&"p1"]);
+```
+*/
+pub fn color<T, A>(graph: &Graph<T, A>) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (colors, _) = get_colors(graph).ok_or_else(get_not_bipartite_error)?;
+    Ok(colors
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), c))
+        .collect())
+}
+
+/**
+Returns `true` if the graph is bipartite: its nodes can be split into two sets such that every
+edge joins a node in one set to a node in the other. For a directed graph the direction of the
+edges is ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, generators};
+let graph = generators::social::karate_club_graph();
+assert!(!bipartite::is_bipartite(&graph));
+let graph = generators::classic::complete_graph(2, false);
+assert!(bipartite::is_bipartite(&graph));
+```
+*/
+pub fn is_bipartite<T, A>(graph: &Graph<T, A>) -> bool
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_colors(graph).is_some()
+}
+
+/**
+Returns the two sets of nodes of a bipartite graph, as `(top, bottom)`.
+
+If `top_nodes` is given, the sets are `top_nodes` and the other nodes. Otherwise the sets are
+found with a [two-coloring](./fn.color.html): the nodes colored 1 are the top nodes. The
+coloring of a disconnected graph is ambiguous, so `top_nodes` must be given for a disconnected
+graph.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be bipartite
+* `top_nodes`: the nodes of one of the sets; optional, but needed if `graph` is disconnected
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new("a", "p1"), Edge::new("b", "p1"), Edge::new("b", "p2")];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let (top, bottom) = bipartite::sets(&graph, None).unwrap();
+assert_eq!(top, vec!["a", "b"].into_iter().collect());
+assert_eq!(bottom, vec!["p1", "p2"].into_iter().collect());
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn sets<T, A>(
+    graph: &Graph<T, A>,
+    top_nodes: Option<&HashSet<T>>,
+) -> Result<(HashSet<T>, HashSet<T>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let is_top = get_sides(graph, top_nodes)?;
+    let mut top = HashSet::new();
+    let mut bottom = HashSet::new();
+    for (i, is_top) in is_top.into_iter().enumerate() {
+        let name = graph.get_node_by_index(&i).unwrap().name.clone();
+        match is_top {
+            true => top.insert(name),
+            false => bottom.insert(name),
+        };
+    }
+    Ok((top, bottom))
+}
+
+/**
+Returns whether each node is a top node: in `top_nodes` if given, otherwise colored 1 in a
+two-coloring of the graph, which must then be connected.
+*/
+pub(crate) fn get_sides<T, A>(
+    graph: &Graph<T, A>,
+    top_nodes: Option<&HashSet<T>>,
+) -> Result<Vec<bool>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    match top_nodes {
+        Some(top_nodes) => {
+            let mut is_top = vec![false; graph.number_of_nodes()];
+            for node in top_nodes {
+                is_top[graph.get_node_index(node)?] = true;
+            }
+            Ok(is_top)
+        }
+        None => {
+            let (colors, number_of_components) =
+                get_colors(graph).ok_or_else(get_not_bipartite_error)?;
+            if number_of_components > 1 {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: "The graph is disconnected, so the bipartite sets are ambiguous; \
+                              top_nodes must be specified."
+                        .to_string(),
+                });
+            }
+            Ok(colors.into_iter().map(|c| c == 1).collect())
+        }
+    }
+}
+
+/**
+Colors the graph with a breadth-first search of each component. Returns the colors and the
+number of connected components, or `None` if the graph isn't bipartite.
+*/
+fn get_colors<T, A>(graph: &Graph<T, A>) -> Option<(Vec<usize>, usize)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut colors: Vec<Option<usize>> = vec![None; n];
+    let mut number_of_components = 0;
+    for start in 0..n {
+        if colors[start].is_some() {
+            continue;
+        }
+        number_of_components += 1;
+        let neighbors = graph.get_neighbors_nodes_by_index(&start);
+        if neighbors.is_empty() {
+            colors[start] = Some(0);
+            continue;
+        }
+        colors[start] = Some(1);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            let color = colors[u].unwrap();
+            for v in graph.get_neighbors_nodes_by_index(&u) {
+                match colors[v] {
+                    None => {
+                        colors[v] = Some(1 - color);
+                        queue.push_back(v);
+                    }
+                    Some(c) if c == color => return None,
+                    _ => {}
+                }
+            }
+        }
+    }
+    Some((colors.into_iter().flatten().collect(), number_of_components))
+}
+
+fn get_not_bipartite_error() -> Error {
+    Error {
+        kind: ErrorKind::InvalidArgument,
+        message: "The graph is not bipartite.".to_string(),
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the degree centrality of each node of a bipartite graph: its degree divided by the
+number of nodes in the other set, which is the largest degree it could have in a simple
+bipartite graph.
+
+# Arguments
+
+* `graph`: a bipartite [Graph](../../struct.Graph.html) instance; for a directed graph the
+  degree is the sum of the in-degree and the out-degree
+* `nodes`: the nodes of one of the sets; the other set is the rest of the graph's nodes. An
+  `ErrorKind::InvalidArgument` error is returned if either set is empty.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("a", "p1"),
+    Edge::new("a", "p2"),
+    Edge::new("a", "p3"),
+    Edge::new("b", "p3"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let top = vec!["a", "b"].into_iter().collect();
+let centralities = bipartite::degree_centrality(&graph, &top).unwrap();
+assert_eq!(centralities[&"a"], 1.0);
+assert_eq!(centralities[&"b"], 1.0 / 3.0);
+assert_eq!(centralities[&"p3"], 1.0);
+```
+
+# References
+
+1. S. P. Borgatti and D. S. Halgin: Analyzing affiliation networks. In: The Sage Handbook of
+   Social Network Analysis, 2011.
+*/
+pub fn degree_centrality<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &HashSet<T>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut is_top = vec![false; n];
+    for node in nodes {
+        is_top[graph.get_node_index(node)?] = true;
+    }
+    let number_of_top = is_top.iter().filter(|t| **t).count();
+    if number_of_top == 0 || number_of_top == n {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Both sets of nodes must be non-empty.".to_string(),
+        });
+    }
+    let top_scale = 1.0 / (n - number_of_top) as f64;
+    let bottom_scale = 1.0 / number_of_top as f64;
+    Ok((0..n)
+        .map(|u| {
+            let scale = match is_top[u] {
+                true => top_scale,
+                false => bottom_scale,
+            };
+            (
+                graph.get_node_by_index(&u).unwrap().name.clone(),
+                graph.get_node_degree_by_index(u) as f64 * scale,
+            )
+        })
+        .collect())
+}
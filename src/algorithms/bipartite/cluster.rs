@@ -0,0 +1,169 @@
+use super::basic::is_bipartite;
+use crate::{Error, ErrorKind, Graph};
+use nohash::IntSet;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+How the overlap of the neighborhoods of two nodes is measured by the bipartite
+[clustering](./fn.clustering.html) coefficient. `|N(u)|` is the number of neighbors of `u`.
+
+`Dot`: `|N(u) ∩ N(v)| / |N(u) ∪ N(v)|`
+
+`Min`: `|N(u) ∩ N(v)| / min(|N(u)|, |N(v)|)`
+
+`Max`: `|N(u) ∩ N(v)| / max(|N(u)|, |N(v)|)`
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClusteringMode {
+    Dot,
+    Min,
+    Max,
+}
+
+/**
+Returns the bipartite clustering coefficient of nodes of a bipartite graph, as defined by
+Latapy et al. A bipartite graph has no triangles, so instead the clustering of a node `u` is the
+average overlap of its neighborhood with the neighborhoods of the nodes `v` that are two steps
+away from it. The clustering of a node with no such nodes is zero.
+
+# Arguments
+
+* `graph`: an undirected bipartite [Graph](../../struct.Graph.html) instance; an
+  `ErrorKind::InvalidArgument` error is returned if it isn't bipartite
+* `node_names`: node names that optionally define a subset of the nodes to compute the
+  clustering of
+* `mode`: how the overlap of two neighborhoods is measured
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("a", "p1"),
+    Edge::new("a", "p2"),
+    Edge::new("b", "p2"),
+    Edge::new("b", "p3"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = bipartite::clustering(&graph, None, bipartite::ClusteringMode::Dot).unwrap();
+// "a" and "b" share one of three neighbors
+assert_eq!(result[&"a"], 1.0 / 3.0);
+let result = bipartite::clustering(&graph, None, bipartite::ClusteringMode::Min).unwrap();
+assert_eq!(result[&"a"], 0.5);
+```
+
+# References
+
+1. M. Latapy, C. Magnien and N. Del Vecchio: Basic notions for the analysis of large two-mode
+   networks. Social Networks 30(1):31–48, 2008.
+*/
+pub fn clustering<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    mode: ClusteringMode,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if !is_bipartite(graph) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not bipartite.".to_string(),
+        });
+    }
+    let nodes = match node_names {
+        Some(node_names) => node_names
+            .iter()
+            .map(|name| graph.get_node_index(name))
+            .collect::<Result<Vec<usize>, Error>>()?,
+        None => (0..graph.number_of_nodes()).collect(),
+    };
+    let neighbors = |u: usize| -> IntSet<usize> {
+        graph
+            .get_successor_nodes_by_index(&u)
+            .iter()
+            .map(|adj| adj.node_index)
+            .collect()
+    };
+    Ok(nodes
+        .into_iter()
+        .map(|u| {
+            let u_neighbors = neighbors(u);
+            let second_neighbors: IntSet<usize> = u_neighbors
+                .iter()
+                .flat_map(|w| neighbors(*w))
+                .filter(|v| *v != u)
+                .collect();
+            let total: f64 = second_neighbors
+                .iter()
+                .map(|v| {
+                    let v_neighbors = neighbors(*v);
+                    let shared = u_neighbors.intersection(&v_neighbors).count() as f64;
+                    let (a, b) = (u_neighbors.len(), v_neighbors.len());
+                    let divisor = match mode {
+                        ClusteringMode::Dot => a + b - shared as usize,
+                        ClusteringMode::Min => a.min(b),
+                        ClusteringMode::Max => a.max(b),
+                    };
+                    shared / divisor as f64
+                })
+                .sum();
+            let coefficient = match second_neighbors.is_empty() {
+                true => 0.0,
+                false => total / second_neighbors.len() as f64,
+            };
+            (
+                graph.get_node_by_index(&u).unwrap().name.clone(),
+                coefficient,
+            )
+        })
+        .collect())
+}
+
+/**
+Returns the average bipartite [clustering](./fn.clustering.html) coefficient of nodes of a
+bipartite graph.
+
+# Arguments
+
+* `graph`: an undirected bipartite [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the nodes to average over, such as
+  one of the sets of the graph; an `InvalidArgument` error is returned if there are no nodes to
+  average over
+* `mode`: how the overlap of two neighborhoods is measured
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new("a", "p1"), Edge::new("a", "p2"), Edge::new("b", "p2")];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let mode = bipartite::ClusteringMode::Max;
+let result = bipartite::average_clustering(&graph, Some(&["a", "b"]), mode).unwrap();
+assert_eq!(result, 0.5);
+```
+*/
+pub fn average_clustering<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    mode: ClusteringMode,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let coefficients = clustering(graph, node_names, mode)?;
+    if coefficients.is_empty() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "There are no nodes to average the clustering over.".to_string(),
+        });
+    }
+    Ok(coefficients.values().sum::<f64>() / coefficients.len() as f64)
+}
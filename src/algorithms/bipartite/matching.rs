@@ -0,0 +1,375 @@
+use super::basic::get_sides;
+use crate::algorithms::centrality::fringe_node::{push_fringe_node, FringeNode};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a maximum cardinality matching of a bipartite graph, found with the Hopcroft-Karp
+algorithm in O(m √n) time. For a directed graph the direction of the edges is ignored.
+
+# Arguments
+
+* `graph`: a bipartite [Graph](../../struct.Graph.html) instance
+* `top_nodes`: the nodes of one side of the graph; optional, but needed if `graph` is
+  disconnected. See [sets](./fn.sets.html).
+
+# Returns
+
+The edges of the matching as `(top, bottom)` tuples.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("alice", "paper1"),
+    Edge::new("alice", "paper2"),
+    Edge::new("bob", "paper1"),
+    Edge::new("carol", "paper1"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let matching = bipartite::hopcroft_karp_matching(&graph, None).unwrap();
+assert_eq!(matching.len(), 2);
+assert!(matching.contains(&("alice", "paper2")));
+```
+
+# References
+
+1. J. E. Hopcroft and R. M. Karp: An n^5/2 algorithm for maximum matchings in bipartite graphs.
+   SIAM Journal on Computing 2(4):225–231, 1973.
+*/
+pub fn hopcroft_karp_matching<T, A>(
+    graph: &Graph<T, A>,
+    top_nodes: Option<&HashSet<T>>,
+) -> Result<HashSet<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let is_top = get_sides(graph, top_nodes)?;
+    let n = graph.number_of_nodes();
+    let adjacent: Vec<Vec<usize>> = (0..n)
+        .map(|u| match is_top[u] {
+            true => graph
+                .get_neighbors_nodes_by_index(&u)
+                .into_iter()
+                .filter(|v| !is_top[*v])
+                .collect(),
+            false => vec![],
+        })
+        .collect();
+    let top: Vec<usize> = (0..n).filter(|u| is_top[*u]).collect();
+    let mut mate: Vec<Option<usize>> = vec![None; n];
+    let mut layer = vec![usize::MAX; n];
+    let mut next_edge = vec![0; n];
+    loop {
+        // a breadth-first search from the free top nodes, layering the top nodes by the
+        // length of the shortest alternating path to them
+        let mut queue = VecDeque::new();
+        for u in top.iter() {
+            layer[*u] = match mate[*u] {
+                None => {
+                    queue.push_back(*u);
+                    0
+                }
+                Some(_) => usize::MAX,
+            };
+        }
+        let mut found_free = false;
+        while let Some(u) = queue.pop_front() {
+            for v in adjacent[u].iter() {
+                match mate[*v] {
+                    None => found_free = true,
+                    Some(w) if layer[w] == usize::MAX => {
+                        layer[w] = layer[u] + 1;
+                        queue.push_back(w);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !found_free {
+            break;
+        }
+        // a maximal set of vertex-disjoint shortest augmenting paths, found by depth-first
+        // searches along the layers
+        for u in top.iter() {
+            next_edge[*u] = 0;
+        }
+        for u in top.iter() {
+            if mate[*u].is_none() {
+                augment(*u, &adjacent, &mut mate, &mut layer, &mut next_edge);
+            }
+        }
+    }
+    let name = |i: usize| graph.get_node_by_index(&i).unwrap().name.clone();
+    Ok(top
+        .into_iter()
+        .filter_map(|u| mate[u].map(|v| (name(u), name(v))))
+        .collect())
+}
+
+/**
+Looks for an augmenting path from free top node `start` through the layers of top nodes, and
+augments the matching along it. Iterative, so that long paths don't overflow the stack.
+*/
+fn augment(
+    start: usize,
+    adjacent: &[Vec<usize>],
+    mate: &mut [Option<usize>],
+    layer: &mut [usize],
+    next_edge: &mut [usize],
+) -> bool {
+    let mut stack = vec![start];
+    while let Some(u) = stack.last().cloned() {
+        if next_edge[u] == adjacent[u].len() {
+            // a dead end; don't visit it again in this phase
+            layer[u] = usize::MAX;
+            stack.pop();
+            continue;
+        }
+        let v = adjacent[u][next_edge[u]];
+        next_edge[u] += 1;
+        match mate[v] {
+            None => {
+                // each top node on the stack takes the bottom node it went to
+                for u in stack {
+                    let v = adjacent[u][next_edge[u] - 1];
+                    mate[u] = Some(v);
+                    mate[v] = Some(u);
+                }
+                return true;
+            }
+            Some(w) if layer[w] == layer[u] + 1 => stack.push(w),
+            _ => {}
+        }
+    }
+    false
+}
+
+/**
+Returns a minimum weight full matching of a bipartite graph: a matching that covers every node
+of the smaller side of the graph, and whose sum of edge weights is as small as possible among
+those matchings. This solves the (rectangular) assignment problem.
+
+The matching is found with the Hungarian method, by finding a shortest augmenting path from
+each node of the smaller side with Dijkstra's algorithm, using node potentials so that the
+reduced edge costs aren't negative; O(n m log n). Missing edges are not allowed assignments.
+An `ErrorKind::Infeasible` error is returned if there is no full matching.
+
+# Arguments
+
+* `graph`: a bipartite [Graph](../../struct.Graph.html) instance. Weights can be negative. Of
+  multiple edges between two nodes the lightest is used. For a directed graph the direction of
+  the edges is ignored.
+* `top_nodes`: the nodes of one side of the graph; optional, but needed if `graph` is
+  disconnected. See [sets](./fn.sets.html).
+* `weighted`: if `true` the edge weights are used and every edge must have a weight,
+  otherwise every edge has a weight of one
+
+# Returns
+
+The edges of the matching as `(top, bottom)` tuples.
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+// the cost of each worker doing each job
+let edges = vec![
+    Edge::with_weight("w1", "j1", 4.0),
+    Edge::with_weight("w1", "j2", 1.0),
+    Edge::with_weight("w1", "j3", 3.0),
+    Edge::with_weight("w2", "j1", 2.0),
+    Edge::with_weight("w2", "j2", 0.0),
+    Edge::with_weight("w2", "j3", 5.0),
+    Edge::with_weight("w3", "j1", 3.0),
+    Edge::with_weight("w3", "j2", 2.0),
+    Edge::with_weight("w3", "j3", 2.0),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let workers = vec!["w1", "w2", "w3"].into_iter().collect();
+let matching = bipartite::minimum_weight_full_matching(&graph, Some(&workers), true).unwrap();
+let expected = vec![("w1", "j2"), ("w2", "j1"), ("w3", "j3")].into_iter().collect();
+assert_eq!(matching, expected);
+```
+
+# References
+
+1. H. W. Kuhn: The Hungarian method for the assignment problem. Naval Research Logistics
+   Quarterly 2:83–97, 1955.
+2. R. Jonker and A. Volgenant: A shortest augmenting path algorithm for dense and sparse
+   linear assignment problems. Computing 38:325–340, 1987.
+*/
+pub fn minimum_weight_full_matching<T, A>(
+    graph: &Graph<T, A>,
+    top_nodes: Option<&HashSet<T>>,
+    weighted: bool,
+) -> Result<HashSet<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let is_top = get_sides(graph, top_nodes)?;
+    let n = graph.number_of_nodes();
+    let number_of_top = is_top.iter().filter(|t| **t).count();
+    // the nodes that must all be matched are the "rows" of the assignment problem
+    let rows_are_top = number_of_top <= n - number_of_top;
+    let is_row: Vec<bool> = is_top.iter().map(|t| *t == rows_are_top).collect();
+    let mut costs: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u).unwrap();
+        let v = graph.get_node_index(&edge.v).unwrap();
+        if is_row[u] == is_row[v] {
+            continue;
+        }
+        let (row, column) = match is_row[u] {
+            true => (u, v),
+            false => (v, u),
+        };
+        let cost = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let entry = costs[row].entry(column).or_insert(cost);
+        *entry = entry.min(cost);
+    }
+    let costs: Vec<Vec<(usize, f64)>> = costs
+        .into_iter()
+        .map(|row_costs| {
+            let mut row_costs: Vec<(usize, f64)> = row_costs.into_iter().collect();
+            row_costs.sort_by_key(|(column, _)| *column);
+            row_costs
+        })
+        .collect();
+    let mate = assign(&is_row, &costs)?;
+    let name = |i: usize| graph.get_node_by_index(&i).unwrap().name.clone();
+    Ok((0..n)
+        .filter(|row| is_row[*row])
+        .map(|row| {
+            let column = mate[row].unwrap();
+            match rows_are_top {
+                true => (name(row), name(column)),
+                false => (name(column), name(row)),
+            }
+        })
+        .collect())
+}
+
+/**
+Assigns every row to a different column at minimum total cost, returning the mate of each
+node. `costs[row]` holds the cost of assigning the row to each column it has an edge to.
+
+Keeps potentials for the rows and columns such that the reduced cost
+`cost - potential[row] - potential[column]` of every edge is non-negative, and zero for
+assigned edges. Each row is added with a shortest path search over the reduced costs, after
+which the potentials are updated to keep those invariants.
+*/
+fn assign(is_row: &[bool], costs: &[Vec<(usize, f64)>]) -> Result<Vec<Option<usize>>, Error> {
+    let n = is_row.len();
+    let infeasible = || Error {
+        kind: ErrorKind::Infeasible,
+        message: "No full matching exists.".to_string(),
+    };
+    let mut potential = vec![0.0; n];
+    for row in (0..n).filter(|r| is_row[*r]) {
+        potential[row] = costs[row]
+            .iter()
+            .map(|(_, cost)| *cost)
+            .min_by(|a, b| a.total_cmp(b))
+            .ok_or_else(infeasible)?;
+    }
+    let mut mate: Vec<Option<usize>> = vec![None; n];
+    let mut search = Search {
+        distance: vec![f64::INFINITY; n],
+        predecessor: vec![0; n],
+        done: vec![false; n],
+        touched: vec![],
+        fringe: BinaryHeap::new(),
+    };
+    for source in (0..n).filter(|r| is_row[*r]) {
+        // the columns reached, in the order they were finalized, and the rows reached
+        let mut columns: Vec<usize> = vec![];
+        let mut rows: Vec<(usize, f64)> = vec![(source, 0.0)];
+        search.relax(source, 0.0, &costs[source], &potential);
+        let free_column = loop {
+            let node = search.fringe.pop().ok_or_else(infeasible)?;
+            let column = node.v;
+            if search.done[column] || -node.distance > search.distance[column] {
+                continue;
+            }
+            search.done[column] = true;
+            columns.push(column);
+            match mate[column] {
+                None => break column,
+                Some(row) => {
+                    let row_distance = search.distance[column];
+                    rows.push((row, row_distance));
+                    search.relax(row, row_distance, &costs[row], &potential);
+                }
+            }
+        };
+        let path_distance = search.distance[free_column];
+        for (row, row_distance) in rows {
+            potential[row] += path_distance - row_distance;
+        }
+        for column in columns {
+            potential[column] -= path_distance - search.distance[column];
+        }
+        // flip the assignments along the augmenting path
+        let mut column = free_column;
+        loop {
+            let row = search.predecessor[column];
+            let previous = mate[row];
+            mate[row] = Some(column);
+            mate[column] = Some(row);
+            match previous {
+                None => break,
+                Some(previous) => column = previous,
+            }
+        }
+        search.reset();
+    }
+    Ok(mate)
+}
+
+/// The state of a shortest path search from a row over the reduced costs.
+struct Search {
+    distance: Vec<f64>,
+    predecessor: Vec<usize>,
+    done: Vec<bool>,
+    /// The columns whose distance has been set, to reset them after the search.
+    touched: Vec<usize>,
+    fringe: BinaryHeap<FringeNode>,
+}
+
+impl Search {
+    fn relax(&mut self, row: usize, row_distance: f64, costs: &[(usize, f64)], potential: &[f64]) {
+        for (column, cost) in costs.iter() {
+            let d = row_distance + cost - potential[row] - potential[*column];
+            if d < self.distance[*column] {
+                if self.distance[*column] == f64::INFINITY {
+                    self.touched.push(*column);
+                }
+                self.distance[*column] = d;
+                self.predecessor[*column] = row;
+                push_fringe_node(&mut self.fringe, row, *column, d);
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        for column in self.touched.drain(..) {
+            self.distance[column] = f64::INFINITY;
+            self.done[column] = false;
+        }
+        self.fringe.clear();
+    }
+}
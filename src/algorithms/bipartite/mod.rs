@@ -0,0 +1,10 @@
+mod basic;
+pub use basic::*;
+mod centrality;
+pub use centrality::*;
+mod cluster;
+pub use cluster::*;
+mod matching;
+pub use matching::*;
+mod projection;
+pub use projection::*;
@@ -0,0 +1,187 @@
+use crate::{Edge, Error, ErrorKind, Graph, GraphSpecs};
+use nohash::IntSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the projection of a bipartite graph onto one of its sets of nodes: a graph of `nodes`
+in which two nodes are joined if they have a neighbor in common in the bipartite graph.
+
+For a directed graph the projection is directed, with an edge from `u` to `v` if the bipartite
+graph has a path `u -> w -> v`. The nodes of the projection keep their attributes; its edges
+have no weight. See [weighted_projected_graph](./fn.weighted_projected_graph.html) for a
+projection whose edges are weighted by the number of shared neighbors.
+
+# Arguments
+
+* `graph`: a bipartite [Graph](../../struct.Graph.html) instance
+* `nodes`: the nodes to project onto; a `NodeNotFound` error is returned if one of them isn't
+  in the graph
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+// authors and the papers they wrote
+let edges = vec![
+    Edge::new("alice", "paper1"),
+    Edge::new("bob", "paper1"),
+    Edge::new("bob", "paper2"),
+    Edge::new("carol", "paper2"),
+    Edge::new("dave", "paper3"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let authors = vec!["alice", "bob", "carol", "dave"].into_iter().collect();
+let coauthors = bipartite::projected_graph(&graph, &authors).unwrap();
+assert_eq!(coauthors.number_of_nodes(), 4);
+assert_eq!(coauthors.number_of_edges(), 2);
+assert!(coauthors.get_edge("alice", "bob").is_ok());
+```
+*/
+pub fn projected_graph<T, A>(graph: &Graph<T, A>, nodes: &HashSet<T>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_projection(graph, nodes, None)
+}
+
+/**
+Returns the weighted projection of a bipartite graph onto one of its sets of nodes: a graph of
+`nodes` in which two nodes are joined if they have a neighbor in common in the bipartite graph,
+weighted by the number of neighbors they have in common.
+
+For a directed graph the projection is directed, with an edge from `u` to `v` if the bipartite
+graph has a path `u -> w -> v`, weighted by the number of such paths. The nodes of the
+projection keep their attributes.
+
+# Arguments
+
+* `graph`: a bipartite [Graph](../../struct.Graph.html) instance
+* `nodes`: the nodes to project onto; a `NodeNotFound` error is returned if one of them isn't
+  in the graph
+* `ratio`: if `true` the weights are divided by the number of nodes in the other set, so that
+  they are the fraction of the other set that the two nodes share; an `InvalidArgument` error is
+  returned if there are no other nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::bipartite, Edge, Graph, GraphSpecs};
+// customers and the products they bought
+let edges = vec![
+    Edge::new("ann", "tea"),
+    Edge::new("ann", "milk"),
+    Edge::new("ben", "tea"),
+    Edge::new("ben", "milk"),
+    Edge::new("cat", "milk"),
+    Edge::new("cat", "eggs"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let customers = vec!["ann", "ben", "cat"].into_iter().collect();
+let projection = bipartite::weighted_projected_graph(&graph, &customers, false).unwrap();
+assert_eq!(projection.get_edge("ann", "ben").unwrap().weight, 2.0);
+assert_eq!(projection.get_edge("ann", "cat").unwrap().weight, 1.0);
+let projection = bipartite::weighted_projected_graph(&graph, &customers, true).unwrap();
+assert_eq!(projection.get_edge("ann", "ben").unwrap().weight, 2.0 / 3.0);
+```
+
+# References
+
+1. P. Holme, F. Liljeros, C. R. Edling and B. J. Kim: Network bipartivity. Physical Review E
+   68:056107, 2003.
+*/
+pub fn weighted_projected_graph<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &HashSet<T>,
+    ratio: bool,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let divisor = match ratio {
+        true => {
+            for node in nodes {
+                graph.get_node_index(node)?;
+            }
+            match graph.number_of_nodes() - nodes.len() {
+                0 => {
+                    return Err(Error {
+                        kind: ErrorKind::InvalidArgument,
+                        message: "With `ratio`, `nodes` must not be every node of the graph."
+                            .to_string(),
+                    })
+                }
+                others => others as f64,
+            }
+        }
+        false => 1.0,
+    };
+    get_projection(graph, nodes, Some(divisor))
+}
+
+/**
+Projects the graph onto `nodes`. If `divisor` is given the edges are weighted by the number of
+shared neighbors divided by `divisor`, otherwise they have no weight.
+*/
+fn get_projection<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &HashSet<T>,
+    divisor: Option<f64>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut indexes = nodes
+        .iter()
+        .map(|node| graph.get_node_index(node))
+        .collect::<Result<Vec<usize>, Error>>()?;
+    indexes.sort_unstable();
+    let mut in_nodes = vec![false; graph.number_of_nodes()];
+    for u in indexes.iter() {
+        in_nodes[*u] = true;
+    }
+    let successors = |u: usize| -> IntSet<usize> {
+        graph
+            .get_successor_nodes_by_index(&u)
+            .iter()
+            .map(|adj| adj.node_index)
+            .collect()
+    };
+    let name = |i: usize| graph.get_node_by_index(&i).unwrap().name.clone();
+    let mut edges = vec![];
+    for u in indexes.iter().cloned() {
+        // the number of paths u -> w -> v to each other node v
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for w in successors(u) {
+            for v in successors(w) {
+                // in an undirected graph each pair is visited from both ends
+                if in_nodes[v] && v != u && (graph.specs.directed || u < v) {
+                    *counts.entry(v).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut counts: Vec<(usize, usize)> = counts.into_iter().collect();
+        counts.sort_unstable();
+        for (v, count) in counts {
+            edges.push(match divisor {
+                None => Edge::new(name(u), name(v)),
+                Some(divisor) => Edge::with_weight(name(u), name(v), count as f64 / divisor),
+            });
+        }
+    }
+    let nodes = indexes
+        .into_iter()
+        .map(|i| graph.get_node_by_index(&i).unwrap().clone())
+        .collect();
+    let specs = match graph.specs.directed {
+        true => GraphSpecs::directed(),
+        false => GraphSpecs::undirected(),
+    };
+    Graph::new_from_nodes_and_edges(nodes, edges, specs)
+}
@@ -1,7 +1,9 @@
 #![allow(non_snake_case)]
 
 use super::fringe_node::{push_fringe_node, FringeNode};
-use crate::{Error, Graph};
+use crate::algorithms::community::utility::get_rng;
+use crate::{Error, ErrorKind, Graph};
+use rand::prelude::*;
 use rayon::iter::*;
 use rayon::prelude::ParallelIterator;
 use std::collections::{BinaryHeap, HashMap, VecDeque};
@@ -9,84 +11,644 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::hash::Hash;
 
-struct SingleSourceResults {
-    S: Vec<usize>,
-    P: Vec<Vec<usize>>,
-    sigma: Vec<f64>,
-    source: usize,
+pub(super) struct SingleSourceResults {
+    pub S: Vec<usize>,
+    pub P: Vec<Vec<usize>>,
+    pub sigma: Vec<f64>,
+    pub source: usize,
 }
 
 /**
 Compute the shortest-path (Dijkstra) betweenness centrality for nodes.
 
+If `k` is given, only the shortest paths from `k` randomly chosen source nodes are counted,
+and the result is scaled to estimate the betweenness centrality of all the nodes.
+
 # Arguments
 
 * `graph`: a [Graph](../../../struct.Graph.html) instance
-* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality;
+  in multigraphs the shortest paths take the lightest of the parallel edges between two nodes
 * `normalized`: set to `true` to normalize the node centrality values
-* `parallel`: set to `true` to compute in parallel
+* `k`: the number of source nodes to sample, between `1` and the number of nodes; use `None`
+  to use every node as a source. With `k` equal to `1` the sampled node gets `0`, where
+  NetworkX gives `nan`
+* `seed`: the seed for choosing the sampled source nodes; use `None` for a random seed
 
 # Examples
 
 ```
 use graphrs::{algorithms::{centrality::{betweenness}}, generators};
 let graph = generators::social::karate_club_graph();
-let centralities = betweenness::betweenness_centrality(&graph, false, true);
+let centralities = betweenness::betweenness_centrality(&graph, false, true, None, None);
+let estimates = betweenness::betweenness_centrality(&graph, false, true, Some(10), Some(1));
 ```
 
 # References
 
 1. Ulrik Brandes: A Faster Algorithm for Betweenness Centrality. Journal of Mathematical Sociology 25(2):163-177, 2001.
+2. Ulrik Brandes and Christian Pich: Centrality Estimation in Large Networks. International
+   Journal of Bifurcation and Chaos 17(7):2303-2318, 2007.
+   <https://doi.org/10.1142/S0218127407018403>
 */
 pub fn betweenness_centrality<T, A>(
     graph: &Graph<T, A>,
     weighted: bool,
     normalized: bool,
+    k: Option<usize>,
+    seed: Option<u64>,
 ) -> Result<HashMap<T, f64>, Error>
 where
     T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let parallel = graph.number_of_nodes() > 20 && rayon::current_num_threads() > 1;
-    let betweenness_mutex = std::sync::Mutex::new(vec![0.0; graph.number_of_nodes()]);
-    match parallel {
-        true => {
-            (0..graph.number_of_nodes())
-                .into_par_iter()
-                .map(|source| match weighted {
-                    true => dijkstra(graph, source),
-                    false => bfs(graph, source),
-                })
-                .for_each(|r| {
-                    let mut betweenness = betweenness_mutex.lock().unwrap();
-                    accumulate_betweenness(&mut betweenness, &r);
+    let num_nodes = graph.number_of_nodes();
+    let sources: Vec<usize> = match k {
+        None => (0..num_nodes).collect(),
+        Some(k) => {
+            if k == 0 || k > num_nodes {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: "`k` must be between 1 and the number of nodes.".to_string(),
                 });
+            }
+            let mut rng = get_rng(seed);
+            rand::seq::index::sample(&mut rng, num_nodes, k).into_vec()
         }
-        false => {
-            for source in 0..graph.number_of_nodes() {
-                let source_source_results = match weighted {
-                    true => dijkstra(graph, source),
-                    false => bfs(graph, source),
+    };
+    let mut betweenness = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        vec![0.0; num_nodes],
+        accumulate_betweenness,
+    );
+    match k {
+        None => rescale(
+            &mut betweenness,
+            num_nodes,
+            normalized,
+            graph.specs.directed,
+        ),
+        Some(_) => rescale_sampled(&mut betweenness, &sources, normalized, graph.specs.directed),
+    }
+    Ok(to_node_map(graph, betweenness))
+}
+
+/**
+Estimate the betweenness centrality for nodes by sampling shortest paths.
+
+Pairs of distinct nodes are chosen at random, and one of the shortest paths between each pair
+is chosen at random; the estimated centrality of a node is proportional to the number of
+chosen paths that pass through it. The number of samples is found from the vertex diameter of
+the graph, the largest number of nodes on a shortest path, so that with probability at least
+`1 - delta` the estimate of every node's betweenness, as a fraction of the `n * (n - 1)`
+ordered pairs of nodes, is within `epsilon` of the exact value. Each search stops once it
+reaches the target of its pair, and the samples are taken in parallel.
+
+The estimates are scaled in the same way as by
+[betweenness_centrality](./fn.betweenness_centrality.html).
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality;
+  in multigraphs the shortest paths take the lightest of the parallel edges between two nodes
+* `normalized`: set to `true` to normalize the node centrality values
+* `epsilon`: the largest error of the estimates, between `0` and `1`
+* `delta`: the probability that some estimate has a larger error than `epsilon`, between `0`
+  and `1`
+* `seed`: the seed for choosing the sampled paths; use `None` for a random seed
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let estimates =
+    betweenness::approximate_betweenness_centrality(&graph, false, true, 0.05, 0.1, Some(1))
+        .unwrap();
+assert!(estimates[&0] > estimates[&1]);
+```
+
+# References
+
+1. Matteo Riondato and Evgenios M. Kornaropoulos: Fast approximation of betweenness
+   centrality through sampling. Data Mining and Knowledge Discovery 30(2):438-475, 2016.
+   <https://doi.org/10.1007/s10618-015-0423-0>
+*/
+pub fn approximate_betweenness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    epsilon: f64,
+    delta: f64,
+    seed: Option<u64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if !(epsilon > 0.0 && epsilon < 1.0 && delta > 0.0 && delta < 1.0) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`epsilon` and `delta` must be between 0 and 1.".to_string(),
+        });
+    }
+    let num_nodes = graph.number_of_nodes();
+    let mut betweenness = vec![0.0; num_nodes];
+    if num_nodes > 2 {
+        let vertex_diameter = get_vertex_diameter_bound(graph, weighted) as f64;
+        let num_samples = get_number_of_samples(vertex_diameter, epsilon, delta);
+        let min_weights = match weighted {
+            true => get_min_weights(graph),
+            false => None,
+        };
+        let mut rng = get_rng(seed);
+        // choose the pairs, and a seed for choosing each path, up front so that the result
+        // doesn't depend on how the samples are spread over threads
+        let samples: Vec<(usize, usize, u64)> = (0..num_samples)
+            .map(|_| {
+                let s = rng.gen_range(0..num_nodes);
+                let t = (s + rng.gen_range(1..num_nodes)) % num_nodes;
+                (s, t, rng.next_u64())
+            })
+            .collect();
+        let counts = samples
+            .into_par_iter()
+            .fold(
+                || vec![0usize; num_nodes],
+                |mut counts, (s, t, path_seed)| {
+                    for v in sample_shortest_path(
+                        graph,
+                        s,
+                        t,
+                        weighted,
+                        min_weights.as_deref(),
+                        path_seed,
+                    ) {
+                        counts[v] += 1;
+                    }
+                    counts
+                },
+            )
+            .reduce(
+                || vec![0usize; num_nodes],
+                |a, b| a.into_iter().zip(b).map(|(x, y)| x + y).collect(),
+            );
+        // scale the fractions of paths up to the number of ordered pairs of nodes
+        let pairs = num_nodes as f64 * (num_nodes as f64 - 1.0);
+        betweenness = counts
+            .into_iter()
+            .map(|c| c as f64 / num_samples as f64 * pairs)
+            .collect();
+        rescale(
+            &mut betweenness,
+            num_nodes,
+            normalized,
+            graph.specs.directed,
+        );
+    }
+    Ok(to_node_map(graph, betweenness))
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for edges.
+
+The betweenness centrality of an edge is the sum of the fraction of all-pairs shortest
+paths that pass through it. The keys of the result are the edges' `(u, v)` names; for
+undirected graphs `u` is never greater than `v`. In multigraphs the parallel edges between
+two nodes share one key, and one value; use
+[edge_betweenness_centrality_multigraph](fn.edge_betweenness_centrality_multigraph.html) to
+split the value between them.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values by `1 / (n * (n - 1))`,
+  where `n` is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+assert_eq!((centralities[&(0, 31)] * 1000.0).round() / 1000.0, 0.127);
+```
+
+# References
+
+1. Ulrik Brandes: A Faster Algorithm for Betweenness Centrality. Journal of Mathematical Sociology 25(2):163-177, 2001.
+2. Ulrik Brandes: On Variants of Shortest-Path Betweenness Centrality and their Generic
+   Computation. Social Networks 30(2):136-145, 2008.
+*/
+pub fn edge_betweenness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<(T, T), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (betweenness, scale) = get_edge_betweenness(graph, weighted, normalized);
+    Ok(to_edge_map(graph, betweenness, scale))
+}
+
+/**
+Compute the shortest-path (Dijkstra) betweenness centrality for the edges of a multigraph.
+
+The keys of the result are `(u, v, i)`, where `u` and `v` are the names of an edge's nodes and
+`i` is the position of the edge in the `Vec` returned by `Graph::get_edges(u, v)`, which tells
+parallel edges apart; for undirected graphs `u` is never greater than `v`.
+
+The value of a pair of nodes is split evenly between the parallel edges that the shortest
+paths between them can take. Without `weighted` that is every parallel edge. With `weighted`,
+the shortest paths only take the lightest of the parallel edges, so the value is split between
+the parallel edges with the smallest weight, and the others get zero.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance, which can also be a graph without
+  parallel edges, in which case every `i` is `0`
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values by `1 / (n * (n - 1))`,
+  where `n` is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, Edge, Graph, GraphSpecs, MissingNodeStrategy};
+let specs = GraphSpecs {
+    missing_node_strategy: MissingNodeStrategy::Create,
+    ..GraphSpecs::multi_undirected()
+};
+let mut graph: Graph<&str, ()> = Graph::new(specs);
+graph.add_edge(Edge::new("a", "b")).unwrap();
+graph.add_edge(Edge::new("a", "b")).unwrap();
+graph.add_edge(Edge::new("b", "c")).unwrap();
+let centralities =
+    betweenness::edge_betweenness_centrality_multigraph(&graph, false, false).unwrap();
+assert_eq!(centralities[&("a", "b", 0)], 1.0);
+assert_eq!(centralities[&("a", "b", 1)], 1.0);
+assert_eq!(centralities[&("b", "c", 0)], 2.0);
+```
+*/
+pub fn edge_betweenness_centrality_multigraph<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<(T, T, usize), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (betweenness, scale) = get_edge_betweenness(graph, weighted, normalized);
+    let directed = graph.specs.directed;
+    let mut hm = HashMap::new();
+    for u in 0..graph.number_of_nodes() {
+        for adj in graph.get_successor_nodes_by_index(&u) {
+            let v = adj.node_index;
+            if !directed && v < u {
+                continue;
+            }
+            let value = betweenness.get(&(u, v)).cloned().unwrap_or(0.0) * scale;
+            let edges = graph.get_edges_by_indexes(u, v).unwrap();
+            let min_weight = get_min_weight(graph, u, v);
+            let on_shortest_paths: Vec<bool> = edges
+                .iter()
+                .map(|edge| !weighted || min_weight.is_nan() || edge.weight == min_weight)
+                .collect();
+            let count = on_shortest_paths.iter().filter(|b| **b).count() as f64;
+            let (u_name, v_name) = (get_name(graph, u), get_name(graph, v));
+            let (u_name, v_name) = match !directed && u_name > v_name {
+                true => (v_name, u_name),
+                false => (u_name, v_name),
+            };
+            for (i, on_shortest_paths) in on_shortest_paths.into_iter().enumerate() {
+                let share = match on_shortest_paths {
+                    true => value / count,
+                    false => 0.0,
                 };
-                let mut betweenness = betweenness_mutex.lock().unwrap();
-                accumulate_betweenness(&mut betweenness, &source_source_results);
+                hm.insert((u_name.clone(), v_name.clone(), i), share);
             }
         }
     }
-    let mut betweenness = betweenness_mutex.lock().unwrap();
+    Ok(hm)
+}
+
+/**
+Compute the betweenness centrality for nodes, counting only the shortest paths from `sources`
+to `targets`.
+
+The betweenness centrality of a node is the sum of the fraction of the shortest paths from
+each of `sources` to each of `targets` that pass through it. The source and target nodes of a
+path aren't counted as being on it.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `sources`: the nodes the shortest paths start from
+* `targets`: the nodes the shortest paths end at
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values by
+  `1 / ((n - 1) * (n - 2))`, where `n` is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities =
+    betweenness::betweenness_centrality_subset(&graph, &[0, 1], &[32, 33], false, false)
+        .unwrap();
+assert_eq!((centralities[&2] * 1000.0).round() / 1000.0, 0.417);
+```
+
+# References
+
+1. Ulrik Brandes: On Variants of Shortest-Path Betweenness Centrality and their Generic
+   Computation. Social Networks 30(2):136-145, 2008.
+   <https://doi.org/10.1016/j.socnet.2007.11.001>
+*/
+pub fn betweenness_centrality_subset<T, A>(
+    graph: &Graph<T, A>,
+    sources: &[T],
+    targets: &[T],
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let (sources, _) = get_node_indexes(graph, sources)?;
+    let (_, targets) = get_node_indexes(graph, targets)?;
+    let mut betweenness = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        vec![0.0; num_nodes],
+        |betweenness, result| accumulate_betweenness_subset(betweenness, result, &targets),
+    );
     rescale(
         &mut betweenness,
-        graph.get_all_nodes().len(),
+        num_nodes,
         normalized,
         graph.specs.directed,
     );
-    let hm = betweenness
-        .clone()
-        .into_iter()
-        .enumerate()
-        .map(|(i, v)| (graph.get_node_by_index(&i).unwrap().name.clone(), v))
+    Ok(to_node_map(graph, betweenness))
+}
+
+/**
+Compute the betweenness centrality for nodes, counting only the shortest paths from `sources`
+to every node.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `sources`: the nodes the shortest paths start from; use `None` for all nodes
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the node centrality values by
+  `1 / ((n - 1) * (n - 2))`, where `n` is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities =
+    betweenness::betweenness_centrality_source(&graph, Some(&[0, 33]), false, true).unwrap();
+```
+*/
+pub fn betweenness_centrality_source<T, A>(
+    graph: &Graph<T, A>,
+    sources: Option<&[T]>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let all_nodes: Vec<T> = graph
+        .get_all_nodes()
+        .iter()
+        .map(|node| node.name.clone())
         .collect();
-    Ok(hm)
+    let sources = sources.unwrap_or(&all_nodes);
+    betweenness_centrality_subset(graph, sources, &all_nodes, weighted, normalized)
+}
+
+/**
+Compute the betweenness centrality for edges, counting only the shortest paths from `sources`
+to `targets`.
+
+The keys of the result are the edges' `(u, v)` names, as for
+[edge_betweenness_centrality](./fn.edge_betweenness_centrality.html).
+
+The values match those of NetworkX, which splits the dependency of a node that isn't one of
+`targets` equally between the edges from its predecessors on the shortest paths, rather than in
+proportion to the number of shortest paths that use each edge. When `targets` are every node,
+the values are those of `edge_betweenness_centrality` for the paths from `sources`.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `sources`: the nodes the shortest paths start from
+* `targets`: the nodes the shortest paths end at
+* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `normalized`: set to `true` to normalize the edge centrality values by `1 / (n * (n - 1))`,
+  where `n` is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{betweenness}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities =
+    betweenness::edge_betweenness_centrality_subset(&graph, &[0], &[33], false, false)
+        .unwrap();
+assert_eq!(centralities[&(0, 1)], 0.0);
+```
+
+# References
+
+1. Ulrik Brandes: On Variants of Shortest-Path Betweenness Centrality and their Generic
+   Computation. Social Networks 30(2):136-145, 2008.
+   <https://doi.org/10.1016/j.socnet.2007.11.001>
+*/
+pub fn edge_betweenness_centrality_subset<T, A>(
+    graph: &Graph<T, A>,
+    sources: &[T],
+    targets: &[T],
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<(T, T), f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (sources, _) = get_node_indexes(graph, sources)?;
+    let (_, targets) = get_node_indexes(graph, targets)?;
+    let directed = graph.specs.directed;
+    let betweenness = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        HashMap::new(),
+        |betweenness, result| {
+            accumulate_edge_betweenness_subset(betweenness, result, &targets, directed)
+        },
+    );
+    let scale = get_edge_scale(graph.number_of_nodes(), normalized, directed).unwrap_or(1.0);
+    Ok(to_edge_map(graph, betweenness, scale))
+}
+
+/// Returns the number of samples needed for the estimates to be within `epsilon` with
+/// probability `1 - delta`, given the vertex diameter of the graph.
+fn get_number_of_samples(vertex_diameter: f64, epsilon: f64, delta: f64) -> usize {
+    let log_vd = match vertex_diameter > 3.0 {
+        true => (vertex_diameter - 2.0).log2().floor(),
+        false => 0.0,
+    };
+    (0.5 / (epsilon * epsilon) * (log_vd + 1.0 + (1.0 / delta).ln())).ceil() as usize
+}
+
+/// Returns an upper bound of the largest number of nodes on a shortest path in `graph`.
+/// For undirected, unweighted graphs this is one more than twice the largest eccentricity
+/// found by a breadth-first search from a node of each component; otherwise it is the
+/// number of nodes of the largest weakly connected component.
+fn get_vertex_diameter_bound<T, A>(graph: &Graph<T, A>, weighted: bool) -> usize
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let num_nodes = graph.number_of_nodes();
+    let undirected_unweighted = !graph.specs.directed && !weighted;
+    let mut distances = vec![usize::MAX; num_nodes];
+    let mut bound = 0;
+    for start in 0..num_nodes {
+        if distances[start] != usize::MAX {
+            continue;
+        }
+        distances[start] = 0;
+        let mut queue = VecDeque::from([start]);
+        let mut component_size = 0;
+        let mut eccentricity = 0;
+        while let Some(v) = queue.pop_front() {
+            component_size += 1;
+            eccentricity = distances[v];
+            let adjacent =
+                graph
+                    .get_successor_nodes_by_index(&v)
+                    .iter()
+                    .chain(match graph.specs.directed {
+                        true => graph.get_predecessor_nodes_by_index(&v).iter(),
+                        false => [].iter(),
+                    });
+            for adj in adjacent {
+                if distances[adj.node_index] == usize::MAX {
+                    distances[adj.node_index] = distances[v] + 1;
+                    queue.push_back(adj.node_index);
+                }
+            }
+        }
+        bound = bound.max(match undirected_unweighted {
+            true => 2 * eccentricity + 1,
+            false => component_size,
+        });
+    }
+    bound
+}
+
+/// Returns the nodes strictly between `s` and `t` on a shortest path chosen uniformly at
+/// random, or no nodes if `t` can't be reached from `s`.
+fn sample_shortest_path<T, A>(
+    graph: &Graph<T, A>,
+    s: usize,
+    t: usize,
+    weighted: bool,
+    min_weights: Option<&[Vec<f64>]>,
+    seed: u64,
+) -> Vec<usize>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let num_nodes = graph.number_of_nodes();
+    let mut P: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+    let mut D = vec![f64::MAX; num_nodes];
+    let mut seen = vec![f64::MAX; num_nodes];
+    let mut sigma = vec![0.0; num_nodes];
+    let mut fringe = BinaryHeap::<FringeNode>::new();
+    sigma[s] = 1.0;
+    seen[s] = 0.0;
+    fringe.push(FringeNode {
+        distance: -0.0,
+        pred: s,
+        v: s,
+    });
+    // a Dijkstra search, with unit costs for unweighted graphs, that stops when `t` is
+    // settled; by then the shortest-path counts of `t` and its predecessors are final
+    while let Some(fringe_item) = fringe.pop() {
+        let dist = -fringe_item.distance;
+        let v = fringe_item.v;
+        if D[v] != f64::MAX {
+            continue;
+        }
+        sigma[v] += sigma[fringe_item.pred];
+        D[v] = dist;
+        if v == t {
+            break;
+        }
+        for (i, adj) in graph.get_successor_nodes_by_index(&v).iter().enumerate() {
+            let w = adj.node_index;
+            let vw_dist = dist
+                + match (weighted, min_weights) {
+                    (true, Some(min_weights)) => min_weights[v][i],
+                    (true, None) => adj.weight,
+                    (false, _) => 1.0,
+                };
+            if D[w] == f64::MAX && (seen[w] == f64::MAX || vw_dist < seen[w]) {
+                seen[w] = vw_dist;
+                push_fringe_node(&mut fringe, v, w, vw_dist);
+                sigma[w] = 0.0;
+                P[w] = vec![v];
+            } else if vw_dist == seen[w] {
+                sigma[w] += sigma[v];
+                P[w].push(v);
+            }
+        }
+    }
+    let mut path = vec![];
+    if D[t] == f64::MAX {
+        return path;
+    }
+    // walk back from `t`, choosing each predecessor in proportion to its number of shortest
+    // paths from `s`
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut w = t;
+    loop {
+        let mut r = rng.gen::<f64>() * sigma[w];
+        let mut chosen = P[w][P[w].len() - 1];
+        for v in P[w].iter() {
+            if r < sigma[*v] {
+                chosen = *v;
+                break;
+            }
+            r -= sigma[*v];
+        }
+        if chosen == s {
+            break;
+        }
+        path.push(chosen);
+        w = chosen;
+    }
+    path
 }
 
 fn bfs<T, A>(graph: &Graph<T, A>, source: usize) -> SingleSourceResults
@@ -132,7 +694,11 @@ where
     }
 }
 
-fn dijkstra<T, A>(graph: &Graph<T, A>, source: usize) -> SingleSourceResults
+fn dijkstra<T, A>(
+    graph: &Graph<T, A>,
+    source: usize,
+    min_weights: Option<&[Vec<f64>]>,
+) -> SingleSourceResults
 where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone,
@@ -166,10 +732,13 @@ where
         sigma[v] += sigma[pred];
         S.push(v);
         D[v] = dist;
-        for adj in graph.get_successor_nodes_by_index(&v) {
+        for (i, adj) in graph.get_successor_nodes_by_index(&v).iter().enumerate() {
             let w = adj.node_index;
             // println!("        u: {}", u);
-            let cost = adj.weight;
+            let cost = match min_weights {
+                Some(min_weights) => min_weights[v][i],
+                None => adj.weight,
+            };
             // println!("            cost: {}", cost);
             let vw_dist = dist + cost;
             // println!("            vu_dist: {}", vu_dist);
@@ -208,6 +777,261 @@ fn accumulate_betweenness(betweenness: &mut Vec<f64>, result: &SingleSourceResul
     }
 }
 
+/// Adds the dependencies of the edges on the shortest paths from the source of `result` to
+/// `betweenness`.
+fn accumulate_edge_betweenness(
+    betweenness: &mut HashMap<(usize, usize), f64>,
+    result: &SingleSourceResults,
+    directed: bool,
+) {
+    let mut delta = vec![0.0; result.sigma.len()];
+    for w in result.S.iter().rev() {
+        let coeff = (1.0 + delta[*w]) / result.sigma[*w];
+        for v in result.P[*w].iter() {
+            let c = result.sigma[*v] * coeff;
+            *betweenness
+                .entry(get_edge_key(*v, *w, directed))
+                .or_insert(0.0) += c;
+            delta[*v] += c;
+        }
+    }
+}
+
+/// Adds the dependencies of the edges on the shortest paths from the source of `result` to
+/// the nodes marked in `targets` to `betweenness`. As in NetworkX, the dependency of a node
+/// that isn't a target is split equally between its predecessors, rather than in proportion
+/// to the number of shortest paths through each of them.
+fn accumulate_edge_betweenness_subset(
+    betweenness: &mut HashMap<(usize, usize), f64>,
+    result: &SingleSourceResults,
+    targets: &[bool],
+    directed: bool,
+) {
+    let mut delta = vec![0.0; result.sigma.len()];
+    for w in result.S.iter().rev() {
+        let num_predecessors = result.P[*w].len() as f64;
+        for v in result.P[*w].iter() {
+            let c = match targets[*w] {
+                true => result.sigma[*v] / result.sigma[*w] * (1.0 + delta[*w]),
+                false => delta[*w] / num_predecessors,
+            };
+            *betweenness
+                .entry(get_edge_key(*v, *w, directed))
+                .or_insert(0.0) += c;
+            delta[*v] += c;
+        }
+    }
+}
+
+/// Returns the key of the edge between `v` and `w`; for undirected graphs the smaller index
+/// comes first.
+fn get_edge_key(v: usize, w: usize, directed: bool) -> (usize, usize) {
+    match !directed && v > w {
+        true => (w, v),
+        false => (v, w),
+    }
+}
+
+/// Adds the dependencies of the nodes on the shortest paths from the source of `result` to
+/// the nodes marked in `targets` to `betweenness`.
+fn accumulate_betweenness_subset(
+    betweenness: &mut [f64],
+    result: &SingleSourceResults,
+    targets: &[bool],
+) {
+    let mut delta = vec![0.0; betweenness.len()];
+    for w in result.S.iter().rev() {
+        let is_target = targets[*w] && *w != result.source;
+        let coeff = (is_target as u8 as f64 + delta[*w]) / result.sigma[*w];
+        for v in result.P[*w].iter() {
+            delta[*v] += result.sigma[*v] * coeff;
+        }
+        if *w != result.source {
+            betweenness[*w] += delta[*w];
+        }
+    }
+}
+
+/// Runs a single-source shortest-paths search from each of `sources`, in parallel for larger
+/// graphs, and accumulates the results into `betweenness`.
+pub(super) fn accumulate_from_sources<T, A, B, F>(
+    graph: &Graph<T, A>,
+    sources: &[usize],
+    weighted: bool,
+    betweenness: B,
+    accumulate: F,
+) -> B
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    B: Send,
+    F: Fn(&mut B, &SingleSourceResults) + Sync,
+{
+    let min_weights = match weighted {
+        true => get_min_weights(graph),
+        false => None,
+    };
+    let parallel = sources.len() > 20 && rayon::current_num_threads() > 1;
+    let betweenness_mutex = std::sync::Mutex::new(betweenness);
+    match parallel {
+        true => {
+            sources
+                .par_iter()
+                .map(|source| match weighted {
+                    true => dijkstra(graph, *source, min_weights.as_deref()),
+                    false => bfs(graph, *source),
+                })
+                .for_each(|r| {
+                    let mut betweenness = betweenness_mutex.lock().unwrap();
+                    accumulate(&mut betweenness, &r);
+                });
+        }
+        false => {
+            for source in sources.iter() {
+                let source_source_results = match weighted {
+                    true => dijkstra(graph, *source, min_weights.as_deref()),
+                    false => bfs(graph, *source),
+                };
+                let mut betweenness = betweenness_mutex.lock().unwrap();
+                accumulate(&mut betweenness, &source_source_results);
+            }
+        }
+    }
+    betweenness_mutex.into_inner().unwrap()
+}
+
+/// Returns the indexes of `nodes`, and whether each node of `graph` is one of them.
+fn get_node_indexes<T, A>(
+    graph: &Graph<T, A>,
+    nodes: &[T],
+) -> Result<(Vec<usize>, Vec<bool>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let indexes = nodes
+        .iter()
+        .map(|n| graph.get_node_index(n))
+        .collect::<Result<Vec<usize>, Error>>()?;
+    let mut marked = vec![false; graph.number_of_nodes()];
+    for i in indexes.iter() {
+        marked[*i] = true;
+    }
+    Ok((indexes, marked))
+}
+
+pub(super) fn to_node_map<T, A>(graph: &Graph<T, A>, betweenness: Vec<f64>) -> HashMap<T, f64>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    betweenness
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (graph.get_node_by_index(&i).unwrap().name.clone(), v))
+        .collect()
+}
+
+/// Returns the edge betweenness centralities of all the pairs of adjacent nodes, keyed by node
+/// index, and the scale to multiply them by.
+fn get_edge_betweenness<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+) -> (HashMap<(usize, usize), f64>, f64)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let sources: Vec<usize> = (0..graph.number_of_nodes()).collect();
+    let directed = graph.specs.directed;
+    let betweenness = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        HashMap::new(),
+        |betweenness, result| accumulate_edge_betweenness(betweenness, result, directed),
+    );
+    let scale = get_edge_scale(graph.number_of_nodes(), normalized, directed).unwrap_or(1.0);
+    (betweenness, scale)
+}
+
+/// Returns the weight of the lightest of the parallel edges from `u` to `v`, which is the
+/// weight that the shortest paths between them use.
+fn get_min_weight<T, A>(graph: &Graph<T, A>, u: usize, v: usize) -> f64
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    graph
+        .get_edges_by_indexes(u, v)
+        .unwrap()
+        .iter()
+        .map(|edge| edge.weight)
+        .reduce(f64::min)
+        .unwrap()
+}
+
+/// Returns, for multigraphs, the weight of the lightest of the parallel edges from each node
+/// to each of its successors, in the order of `get_successor_nodes_by_index`; the graph keeps
+/// the weight of the first of the parallel edges added for the pair of nodes. Returns `None`
+/// for graphs without parallel edges.
+pub(super) fn get_min_weights<T, A>(graph: &Graph<T, A>) -> Option<Vec<Vec<f64>>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    if !graph.specs.multi_edges {
+        return None;
+    }
+    let min_weights = (0..graph.number_of_nodes())
+        .map(|u| {
+            graph
+                .get_successor_nodes_by_index(&u)
+                .iter()
+                .map(|adj| get_min_weight(graph, u, adj.node_index))
+                .collect()
+        })
+        .collect();
+    Some(min_weights)
+}
+
+fn get_name<T, A>(graph: &Graph<T, A>, index: usize) -> T
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    graph.get_node_by_index(&index).unwrap().name.clone()
+}
+
+/// Returns the edge betweenness centralities, multiplied by `scale`, keyed by edge name; edges
+/// on no counted shortest path have a value of zero.
+fn to_edge_map<T, A>(
+    graph: &Graph<T, A>,
+    betweenness: HashMap<(usize, usize), f64>,
+    scale: f64,
+) -> HashMap<(T, T), f64>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let mut hm: HashMap<(T, T), f64> = graph
+        .get_all_edges()
+        .into_iter()
+        .map(|edge| ((edge.u.clone(), edge.v.clone()), 0.0))
+        .collect();
+    for ((v, w), value) in betweenness.into_iter() {
+        let v_name = graph.get_node_by_index(&v).unwrap().name.clone();
+        let w_name = graph.get_node_by_index(&w).unwrap().name.clone();
+        let key = match !graph.specs.directed && v_name > w_name {
+            true => (w_name, v_name),
+            false => (v_name, w_name),
+        };
+        hm.insert(key, value * scale);
+    }
+    hm
+}
+
 fn rescale(betweeneess: &mut Vec<f64>, num_nodes: usize, normalized: bool, directed: bool) {
     let scale = get_scale(num_nodes, normalized, directed);
     if scale.is_some() {
@@ -218,6 +1042,44 @@ fn rescale(betweeneess: &mut Vec<f64>, num_nodes: usize, normalized: bool, direc
     }
 }
 
+/// Scales sampled betweenness centralities, from the shortest paths from `sources` only,
+/// to estimate the betweenness centralities from all the nodes.
+fn rescale_sampled(betweenness: &mut [f64], sources: &[usize], normalized: bool, directed: bool) {
+    let num_nodes = betweenness.len();
+    if num_nodes <= 2 {
+        return;
+    }
+    let (source_scale, other_scale) =
+        get_sampled_scales(num_nodes, sources.len(), normalized, directed);
+    let mut scales = vec![other_scale; num_nodes];
+    for source in sources {
+        scales[*source] = source_scale;
+    }
+    for (value, scale) in betweenness.iter_mut().zip(scales) {
+        *value *= scale;
+    }
+}
+
+/// Returns the scales of the betweenness centralities of the `k` sampled source nodes and of
+/// the other nodes; a source node isn't a source of paths through itself. With a single
+/// sample there are no other sources for the sampled node, and NetworkX gives it `nan`; as a
+/// deliberate deviation, every node is scaled by `1 / k` instead, so that it gets `0`.
+fn get_sampled_scales(num_nodes: usize, k: usize, normalized: bool, directed: bool) -> (f64, f64) {
+    let n = num_nodes as f64 - 1.0;
+    let k = k as f64;
+    let other_scale = 1.0 / k;
+    let source_scale = match k > 1.0 {
+        true => 1.0 / (k - 1.0),
+        false => other_scale,
+    };
+    let scale = match (normalized, directed) {
+        (true, _) => 1.0 / (n - 1.0),
+        (false, true) => n,
+        (false, false) => n / 2.0,
+    };
+    (source_scale * scale, other_scale * scale)
+}
+
 #[inline]
 fn get_scale(num_nodes: usize, normalized: bool, directed: bool) -> Option<f64> {
     match normalized {
@@ -232,6 +1094,20 @@ fn get_scale(num_nodes: usize, normalized: bool, directed: bool) -> Option<f64>
     }
 }
 
+#[inline]
+fn get_edge_scale(num_nodes: usize, normalized: bool, directed: bool) -> Option<f64> {
+    match normalized {
+        true => match num_nodes <= 1 {
+            true => None,
+            false => Some(1.0 / (num_nodes as f64 * (num_nodes as f64 - 1.0))),
+        },
+        false => match directed {
+            true => None,
+            false => Some(0.5),
+        },
+    }
+}
+
 // tests for private methods only; other tests are in:
 // tests/test_algorithms_centrality_betweenness
 #[cfg(test)]
@@ -268,4 +1144,47 @@ mod tests {
         let result = get_scale(10, false, false).unwrap();
         assert_eq!(result, 0.5);
     }
+
+    #[test]
+    fn test_get_edge_scale_1() {
+        let result = get_edge_scale(10, true, true).unwrap();
+        assert_eq!(result, 1.0 / 90.0);
+    }
+
+    #[test]
+    fn test_get_edge_scale_2() {
+        let result = get_edge_scale(1, true, false);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_get_edge_scale_3() {
+        let result = get_edge_scale(10, false, false).unwrap();
+        assert_eq!(result, 0.5);
+    }
+
+    #[test]
+    fn test_get_sampled_scales_1() {
+        // sampling every node gives the same scale as not sampling
+        let (source_scale, _) = get_sampled_scales(10, 10, true, true);
+        assert_eq!(source_scale, 1.0 / 72.0);
+        let (source_scale, _) = get_sampled_scales(10, 10, false, false);
+        assert_eq!(source_scale, 0.5);
+    }
+
+    #[test]
+    fn test_get_sampled_scales_2() {
+        let (source_scale, other_scale) = get_sampled_scales(10, 4, false, true);
+        assert_eq!(source_scale, 3.0);
+        assert_eq!(other_scale, 9.0 / 4.0);
+        let (source_scale, other_scale) = get_sampled_scales(10, 1, false, true);
+        assert_eq!(source_scale, 9.0);
+        assert_eq!(other_scale, 9.0);
+    }
+
+    #[test]
+    fn test_get_number_of_samples_1() {
+        assert_eq!(get_number_of_samples(2.0, 0.1, 0.1), 166);
+        assert_eq!(get_number_of_samples(10.0, 0.1, 0.1), 316);
+    }
 }
@@ -1,11 +1,11 @@
 #![allow(non_snake_case)]
 
 use super::fringe_node::{push_fringe_node, FringeNode};
-use crate::{Error, Graph};
+use crate::{Error, ErrorKind, Graph};
 use nohash::{IntMap, IntSet};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::collections::BinaryHeap;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
@@ -64,7 +64,9 @@ where
                 .into_par_iter()
                 .for_each(|source| {
                     let shortest_paths = match weighted {
-                        true => single_source_shortest_path_length_weighted(the_graph, source),
+                        true => {
+                            single_source_shortest_path_length_weighted(the_graph, source, None)
+                        }
                         false => single_source_shortest_path_length_unweighted(the_graph, source),
                     };
                     let cc = get_node_centrality(&shortest_paths, num_nodes, wf_improved);
@@ -76,7 +78,7 @@ where
         false => {
             for source in 0..the_graph.number_of_nodes() {
                 let shortest_paths = match weighted {
-                    true => single_source_shortest_path_length_weighted(the_graph, source),
+                    true => single_source_shortest_path_length_weighted(the_graph, source, None),
                     false => single_source_shortest_path_length_unweighted(the_graph, source),
                 };
                 let cc = get_node_centrality(&shortest_paths, num_nodes, wf_improved);
@@ -90,7 +92,133 @@ where
     Ok(centralities)
 }
 
-fn single_source_shortest_path_length_unweighted<T, A>(
+/**
+Compute the closeness centrality of the nodes of an undirected graph after an edge is added to
+it, from their closeness centrality before.
+
+Only the nodes whose distances to the ends of the new edge differ by more than one can get
+closer to other nodes; the closeness centrality of the other nodes is copied from
+`prev_closeness`, and that of these nodes is recomputed. Edge weights are not used. `graph`
+is not changed.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../../struct.Graph.html) instance, without the new edge
+* `u`: the name of one end of the new edge
+* `v`: the name of the other end of the new edge
+* `prev_closeness`: the closeness centrality of every node of `graph`, as computed by
+  [closeness_centrality](./fn.closeness_centrality.html) without edge weights; use `None` to
+  compute the closeness centrality of every node
+* `wf_improved`: if `true`, scale by the fraction of nodes reachable; this gives the
+  Wasserman and Faust improved formula. For single component graphs it is the same as the
+  original formula.
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{closeness}}, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3)];
+let graph: Graph<i32, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let before = closeness::closeness_centrality(&graph, false, true).unwrap();
+let after =
+    closeness::incremental_closeness_centrality(&graph, 0, 3, Some(&before), true).unwrap();
+assert_eq!(after[&0], 0.75);
+```
+
+# References
+
+1. Ahmet Erdem Sariyüce, Kamer Kaya, Erik Saule and Ümit V. Çatalyürek: Incremental Algorithms
+   for Closeness Centrality. IEEE International Conference on Big Data, 2013.
+   <https://doi.org/10.1109/BigData.2013.6691611>
+*/
+pub fn incremental_closeness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    u: T,
+    v: T,
+    prev_closeness: Option<&HashMap<T, f64>>,
+    wf_improved: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let new_edge = (graph.get_node_index(&u)?, graph.get_node_index(&v)?);
+    let num_nodes = graph.number_of_nodes();
+    if let Some(prev_closeness) = prev_closeness {
+        let same_nodes = prev_closeness.len() == num_nodes
+            && graph
+                .get_all_node_names()
+                .into_iter()
+                .all(|n| prev_closeness.contains_key(n));
+        if !same_nodes {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "`prev_closeness` and `graph` do not have the same nodes.".to_string(),
+            });
+        }
+    }
+    // the distances before the edge is added
+    let du = get_distances_with_edge(graph, new_edge.0, None);
+    let dv = get_distances_with_edge(graph, new_edge.1, None);
+    Ok((0..num_nodes)
+        .into_par_iter()
+        .map(|n| {
+            let name = graph.get_node_by_index(&n).unwrap().name.clone();
+            if let (Some(prev_closeness), Some(d1), Some(d2)) = (prev_closeness, du[n], dv[n]) {
+                if d1.abs_diff(d2) <= 1 {
+                    return (name.clone(), prev_closeness[&name]);
+                }
+            }
+            let shortest_paths: Vec<(usize, f64)> =
+                get_distances_with_edge(graph, n, Some(new_edge))
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(w, d)| d.map(|d| (w, d as f64)))
+                    .collect();
+            (
+                name,
+                get_node_centrality(&shortest_paths, num_nodes, wf_improved),
+            )
+        })
+        .collect())
+}
+
+/// Returns the number of edges on the shortest path from `source` to each node, if there is a
+/// path, in the graph with `extra_edge` added to it.
+fn get_distances_with_edge<T, A>(
+    graph: &Graph<T, A>,
+    source: usize,
+    extra_edge: Option<(usize, usize)>,
+) -> Vec<Option<usize>>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut distances = vec![None; graph.number_of_nodes()];
+    distances[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(w) = queue.pop_front() {
+        let d = distances[w].unwrap() + 1;
+        let extra = match extra_edge {
+            Some((a, b)) if a == w => Some(b),
+            Some((a, b)) if b == w => Some(a),
+            _ => None,
+        };
+        let successors = graph.get_successor_nodes_by_index(&w).iter();
+        for x in successors.map(|adj| adj.node_index).chain(extra) {
+            if distances[x].is_none() {
+                distances[x] = Some(d);
+                queue.push_back(x);
+            }
+        }
+    }
+    distances
+}
+
+pub(super) fn single_source_shortest_path_length_unweighted<T, A>(
     graph: &Graph<T, A>,
     source: usize,
 ) -> Vec<(usize, f64)>
@@ -128,9 +256,14 @@ where
     results
 }
 
-fn single_source_shortest_path_length_weighted<T, A>(
+/// Returns the weighted shortest path length from `source` to each node it can reach. For
+/// multigraphs, `min_weights` gives the weight of the lightest of the parallel edges to each
+/// successor, as returned by `get_min_weights`; with `None`, the weight kept by the graph for
+/// each pair of nodes is used.
+pub(super) fn single_source_shortest_path_length_weighted<T, A>(
     graph: &Graph<T, A>,
     source: usize,
+    min_weights: Option<&[Vec<f64>]>,
 ) -> Vec<(usize, f64)>
 where
     T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
@@ -159,9 +292,12 @@ where
         }
         sigma[v] += sigma[pred];
         D[v] = dist;
-        for adj in graph.get_successor_nodes_by_index(&v) {
+        for (i, adj) in graph.get_successor_nodes_by_index(&v).iter().enumerate() {
             let w = adj.node_index;
-            let cost = adj.weight;
+            let cost = match min_weights {
+                Some(min_weights) => min_weights[v][i],
+                None => adj.weight,
+            };
             let vw_dist = dist + cost;
             if D[w] == f64::MAX && (seen[w] == f64::MAX || vw_dist < seen[w]) {
                 seen[w] = vw_dist;
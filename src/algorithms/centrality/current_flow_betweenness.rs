@@ -0,0 +1,278 @@
+use super::laplacian_solver::{GroundedLaplacian, LaplacianSolver};
+use crate::algorithms::community::utility::get_rng;
+use crate::algorithms::components::number_of_connected_components;
+use crate::{Error, ErrorKind, Graph};
+use rand::prelude::*;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the current-flow betweenness centrality for nodes.
+
+Current-flow betweenness centrality, also known as random-walk betweenness centrality, treats
+the graph as an electrical network with the edges as resistors, with conductances of the edge
+weights. The centrality of a node is the sum, over all pairs of other nodes, of the current
+that passes through it when a unit current is sent between the pair. Unlike shortest-path
+betweenness centrality every route between the pair carries some of the current.
+
+One system of linear equations in the Laplacian matrix is solved for each edge, in parallel.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights as the conductances of the edges; otherwise
+  every edge has a conductance of `1`; the conductances of parallel edges add up
+* `normalized`: set to `true` to normalize the node centrality values by
+  `2 / ((n - 1) * (n - 2))`, where `n` is the number of nodes
+* `solver`: the method used to solve the systems of linear equations
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{current_flow_betweenness, LaplacianSolver}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = current_flow_betweenness::current_flow_betweenness_centrality(
+    &graph,
+    false,
+    true,
+    LaplacianSolver::ConjugateGradient,
+)
+.unwrap();
+assert_eq!((centralities[&0] * 1000.0).round() / 1000.0, 0.486);
+```
+
+# References
+
+1. Ulrik Brandes and Daniel Fleischer: Centrality Measures Based on Current Flow.
+   Proc. 22nd Symp. Theoretical Aspects of Computer Science (STACS '05), LNCS 3404:533-544, 2005.
+   <https://doi.org/10.1007/978-3-540-31856-9_44>
+
+2. Mark E. J. Newman: A measure of betweenness centrality based on random walks.
+   Social Networks 27(1):39-54, 2005.
+   <https://doi.org/10.1016/j.socnet.2004.11.009>
+*/
+pub fn current_flow_betweenness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    solver: LaplacianSolver,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    if let Some(centralities) = check_graph(graph)? {
+        return Ok(centralities);
+    }
+    let laplacian = GroundedLaplacian::new(graph, weighted, solver)?;
+    let edges: Vec<(usize, usize, f64)> = (0..n)
+        .flat_map(|u| {
+            laplacian
+                .get_neighbors(u)
+                .iter()
+                .filter(move |(v, _)| u < *v)
+                .map(move |(v, w)| (u, *v, *w))
+        })
+        .collect();
+    let betweenness = edges
+        .into_par_iter()
+        .try_fold(
+            || vec![0.0; n],
+            |mut betweenness, (u, v, w)| {
+                // the current through the edge when a unit current enters at each node and
+                // leaves at the grounded node
+                let mut b = vec![0.0; n];
+                b[u] = w;
+                b[v] = -w;
+                let row = laplacian.solve(&b)?;
+                // the sum over all pairs of the absolute difference of their currents is
+                // found from the rank of each current
+                let mut order: Vec<usize> = (0..n).collect();
+                order.sort_by(|i, j| row[*j].total_cmp(&row[*i]));
+                let mut pos = vec![0; n];
+                for (rank, i) in order.into_iter().enumerate() {
+                    pos[i] = rank;
+                }
+                for i in 0..n {
+                    betweenness[u] += (i as f64 - pos[i] as f64) * row[i];
+                    betweenness[v] += ((n - i - 1) as f64 - pos[i] as f64) * row[i];
+                }
+                Ok(betweenness)
+            },
+        )
+        .try_reduce(
+            || vec![0.0; n],
+            |a, b| Ok(a.into_iter().zip(b).map(|(x, y)| x + y).collect()),
+        )?;
+    let nb = match normalized {
+        true => (n as f64 - 1.0) * (n as f64 - 2.0),
+        false => 2.0,
+    };
+    Ok(betweenness
+        .into_iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let name = graph.get_node_by_index(&i).unwrap().name.clone();
+            (name, (b - i as f64) * 2.0 / nb)
+        })
+        .collect())
+}
+
+/**
+Estimate the current-flow betweenness centrality for nodes by sampling pairs of nodes.
+
+Random pairs of distinct nodes are chosen, and the current through each node when a unit
+current is sent between each pair is measured. With probability at least `1 - 1 / n` the
+normalized estimates are within `epsilon` of the exact values. The number of samples is
+proportional to `log(n) / epsilon^2`, and they are solved in parallel.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights as the conductances of the edges; otherwise
+  every edge has a conductance of `1`; the conductances of parallel edges add up
+* `normalized`: set to `true` to normalize the node centrality values by
+  `2 / ((n - 1) * (n - 2))`, where `n` is the number of nodes
+* `solver`: the method used to solve the systems of linear equations
+* `epsilon`: the largest error of the normalized estimates
+* `kmax`: the largest number of samples to take; an error is returned if more are needed. Use
+  `None` to use the default value of `10000`.
+* `seed`: the seed for choosing the sampled pairs of nodes; use `None` for a random seed
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{current_flow_betweenness, LaplacianSolver}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+    &graph,
+    false,
+    true,
+    LaplacianSolver::ConjugateGradient,
+    1.0,
+    None,
+    Some(1),
+)
+.unwrap();
+```
+
+# References
+
+1. Ulrik Brandes and Daniel Fleischer: Centrality Measures Based on Current Flow.
+   Proc. 22nd Symp. Theoretical Aspects of Computer Science (STACS '05), LNCS 3404:533-544, 2005.
+   <https://doi.org/10.1007/978-3-540-31856-9_44>
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn approximate_current_flow_betweenness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    solver: LaplacianSolver,
+    epsilon: f64,
+    kmax: Option<usize>,
+    seed: Option<u64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let kmax = kmax.unwrap_or(10000);
+    if epsilon <= 0.0 || epsilon.is_nan() {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`epsilon` must be positive.".to_string(),
+        });
+    }
+    if let Some(centralities) = check_graph(graph)? {
+        return Ok(centralities);
+    }
+    let n = graph.number_of_nodes();
+    let nb = (n as f64 - 1.0) * (n as f64 - 2.0);
+    let cstar = n as f64 * (n as f64 - 1.0) / nb;
+    let k = ((cstar / epsilon).powi(2) * (n as f64).ln()).ceil() as usize;
+    if k > kmax {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The number of samples needed, {}, is greater than `kmax`; increase `kmax` or `epsilon`.",
+                k
+            ),
+        });
+    }
+    let laplacian = GroundedLaplacian::new(graph, weighted, solver)?;
+    let mut rng = get_rng(seed);
+    let pairs: Vec<(usize, usize)> = (0..k)
+        .map(|_| {
+            let s = rng.gen_range(0..n);
+            (s, (s + rng.gen_range(1..n)) % n)
+        })
+        .collect();
+    let scale = cstar / (2.0 * k as f64);
+    let betweenness = pairs
+        .into_par_iter()
+        .try_fold(
+            || vec![0.0; n],
+            |mut betweenness, (s, t)| {
+                let mut b = vec![0.0; n];
+                b[s] = 1.0;
+                b[t] = -1.0;
+                let potentials = laplacian.solve(&b)?;
+                for v in (0..n).filter(|v| *v != s && *v != t) {
+                    let current: f64 = laplacian
+                        .get_neighbors(v)
+                        .iter()
+                        .map(|(u, w)| w * (potentials[v] - potentials[*u]).abs())
+                        .sum();
+                    betweenness[v] += current * scale;
+                }
+                Ok(betweenness)
+            },
+        )
+        .try_reduce(
+            || vec![0.0; n],
+            |a, b| Ok(a.into_iter().zip(b).map(|(x, y)| x + y).collect()),
+        )?;
+    let factor = match normalized {
+        true => 1.0,
+        false => nb / 2.0,
+    };
+    Ok(betweenness
+        .into_iter()
+        .enumerate()
+        .map(|(i, b)| {
+            (
+                graph.get_node_by_index(&i).unwrap().name.clone(),
+                b * factor,
+            )
+        })
+        .collect())
+}
+
+/// Returns an error if `graph` isn't undirected and connected; returns the centralities if
+/// they are all zero because the graph has fewer than three nodes.
+fn check_graph<T, A>(graph: &Graph<T, A>) -> Result<Option<HashMap<T, f64>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if graph.number_of_nodes() > 0 && number_of_connected_components(graph)? != 1 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not connected.".to_string(),
+        });
+    }
+    if graph.number_of_nodes() < 3 {
+        return Ok(Some(
+            graph
+                .get_all_nodes()
+                .iter()
+                .map(|node| (node.name.clone(), 0.0))
+                .collect(),
+        ));
+    }
+    Ok(None)
+}
@@ -0,0 +1,98 @@
+use super::laplacian_solver::{GroundedLaplacian, LaplacianSolver};
+use crate::algorithms::components::number_of_connected_components;
+use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the current-flow closeness centrality for nodes.
+
+Current-flow closeness centrality, which is the same as information centrality, treats the
+graph as an electrical network with the edges as resistors, with conductances of the edge
+weights. The centrality of a node is the reciprocal of the sum of the effective resistances
+between it and every other node; unlike shortest-path closeness centrality it accounts for
+every route between the nodes. A graph with a single node gives it a centrality of `0`.
+
+One system of linear equations in the Laplacian matrix is solved for each node, in parallel.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights as the conductances of the edges; otherwise
+  every edge has a conductance of `1`; the conductances of parallel edges add up
+* `solver`: the method used to solve the systems of linear equations
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{current_flow_closeness, LaplacianSolver}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = current_flow_closeness::current_flow_closeness_centrality(
+    &graph,
+    false,
+    LaplacianSolver::ConjugateGradient,
+)
+.unwrap();
+assert_eq!((centralities[&33] * 1000.0).round() / 1000.0, 0.059);
+```
+
+# References
+
+1. Ulrik Brandes and Daniel Fleischer: Centrality Measures Based on Current Flow.
+   Proc. 22nd Symp. Theoretical Aspects of Computer Science (STACS '05), LNCS 3404:533-544, 2005.
+   <https://doi.org/10.1007/978-3-540-31856-9_44>
+
+2. Karen Stephenson and Marvin Zelen: Rethinking centrality: Methods and examples.
+   Social Networks 11(1):1-37, 1989.
+   <https://doi.org/10.1016/0378-8733(89)90016-6>
+*/
+pub fn current_flow_closeness_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    solver: LaplacianSolver,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    if number_of_connected_components(graph)? != 1 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not connected.".to_string(),
+        });
+    }
+    if n == 1 {
+        let name = graph.get_node_by_index(&0).unwrap().name.clone();
+        return Ok(HashMap::from([(name, 0.0)]));
+    }
+    let laplacian = GroundedLaplacian::new(graph, weighted, solver)?;
+    // with `C` the inverse of the grounded Laplacian matrix, the effective resistance between
+    // `v` and `w` is `C[v][v] + C[w][w] - 2 * C[v][w]`
+    let row_sums = laplacian.solve(&vec![1.0; n])?;
+    let diagonal = (0..n)
+        .into_par_iter()
+        .map(|v| match v {
+            0 => Ok(0.0),
+            _ => {
+                let mut b = vec![0.0; n];
+                b[v] = 1.0;
+                Ok(laplacian.solve(&b)?[v])
+            }
+        })
+        .collect::<Result<Vec<f64>, Error>>()?;
+    let trace: f64 = diagonal.iter().sum();
+    Ok((0..n)
+        .map(|v| {
+            let resistances = n as f64 * diagonal[v] + trace - 2.0 * row_sums[v];
+            let name = graph.get_node_by_index(&v).unwrap().name.clone();
+            (name, 1.0 / resistances)
+        })
+        .collect())
+}
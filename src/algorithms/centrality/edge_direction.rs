@@ -0,0 +1,33 @@
+use crate::{AdjacentNode, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Specifies which edges of a directed graph pass centrality to a node: those that point to it or
+those that point away from it. Undirected edges are used in both cases.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeDirection {
+    /// A node's centrality comes from the nodes with edges to it.
+    Incoming,
+    /// A node's centrality comes from the nodes it has edges to.
+    Outgoing,
+}
+
+impl EdgeDirection {
+    /// Returns the nodes that pass centrality to the node at `node_index`.
+    pub(crate) fn get_adjacent_nodes<'a, T, A>(
+        &self,
+        graph: &'a Graph<T, A>,
+        node_index: &usize,
+    ) -> &'a Vec<AdjacentNode>
+    where
+        T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+        A: Clone + Send + Sync,
+    {
+        match self {
+            EdgeDirection::Incoming => graph.get_predecessors_or_neighbors_by_index(node_index),
+            EdgeDirection::Outgoing => graph.get_successor_nodes_by_index(node_index),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use super::EdgeDirection;
 use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::hash::Hash;
@@ -10,36 +12,46 @@ Eigenvector centrality computes the centrality for a node based on the
 centrality of its neighbors. Eigenvector centrality is commonly described
 as a measure of the influence of a node in the graph.
 
+The centralities are found by power iteration, with each sparse matrix-vector
+product computed in parallel. An `ErrorKind::PowerIterationFailedConvergence`
+error is returned if they don't converge within `max_iter` iterations.
+
 # Arguments
 
-* `graph`: a [Graph](../../../struct.Graph.html) instance
-* `weighted`: set to `true` to use edge weights when computing the betweenness centrality
+* `graph`: a [Graph](../../../struct.Graph.html) instance; as in NetworkX, multigraphs aren't
+  supported and give a `WrongMethod` error
+* `weighted`: set to `true` to use edge weights when computing the eigenvector centrality
+* `direction`: for directed graphs, whether a node's centrality comes from its predecessors
+  (`EdgeDirection::Incoming`, the left eigenvector of the adjacency matrix) or its successors
+  (`EdgeDirection::Outgoing`, the right eigenvector)
 * `max_iter`: the maximum number of iterations in power method; use `None` to use the default
-value of `100`
+  value of `100`
 * `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
-use the default value of `1.0e-6`
+  use the default value of `1.0e-6`
 
 # Examples
 
 ```
-use graphrs::{algorithms::{centrality::{eigenvector}}, generators};
+use graphrs::{algorithms::{centrality::{eigenvector, EdgeDirection}}, generators};
 let graph = generators::social::karate_club_graph();
-let centralities = eigenvector::eigenvector_centrality(&graph, false, None, None);
+let centralities =
+    eigenvector::eigenvector_centrality(&graph, false, EdgeDirection::Incoming, None, None);
 ```
 
 # References
 
 1. Phillip Bonacich. "Power and Centrality: A Family of Measures."
-*American Journal of Sociology* 92(5):1170–1182, 1986
-<http://www.leonidzhukov.net/hse/2014/socialnetworks/papers/Bonacich-Centrality.pdf>
+   *American Journal of Sociology* 92(5):1170–1182, 1986
+   <http://www.leonidzhukov.net/hse/2014/socialnetworks/papers/Bonacich-Centrality.pdf>
 
 2. Mark E. J. Newman.
-*Networks: An Introduction.*
-Oxford University Press, USA, 2010, pp. 169.
+   *Networks: An Introduction.*
+   Oxford University Press, USA, 2010, pp. 169.
 */
 pub fn eigenvector_centrality<T, A>(
     graph: &Graph<T, A>,
     weighted: bool,
+    direction: EdgeDirection,
     max_iter: Option<u32>,
     tolerance: Option<f64>,
 ) -> Result<HashMap<T, f64>, Error>
@@ -47,39 +59,43 @@ where
     T: Hash + Eq + Clone + Ord + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let _max_iter = max_iter.unwrap_or(100);
-    let _tolerance = tolerance.unwrap_or(1.0e-6);
-    let nnodes = graph.get_all_nodes().len();
-    let mut x: HashMap<T, f64> = graph
-        .get_all_nodes()
-        .iter()
-        .map(|n| (n.name.clone(), 1.0 / nnodes as f64))
-        .collect();
-    for _i in 0.._max_iter {
-        let xlast = x.clone();
-        for n in xlast.keys() {
-            for nbr in graph.get_successors_or_neighbors(n.clone()) {
-                let edge = graph.get_edge(n.clone(), nbr.name.clone()).unwrap();
-                let w = match !weighted || edge.weight.is_nan() {
-                    true => 1.0,
-                    false => edge.weight,
-                };
-                *x.get_mut(&nbr.name).unwrap() += xlast.get(n).unwrap() * w;
-            }
+    graph.ensure_not_multi_edges()?;
+    let max_iter = max_iter.unwrap_or(100);
+    let tolerance = tolerance.unwrap_or(1.0e-6);
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    let get_weight = |weight: f64| match !weighted || weight.is_nan() {
+        true => 1.0,
+        false => weight,
+    };
+    let mut x = vec![1.0 / n as f64; n];
+    for _i in 0..max_iter {
+        let xlast = x;
+        // iterate with A + I rather than A, which converges on bipartite graphs too
+        x = (0..n)
+            .into_par_iter()
+            .map(|v| {
+                xlast[v]
+                    + direction
+                        .get_adjacent_nodes(graph, &v)
+                        .iter()
+                        .map(|adj| xlast[adj.node_index] * get_weight(adj.weight))
+                        .sum::<f64>()
+            })
+            .collect();
+        let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            x.iter_mut().for_each(|v| *v /= norm);
         }
-        let mut norm: f64 = x.values().map(|v| v.powf(2.0)).sum();
-        norm = norm.sqrt();
-        norm = match norm == 0.0 {
-            true => 1.0,
-            false => norm,
-        };
-        x.values_mut().for_each(|v| *v /= norm);
-        let y: f64 = x
-            .iter()
-            .map(|(k, v)| (v - xlast.get(k).unwrap()).abs())
-            .sum();
-        if y < (nnodes as f64 * _tolerance) {
-            return Ok(x);
+        let err: f64 = x.iter().zip(xlast.iter()).map(|(a, b)| (a - b).abs()).sum();
+        if err < n as f64 * tolerance {
+            return Ok(x
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), c))
+                .collect());
         }
     }
     Err(Error {
@@ -0,0 +1,106 @@
+use super::betweenness::get_min_weights;
+use super::closeness::{
+    single_source_shortest_path_length_unweighted, single_source_shortest_path_length_weighted,
+};
+use crate::{Error, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+/**
+Compute harmonic centrality for nodes.
+
+Harmonic centrality of a node u is the sum of the reciprocal of the shortest path distances
+from all other nodes to u. Nodes that can't reach u add nothing to the sum, so unlike closeness
+centrality it is well defined for disconnected graphs.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the harmonic centrality; in
+  multigraphs the lightest of the parallel edges between two nodes is used
+* `nodes`: the nodes to compute the harmonic centrality for; use `None` for all nodes
+* `sources`: the nodes whose distances to `nodes` are added up; use `None` for all nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{harmonic}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = harmonic::harmonic_centrality(&graph, false, None, None).unwrap();
+assert_eq!(centralities[&33], 23.25);
+```
+
+# References
+
+1. Yannick Rochat: Closeness centrality extended to unconnected graphs: The harmonic
+   centrality index. Applications of Social Network Analysis, 2009.
+   <http://infoscience.epfl.ch/record/200525>
+
+2. Paolo Boldi and Sebastiano Vigna: Axioms for centrality. Internet Mathematics
+   10(3-4):222-262, 2014.
+   <https://doi.org/10.1080/15427951.2013.865686>
+*/
+pub fn harmonic_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    nodes: Option<&[T]>,
+    sources: Option<&[T]>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let node_indexes = get_node_indexes(graph, nodes)?;
+    let source_indexes = get_node_indexes(graph, sources)?;
+    let min_weights = match weighted {
+        true => get_min_weights(graph),
+        false => None,
+    };
+    let centralities = source_indexes
+        .into_par_iter()
+        .fold(
+            || vec![0.0; num_nodes],
+            |mut centralities, source| {
+                let shortest_paths = match weighted {
+                    true => single_source_shortest_path_length_weighted(
+                        graph,
+                        source,
+                        min_weights.as_deref(),
+                    ),
+                    false => single_source_shortest_path_length_unweighted(graph, source),
+                };
+                for (v, distance) in shortest_paths {
+                    if distance > 0.0 {
+                        centralities[v] += 1.0 / distance;
+                    }
+                }
+                centralities
+            },
+        )
+        .reduce(
+            || vec![0.0; num_nodes],
+            |a, b| a.into_iter().zip(b).map(|(x, y)| x + y).collect(),
+        );
+    Ok(node_indexes
+        .into_iter()
+        .map(|v| {
+            let name = graph.get_node_by_index(&v).unwrap().name.clone();
+            (name, centralities[v])
+        })
+        .collect())
+}
+
+/// Returns the indexes of `nodes`, or of all the nodes if `nodes` is `None`.
+fn get_node_indexes<T, A>(graph: &Graph<T, A>, nodes: Option<&[T]>) -> Result<Vec<usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    match nodes {
+        None => Ok((0..graph.number_of_nodes()).collect()),
+        Some(nodes) => nodes.iter().map(|n| graph.get_node_index(n)).collect(),
+    }
+}
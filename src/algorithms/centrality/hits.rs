@@ -0,0 +1,158 @@
+use super::pagerank::get_distribution;
+use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the hub and authority scores of the nodes in the graph with the HITS algorithm.
+
+A good hub is a node with edges to many good authorities, and a good authority is a node with
+edges from many good hubs. The authority scores are proportional to the principal eigenvector
+of *A^T A* and the hub scores to that of *A A^T*, where *A* is the adjacency matrix; they are
+found by power iteration, with the sparse matrix-vector products computed in parallel. For
+undirected graphs the hub and authority scores are the same.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance; as in NetworkX, multigraphs aren't
+  supported and give a `WrongMethod` error
+* `weighted`: set to `true` to use edge weights when computing the scores
+* `normalized`: set to `true` to scale the hub and the authority scores to each sum to one;
+  otherwise the largest of each is one
+* `nstart`: the starting hub score of each node, which is normalized to sum to one; nodes that
+  aren't given start at zero. Use `None` to start every node at the same value.
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `100`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-8`
+
+# Returns
+
+A tuple of the hub scores and the authority scores.
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{hits}}, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n3"),
+    Edge::new("n2", "n3"),
+    Edge::new("n2", "n4"),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+let (hubs, authorities) = hits::hits(&graph, false, true, None, None, None).unwrap();
+assert!(hubs[&"n2"] > hubs[&"n1"]);
+assert!(authorities[&"n3"] > authorities[&"n4"]);
+assert_eq!(authorities[&"n1"], 0.0);
+```
+
+# References
+
+1. Jon Kleinberg: Authoritative sources in a hyperlinked environment. Journal of the ACM
+   46(5):604-632, 1999.
+   <https://doi.org/10.1145/324133.324140>
+
+2. Amy N. Langville and Carl D. Meyer: A Survey of Eigenvector Methods for Web Information
+   Retrieval. SIAM Review 47(1):135-161, 2005.
+   <https://doi.org/10.1137/S0036144503424786>
+*/
+#[allow(clippy::type_complexity)]
+pub fn hits<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    nstart: Option<&HashMap<T, f64>>,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<(HashMap<T, f64>, HashMap<T, f64>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    let max_iter = max_iter.unwrap_or(100);
+    let tolerance = tolerance.unwrap_or(1.0e-8);
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok((HashMap::new(), HashMap::new()));
+    }
+    let mut hubs = match nstart {
+        None => vec![1.0 / n as f64; n],
+        Some(values) => get_distribution(graph, values, "nstart")?,
+    };
+    let get_weight = |weight: f64| match !weighted || weight.is_nan() {
+        true => 1.0,
+        false => weight,
+    };
+    for _i in 0..max_iter {
+        let hubs_last = hubs;
+        let mut authorities: Vec<f64> = (0..n)
+            .into_par_iter()
+            .map(|v| {
+                graph
+                    .get_predecessors_or_neighbors_by_index(&v)
+                    .iter()
+                    .map(|adj| hubs_last[adj.node_index] * get_weight(adj.weight))
+                    .sum()
+            })
+            .collect();
+        hubs = (0..n)
+            .into_par_iter()
+            .map(|u| {
+                graph
+                    .get_successor_nodes_by_index(&u)
+                    .iter()
+                    .map(|adj| authorities[adj.node_index] * get_weight(adj.weight))
+                    .sum()
+            })
+            .collect();
+        scale_to_max(&mut hubs);
+        scale_to_max(&mut authorities);
+        let err: f64 = hubs
+            .iter()
+            .zip(hubs_last.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        if err < tolerance {
+            if normalized {
+                scale_to_sum(&mut hubs);
+                scale_to_sum(&mut authorities);
+            }
+            return Ok((to_map(graph, hubs), to_map(graph, authorities)));
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
+
+fn scale_to_max(values: &mut [f64]) {
+    let max = values.iter().cloned().fold(0.0, f64::max);
+    if max > 0.0 {
+        values.iter_mut().for_each(|v| *v /= max);
+    }
+}
+
+fn scale_to_sum(values: &mut [f64]) {
+    let sum: f64 = values.iter().sum();
+    if sum > 0.0 {
+        values.iter_mut().for_each(|v| *v /= sum);
+    }
+}
+
+fn to_map<T, A>(graph: &Graph<T, A>, values: Vec<f64>) -> HashMap<T, f64>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (graph.get_node_by_index(&i).unwrap().name.clone(), v))
+        .collect()
+}
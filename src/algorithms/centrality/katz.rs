@@ -0,0 +1,136 @@
+use super::EdgeDirection;
+use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute Katz centrality for nodes.
+
+Katz centrality generalizes eigenvector centrality: the centrality of a node is `alpha` times
+the sum of the centralities of its neighbors, plus `beta`. Every node gets some centrality,
+so unlike eigenvector centrality it is useful for directed acyclic graphs too. `alpha` must be
+less than the reciprocal of the largest eigenvalue of the adjacency matrix for the iteration
+to converge.
+
+The centralities are found by power iteration, with each sparse matrix-vector product computed
+in parallel. An `ErrorKind::PowerIterationFailedConvergence` error is returned if they don't
+converge within `max_iter` iterations.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance; as in NetworkX, multigraphs aren't
+  supported and give a `WrongMethod` error
+* `weighted`: set to `true` to use edge weights when computing the Katz centrality
+* `alpha`: the attenuation factor; use `None` to use the default value of `0.1`
+* `beta`: the weight given to each node regardless of its neighbors, which must be given for
+  every node; use `None` to give every node a weight of `1.0`
+* `direction`: for directed graphs, whether a node's centrality comes from its predecessors
+  (`EdgeDirection::Incoming`) or its successors (`EdgeDirection::Outgoing`)
+* `normalized`: set to `true` to scale the centralities to a Euclidean norm of one
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `1000`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-6`
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{katz, EdgeDirection}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = katz::katz_centrality(
+    &graph,
+    false,
+    Some(0.1),
+    None,
+    EdgeDirection::Incoming,
+    true,
+    None,
+    None,
+)
+.unwrap();
+assert_eq!((centralities[&33] * 1000.0).round() / 1000.0, 0.331);
+```
+
+# References
+
+1. Leo Katz: A New Status Index Derived from Sociometric Index.
+   *Psychometrika* 18(1):39–43, 1953
+   <https://link.springer.com/content/pdf/10.1007/BF02289026.pdf>
+
+2. Mark E. J. Newman.
+   *Networks: An Introduction.*
+   Oxford University Press, USA, 2010, p. 720.
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn katz_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    alpha: Option<f64>,
+    beta: Option<&HashMap<T, f64>>,
+    direction: EdgeDirection,
+    normalized: bool,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    let alpha = alpha.unwrap_or(0.1);
+    let max_iter = max_iter.unwrap_or(1000);
+    let tolerance = tolerance.unwrap_or(1.0e-6);
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    let beta: Vec<f64> = match beta {
+        None => vec![1.0; n],
+        Some(beta) => (0..n)
+            .map(|i| {
+                let name = &graph.get_node_by_index(&i).unwrap().name;
+                beta.get(name).cloned().ok_or_else(|| Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: format!("No `beta` value was given for node {}.", name),
+                })
+            })
+            .collect::<Result<Vec<f64>, Error>>()?,
+    };
+    let get_weight = |weight: f64| match !weighted || weight.is_nan() {
+        true => 1.0,
+        false => weight,
+    };
+    let mut x = vec![0.0; n];
+    for _i in 0..max_iter {
+        let xlast = x;
+        x = (0..n)
+            .into_par_iter()
+            .map(|v| {
+                let neighbors_sum: f64 = direction
+                    .get_adjacent_nodes(graph, &v)
+                    .iter()
+                    .map(|adj| xlast[adj.node_index] * get_weight(adj.weight))
+                    .sum();
+                alpha * neighbors_sum + beta[v]
+            })
+            .collect();
+        let err: f64 = x.iter().zip(xlast.iter()).map(|(a, b)| (a - b).abs()).sum();
+        if err < n as f64 * tolerance {
+            let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+            if normalized && norm > 0.0 {
+                x.iter_mut().for_each(|v| *v /= norm);
+            }
+            return Ok(x
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), c))
+                .collect());
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
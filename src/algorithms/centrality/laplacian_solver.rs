@@ -0,0 +1,272 @@
+use crate::{Error, ErrorKind, Graph};
+#[cfg(feature = "adjacency_matrix")]
+use nalgebra::{DMatrix, DVector, LU};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The method used to solve the systems of linear equations in the Laplacian matrix of a graph,
+for the current-flow centralities.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaplacianSolver {
+    /// A preconditioned conjugate-gradient solver that only uses the edges of the graph, so it
+    /// needs little memory and suits large, sparse graphs.
+    ConjugateGradient,
+    /// A dense LU decomposition of the Laplacian matrix, computed once; faster for smaller
+    /// graphs. Requires the `adjacency_matrix` feature: without it, an `InvalidArgument`
+    /// error is returned.
+    Lu,
+}
+
+/// The Laplacian matrix of a connected, undirected graph with the node at index `0` grounded:
+/// its row and column are removed so that the rest of the matrix is invertible.
+pub(super) struct GroundedLaplacian {
+    neighbors: Vec<Vec<(usize, f64)>>,
+    degrees: Vec<f64>,
+    #[cfg(feature = "adjacency_matrix")]
+    lu: Option<LU<f64, nalgebra::Dyn, nalgebra::Dyn>>,
+}
+
+impl GroundedLaplacian {
+    pub fn new<T, A>(
+        graph: &Graph<T, A>,
+        weighted: bool,
+        solver: LaplacianSolver,
+    ) -> Result<GroundedLaplacian, Error>
+    where
+        T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+        A: Clone,
+    {
+        let neighbors: Vec<Vec<(usize, f64)>> = (0..graph.number_of_nodes())
+            .map(|u| {
+                graph
+                    .get_successor_nodes_by_index(&u)
+                    .iter()
+                    .filter(|adj| adj.node_index != u)
+                    .map(|adj| {
+                        let conductance =
+                            get_conductance(graph, u, adj.node_index, adj.weight, weighted);
+                        (adj.node_index, conductance)
+                    })
+                    .collect()
+            })
+            .collect();
+        let degrees: Vec<f64> = neighbors
+            .iter()
+            .map(|adjacent| adjacent.iter().map(|(_, w)| w).sum())
+            .collect();
+        #[cfg(feature = "adjacency_matrix")]
+        let lu = match solver {
+            LaplacianSolver::Lu => Some(get_lu(&neighbors, &degrees)),
+            LaplacianSolver::ConjugateGradient => None,
+        };
+        #[cfg(not(feature = "adjacency_matrix"))]
+        if solver == LaplacianSolver::Lu {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "The LU solver requires the \"adjacency_matrix\" feature.".to_string(),
+            });
+        }
+        Ok(GroundedLaplacian {
+            neighbors,
+            degrees,
+            #[cfg(feature = "adjacency_matrix")]
+            lu,
+        })
+    }
+
+    /// Returns the weighted neighbors of the node at index `u`, without self-loops.
+    pub fn get_neighbors(&self, u: usize) -> &[(usize, f64)] {
+        &self.neighbors[u]
+    }
+
+    /**
+    Returns the potentials `x`, with `x[0] = 0`, such that `L x = b` in every row but the
+    first, where `L` is the Laplacian matrix; `b[0]` isn't used. If the values of `b` sum to
+    zero, the first row is satisfied too.
+    */
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, Error> {
+        #[cfg(feature = "adjacency_matrix")]
+        if let Some(lu) = &self.lu {
+            let rhs = DVector::from_column_slice(&b[1..]);
+            return match lu.solve(&rhs) {
+                Some(x) => Ok(std::iter::once(0.0).chain(x.iter().cloned()).collect()),
+                None => Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: "The Laplacian matrix is singular.".to_string(),
+                }),
+            };
+        }
+        self.solve_cg(b)
+    }
+
+    /// Solves with the conjugate-gradient method, with the diagonal of the Laplacian matrix
+    /// as the preconditioner.
+    fn solve_cg(&self, b: &[f64]) -> Result<Vec<f64>, Error> {
+        let n = self.degrees.len();
+        let mut x = vec![0.0; n];
+        let mut r: Vec<f64> = b.to_vec();
+        r[0] = 0.0;
+        let b_norm = norm(&r);
+        if b_norm == 0.0 {
+            return Ok(x);
+        }
+        let tolerance = 1.0e-10 * b_norm;
+        let mut z: Vec<f64> = self.precondition(&r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+        for _i in 0..(10 * n).max(100) {
+            let q = self.multiply(&p);
+            let alpha = rz / dot(&p, &q);
+            for i in 1..n {
+                x[i] += alpha * p[i];
+                r[i] -= alpha * q[i];
+            }
+            if norm(&r) < tolerance {
+                return Ok(x);
+            }
+            z = self.precondition(&r);
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            rz = rz_next;
+            for i in 1..n {
+                p[i] = z[i] + beta * p[i];
+            }
+        }
+        Err(Error {
+            kind: ErrorKind::SolverFailedConvergence,
+            message: "The conjugate-gradient solver failed to converge.".to_string(),
+        })
+    }
+
+    /// Returns `L x` for the grounded Laplacian matrix `L`, with a zero in the first row.
+    fn multiply(&self, x: &[f64]) -> Vec<f64> {
+        (0..x.len())
+            .map(|u| match u {
+                0 => 0.0,
+                _ => {
+                    self.degrees[u] * x[u]
+                        - self.neighbors[u]
+                            .iter()
+                            .filter(|(v, _)| *v != 0)
+                            .map(|(v, w)| w * x[*v])
+                            .sum::<f64>()
+                }
+            })
+            .collect()
+    }
+
+    fn precondition(&self, r: &[f64]) -> Vec<f64> {
+        r.iter()
+            .zip(self.degrees.iter())
+            .enumerate()
+            .map(|(u, (r, d))| match u == 0 || *d == 0.0 {
+                true => 0.0,
+                false => r / d,
+            })
+            .collect()
+    }
+}
+
+/// Returns the conductance between the nodes at indexes `u` and `v`, where `weight` is the
+/// weight of the edge that the graph keeps for them: the total weight of the parallel edges
+/// between them in multigraphs, as in the Laplacian matrix NetworkX builds. Without `weighted`,
+/// or for edges without a weight, every edge has a conductance of one.
+pub(super) fn get_conductance<T, A>(
+    graph: &Graph<T, A>,
+    u: usize,
+    v: usize,
+    weight: f64,
+    weighted: bool,
+) -> f64
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone,
+{
+    let get_weight = |weight: f64| match !weighted || weight.is_nan() {
+        true => 1.0,
+        false => weight,
+    };
+    match graph.specs.multi_edges {
+        false => get_weight(weight),
+        true => graph
+            .get_edges_by_indexes(u, v)
+            .unwrap()
+            .iter()
+            .map(|edge| get_weight(edge.weight))
+            .sum(),
+    }
+}
+
+#[cfg(feature = "adjacency_matrix")]
+fn get_lu(
+    neighbors: &[Vec<(usize, f64)>],
+    degrees: &[f64],
+) -> LU<f64, nalgebra::Dyn, nalgebra::Dyn> {
+    let n = degrees.len();
+    let mut matrix = DMatrix::<f64>::zeros(n - 1, n - 1);
+    for u in 1..n {
+        matrix[(u - 1, u - 1)] = degrees[u];
+        for (v, w) in neighbors[u].iter().filter(|(v, _)| *v != 0) {
+            matrix[(u - 1, v - 1)] -= w;
+        }
+    }
+    matrix.lu()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::generators;
+
+    #[test]
+    fn test_solve_cg() {
+        let graph = generators::social::karate_club_graph();
+        let laplacian =
+            GroundedLaplacian::new(&graph, true, LaplacianSolver::ConjugateGradient).unwrap();
+        let mut b = vec![0.0; 34];
+        b[5] = 1.0;
+        b[20] = -1.0;
+        let x = laplacian.solve(&b).unwrap();
+        assert_eq!(x[0], 0.0);
+        let lx = laplacian.multiply(&x);
+        for i in 1..34 {
+            assert!((lx[i] - b[i]).abs() < 1e-8);
+        }
+    }
+
+    #[cfg(feature = "adjacency_matrix")]
+    #[test]
+    fn test_solve_lu() {
+        let graph = generators::social::karate_club_graph();
+        let cg = GroundedLaplacian::new(&graph, true, LaplacianSolver::ConjugateGradient).unwrap();
+        let lu = GroundedLaplacian::new(&graph, true, LaplacianSolver::Lu).unwrap();
+        let b: Vec<f64> = (0..34).map(|i| (i % 5) as f64 - 2.0).collect();
+        let x_cg = cg.solve(&b).unwrap();
+        let x_lu = lu.solve(&b).unwrap();
+        for (a, b) in x_cg.iter().zip(x_lu.iter()) {
+            assert!((a - b).abs() < 1e-8);
+        }
+    }
+
+    #[cfg(not(feature = "adjacency_matrix"))]
+    #[test]
+    fn test_lu_without_feature() {
+        let graph = generators::social::karate_club_graph();
+        let result = GroundedLaplacian::new(&graph, true, LaplacianSolver::Lu);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
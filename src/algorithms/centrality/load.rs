@@ -0,0 +1,91 @@
+use super::betweenness::{accumulate_from_sources, to_node_map, SingleSourceResults};
+use crate::{Error, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the load centrality for nodes.
+
+The load centrality of a node is the fraction of all shortest paths that pass through it,
+counted the way Newman describes: a unit of flow is sent from every node to every other node,
+and at each node on the way the flow is split equally between the predecessors that lie on a
+shortest path back to the source. It is similar to, but not the same as, betweenness
+centrality, where the flow is split in proportion to the number of shortest paths.
+
+Unlike betweenness centrality, the unnormalized values for undirected graphs aren't halved,
+because the flow from `u` to `v` and the flow from `v` to `u` are both counted.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the load centrality
+* `normalized`: set to `true` to divide the load centralities by `(n - 1)(n - 2)`, where `n`
+  is the number of nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{load}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = load::load_centrality(&graph, false, true).unwrap();
+assert_eq!((centralities[&0] * 1000.0).round() / 1000.0, 0.435);
+```
+
+# References
+
+1. Mark E. J. Newman: Scientific collaboration networks. II. Shortest paths, weighted
+   networks, and centrality. Physical Review E 64, 016132, 2001.
+   <https://doi.org/10.1103/PhysRevE.64.016132>
+
+2. Kwang-Il Goh, Byungnam Kahng and Doochul Kim: Universal Behavior of Load Distribution in
+   Scale-Free Networks. Physical Review Letters 87(27):278701, 2001.
+   <https://doi.org/10.1103/PhysRevLett.87.278701>
+*/
+pub fn load_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let sources: Vec<usize> = (0..num_nodes).collect();
+    let mut load = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        vec![0.0; num_nodes],
+        |load: &mut Vec<f64>, result: &SingleSourceResults| accumulate_load(load, result),
+    );
+    if normalized && num_nodes > 2 {
+        let scale = 1.0 / ((num_nodes - 1) * (num_nodes - 2)) as f64;
+        load.iter_mut().for_each(|v| *v *= scale);
+    }
+    Ok(to_node_map(graph, load))
+}
+
+/// Adds the load that the shortest paths from the source of `result` put on each node to
+/// `load`; the share of the flow that is routed to the source itself isn't counted.
+fn accumulate_load(load: &mut [f64], result: &SingleSourceResults) {
+    let mut between = vec![0.0; load.len()];
+    for v in result.S.iter() {
+        between[*v] = 1.0;
+    }
+    for w in result.S.iter().rev() {
+        if *w == result.source {
+            continue;
+        }
+        let share = between[*w] / result.P[*w].len() as f64;
+        for x in result.P[*w].iter() {
+            if *x != result.source {
+                between[*x] += share;
+            }
+        }
+    }
+    for v in result.S.iter() {
+        load[*v] += between[*v] - 1.0;
+    }
+}
@@ -4,14 +4,47 @@ pub mod betweenness;
 /// Compute closeness centrality of nodes and edges.
 pub mod closeness;
 
+/// Compute current-flow betweenness centrality of nodes.
+pub mod current_flow_betweenness;
+
+/// Compute current-flow closeness centrality of nodes.
+pub mod current_flow_closeness;
+
 /// Compute degree centrality of nodes and edges.
 pub mod degree;
 
+mod edge_direction;
+pub use edge_direction::*;
+
 /// Compute eigenvector centrality of nodes and edges.
 pub mod eigenvector;
 
 /// Compute centrality measures for groups of nodes.
 pub mod groups;
 
+/// Compute harmonic centrality of nodes.
+pub mod harmonic;
+
+/// Compute the hub and authority scores of nodes.
+pub mod hits;
+
+/// Compute Katz centrality of nodes.
+pub mod katz;
+
+mod laplacian_solver;
+pub use laplacian_solver::LaplacianSolver;
+
+/// Compute load centrality of nodes.
+pub mod load;
+
+/// Compute the PageRank of nodes.
+pub mod pagerank;
+
+/// Compute percolation centrality of nodes.
+pub mod percolation;
+
+/// Compute second order centrality of nodes.
+pub mod second_order;
+
 /// Structs and functions for `BinaryHeap` fringe - for Dijkstra functions.
-mod fringe_node;
+pub(crate) mod fringe_node;
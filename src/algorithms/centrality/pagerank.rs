@@ -0,0 +1,192 @@
+use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the PageRank of the nodes in the graph.
+
+PageRank ranks nodes by the structure of their incoming edges: it is the probability that a
+random walk that follows an edge with probability `alpha`, and otherwise jumps to a node
+chosen by the `personalization` distribution, is at a node. Walks that reach a node with no
+outgoing edges (a dangling node) jump to a node chosen by the `dangling` distribution.
+
+Undirected edges are followed in both directions. The scores are found by power iteration;
+each iteration is a sparse matrix-vector product over the incoming edges of the nodes, which is
+computed in parallel.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when choosing which edge a random walk follows;
+  in multigraphs the weights of parallel edges are added up, and without `weighted` each
+  parallel edge counts once
+* `alpha`: the damping factor, between `0` and `1`; use `None` to use the default value of `0.85`
+* `personalization`: the probability of jumping to each node, which is normalized to sum to
+  one; nodes that aren't given have a probability of zero. Use `None` to jump to every node with
+  equal probability.
+* `dangling`: the probability of jumping to each node from a dangling node, which is normalized
+  to sum to one; nodes that aren't given have a probability of zero. Use `None` to use the
+  `personalization` distribution.
+* `max_iter`: the maximum number of iterations in power method; use `None` to use the default
+  value of `100`
+* `tolerance`: the error tolerance used to check convergence in power method iteration; use `None` to
+  use the default value of `1.0e-6`
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{pagerank}}, generators};
+let graph = generators::social::karate_club_graph();
+let ranks = pagerank::pagerank(&graph, false, None, None, None, None, None).unwrap();
+assert_eq!((ranks[&33] * 1000.0).round() / 1000.0, 0.101);
+```
+
+# References
+
+1. Amy N. Langville and Carl D. Meyer: A Survey of Eigenvector Methods for Web Information
+   Retrieval. SIAM Review 47(1):135-161, 2005.
+   <https://doi.org/10.1137/S0036144503424786>
+
+2. Lawrence Page, Sergey Brin, Rajeev Motwani and Terry Winograd: The PageRank citation ranking:
+   Bringing order to the Web. Stanford Digital Library Technologies Project, 1999.
+   <http://dbpubs.stanford.edu:8090/pub/showDoc.Fulltext?lang=en&doc=1999-66&format=pdf>
+*/
+pub fn pagerank<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    alpha: Option<f64>,
+    personalization: Option<&HashMap<T, f64>>,
+    dangling: Option<&HashMap<T, f64>>,
+    max_iter: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let alpha = alpha.unwrap_or(0.85);
+    let max_iter = max_iter.unwrap_or(100);
+    let tolerance = tolerance.unwrap_or(1.0e-6);
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "`alpha` must be between 0 and 1.".to_string(),
+        });
+    }
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    let personalization = match personalization {
+        None => vec![1.0 / n as f64; n],
+        Some(values) => get_distribution(graph, values, "personalization")?,
+    };
+    let dangling_weights = match dangling {
+        None => personalization.clone(),
+        Some(values) => get_distribution(graph, values, "dangling")?,
+    };
+    let get_weight = |weight: f64| match !weighted || weight.is_nan() {
+        true => 1.0,
+        false => weight,
+    };
+    // the total weight of the edges from `u` to `v`, adding up parallel edges as NetworkX does
+    let get_total_weight = |u: usize, v: usize, weight: f64| match graph.specs.multi_edges {
+        false => get_weight(weight),
+        true => graph
+            .get_edges_by_indexes(u, v)
+            .unwrap()
+            .iter()
+            .map(|edge| get_weight(edge.weight))
+            .sum(),
+    };
+    // the reciprocal of the total weight of each node's outgoing edges; zero for dangling nodes
+    let out_scale: Vec<f64> = (0..n)
+        .map(|u| {
+            let total: f64 = graph
+                .get_successor_nodes_by_index(&u)
+                .iter()
+                .map(|adj| get_total_weight(u, adj.node_index, adj.weight))
+                .sum();
+            match total == 0.0 {
+                true => 0.0,
+                false => 1.0 / total,
+            }
+        })
+        .collect();
+    // the incoming edges of each node, as the source node and the total weight of the edges
+    let incoming: Vec<Vec<(usize, f64)>> = (0..n)
+        .map(|v| {
+            graph
+                .get_predecessors_or_neighbors_by_index(&v)
+                .iter()
+                .map(|adj| {
+                    (
+                        adj.node_index,
+                        get_total_weight(adj.node_index, v, adj.weight),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    let dangling_nodes: Vec<usize> = (0..n).filter(|u| out_scale[*u] == 0.0).collect();
+    let mut x = vec![1.0 / n as f64; n];
+    for _i in 0..max_iter {
+        let dangling_sum: f64 = dangling_nodes.iter().map(|u| x[*u]).sum();
+        let xlast = x;
+        x = (0..n)
+            .into_par_iter()
+            .map(|v| {
+                let walked: f64 = incoming[v]
+                    .iter()
+                    .map(|(u, weight)| xlast[*u] * weight * out_scale[*u])
+                    .sum();
+                alpha * (walked + dangling_sum * dangling_weights[v])
+                    + (1.0 - alpha) * personalization[v]
+            })
+            .collect();
+        let err: f64 = x.iter().zip(xlast.iter()).map(|(a, b)| (a - b).abs()).sum();
+        if err < n as f64 * tolerance {
+            return Ok(x
+                .into_iter()
+                .enumerate()
+                .map(|(i, r)| (graph.get_node_by_index(&i).unwrap().name.clone(), r))
+                .collect());
+        }
+    }
+    Err(Error {
+        kind: ErrorKind::PowerIterationFailedConvergence,
+        message: "failed to converge to the specified tolerance within the specified number of iterations.".to_string(),
+    })
+}
+
+/// Returns `values` as a vector indexed by node, normalized to sum to one.
+pub(super) fn get_distribution<T, A>(
+    graph: &Graph<T, A>,
+    values: &HashMap<T, f64>,
+    argument: &str,
+) -> Result<Vec<f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut distribution = vec![0.0; graph.number_of_nodes()];
+    for (name, value) in values {
+        if *value < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("The `{}` value of node {} is negative.", argument, name),
+            });
+        }
+        distribution[graph.get_node_index(name)?] = *value;
+    }
+    let total: f64 = distribution.iter().sum();
+    if total == 0.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!("The `{}` values must not all be zero.", argument),
+        });
+    }
+    Ok(distribution.into_iter().map(|v| v / total).collect())
+}
@@ -0,0 +1,135 @@
+use super::betweenness::{accumulate_from_sources, to_node_map, SingleSourceResults};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the percolation centrality for nodes.
+
+Percolation centrality measures the importance of a node for spreading something, such as an
+infection or a failure, through the graph. Each node has a percolation state between `0` and
+`1`, such as the degree to which it is infected, and the shortest paths from each source node
+are weighted by the state of the source relative to the total state of the graph. With every
+node in the same state, the normalized percolation centrality is the same as the normalized
+betweenness centrality.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use edge weights when computing the percolation centrality
+* `normalized`: set to `true` to divide the percolation centralities by `n - 2`, where `n` is
+  the number of nodes, as in the definition of Piraveenan et al.
+* `states`: the percolation state of each node, between `0` and `1`, which must be given for
+  every node; use `None` to give every node a state of `1.0`
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{percolation}}, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+let edges = vec![
+    Edge::new(1, 4),
+    Edge::new(2, 4),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+    Edge::new(5, 6),
+    Edge::new(6, 7),
+    Edge::new(6, 8),
+];
+let graph: Graph<i32, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let states = HashMap::from([
+    (1, 0.1),
+    (2, 0.2),
+    (3, 0.2),
+    (4, 0.2),
+    (5, 0.3),
+    (6, 0.2),
+    (7, 0.5),
+    (8, 0.5),
+]);
+let centralities = percolation::percolation_centrality(&graph, false, true, Some(&states)).unwrap();
+assert_eq!((centralities[&4] * 1000.0).round() / 1000.0, 0.625);
+assert_eq!((centralities[&6] * 1000.0).round() / 1000.0, 0.667);
+```
+
+# References
+
+1. Mahendra Piraveenan, Mikhail Prokopenko and Liaquat Hossain: Percolation Centrality:
+   Quantifying Graph-Theoretic Impact of Nodes during Percolation in Networks.
+   PLoS ONE 8(1):e53095, 2013.
+   <https://doi.org/10.1371/journal.pone.0053095>
+*/
+pub fn percolation_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    states: Option<&HashMap<T, f64>>,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let states: Vec<f64> = match states {
+        None => vec![1.0; num_nodes],
+        Some(states) => (0..num_nodes)
+            .map(|i| {
+                let name = &graph.get_node_by_index(&i).unwrap().name;
+                match states.get(name) {
+                    None => Err(Error {
+                        kind: ErrorKind::InvalidArgument,
+                        message: format!("No `states` value was given for node {}.", name),
+                    }),
+                    Some(state) if !(0.0..=1.0).contains(state) => Err(Error {
+                        kind: ErrorKind::InvalidArgument,
+                        message: format!(
+                            "The `states` value of node {} is not between 0 and 1.",
+                            name
+                        ),
+                    }),
+                    Some(state) => Ok(*state),
+                }
+            })
+            .collect::<Result<Vec<f64>, Error>>()?,
+    };
+    let total_state: f64 = states.iter().sum();
+    let sources: Vec<usize> = (0..num_nodes).collect();
+    let mut percolation = accumulate_from_sources(
+        graph,
+        &sources,
+        weighted,
+        vec![0.0; num_nodes],
+        |percolation: &mut Vec<f64>, result: &SingleSourceResults| {
+            accumulate_percolation(percolation, result, &states, total_state)
+        },
+    );
+    if normalized && num_nodes > 2 {
+        let scale = 1.0 / (num_nodes - 2) as f64;
+        percolation.iter_mut().for_each(|v| *v *= scale);
+    }
+    Ok(to_node_map(graph, percolation))
+}
+
+/// Adds the dependencies of the nodes on the shortest paths from the source of `result` to
+/// `percolation`, weighted by the state of the source relative to the states of the graph.
+fn accumulate_percolation(
+    percolation: &mut [f64],
+    result: &SingleSourceResults,
+    states: &[f64],
+    total_state: f64,
+) {
+    let source_state = states[result.source];
+    let mut delta = vec![0.0; percolation.len()];
+    for w in result.S.iter().rev() {
+        let coeff = (1.0 + delta[*w]) / result.sigma[*w];
+        for v in result.P[*w].iter() {
+            delta[*v] += result.sigma[*v] * coeff;
+        }
+        if *w != result.source && source_state > 0.0 {
+            percolation[*w] += delta[*w] * source_state / (total_state - states[*w]);
+        }
+    }
+}
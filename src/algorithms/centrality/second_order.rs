@@ -0,0 +1,133 @@
+use super::laplacian_solver::{get_conductance, GroundedLaplacian, LaplacianSolver};
+use crate::algorithms::components::number_of_connected_components;
+use crate::{Error, ErrorKind, Graph};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Compute the second order centrality for nodes.
+
+The second order centrality of a node is the standard deviation of the return times to the
+node of a perpetual random walk on the graph. Self-loops are added to the nodes so that every
+node has the weighted degree of the node with the highest degree, which makes the walk visit
+every node equally often. Unlike the other centralities, a smaller value means a more central
+node: one that the walk visits more regularly.
+
+The return times are found from the effective resistances between the nodes, with one system of
+linear equations in the Laplacian matrix solved for each node, in parallel.
+
+# Arguments
+
+* `graph`: a connected, undirected [Graph](../../../struct.Graph.html) instance
+* `weighted`: set to `true` to use the edge weights, which must not be negative, to weight the
+  probabilities of the random walk following the edges; otherwise every edge is followed with
+  the same probability. Each of the parallel edges between two nodes can be followed, so their
+  weights add up.
+* `normalized`: set to `true` to divide the standard deviations by the mean return time; as
+  the walk visits every node equally often, by Kac's lemma the mean return time is `n`, the
+  number of nodes, for every node. With `M[u][v]` the mean time for the walk to go from `u` to
+  `v`, the value of `v` is then `sqrt(2 * sum(M[u][v] for every u) - n * (n + 1)) / n`, the
+  coefficient of variation of its return times, which can be compared between graphs of
+  different sizes; this normalization isn't part of NetworkX
+* `solver`: the method used to solve the systems of linear equations
+
+# Examples
+
+```
+use graphrs::{algorithms::{centrality::{second_order, LaplacianSolver}}, generators};
+let graph = generators::social::karate_club_graph();
+let centralities = second_order::second_order_centrality(
+    &graph,
+    false,
+    false,
+    LaplacianSolver::ConjugateGradient,
+)
+.unwrap();
+assert_eq!((centralities[&33] * 1000.0).round() / 1000.0, 49.209);
+```
+
+# References
+
+1. Anne-Marie Kermarrec, Erwan Le Merrer, Bruno Sericola and Gilles Trédan: Second order
+   centrality: Distributed assessment of nodes criticity in complex networks.
+   Computer Communications 34(5):619-628, 2011.
+   <https://doi.org/10.1016/j.comcom.2010.06.007>
+2. Mark Kac: On the notion of recurrence in discrete stochastic processes. Bulletin of the
+   American Mathematical Society 53(10):1002-1010, 1947.
+   <https://doi.org/10.1090/S0002-9904-1947-08927-8>
+*/
+pub fn second_order_centrality<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+    normalized: bool,
+    solver: LaplacianSolver,
+) -> Result<HashMap<T, f64>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    if number_of_connected_components(graph)? != 1 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not connected.".to_string(),
+        });
+    }
+    if weighted && graph.get_all_edges().iter().any(|edge| edge.weight < 0.0) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has negative edge weights.".to_string(),
+        });
+    }
+    if n == 1 {
+        let name = graph.get_node_by_index(&0).unwrap().name.clone();
+        return Ok(HashMap::from([(name, 0.0)]));
+    }
+    // self-loops count once towards the degree, as they're followed as a single edge
+    let max_degree = (0..n)
+        .map(|u| {
+            graph
+                .get_successor_nodes_by_index(&u)
+                .iter()
+                .map(|adj| get_conductance(graph, u, adj.node_index, adj.weight, weighted))
+                .sum::<f64>()
+        })
+        .fold(0.0, f64::max);
+    let laplacian = GroundedLaplacian::new(graph, weighted, solver)?;
+    // with `C` the inverse of the grounded Laplacian matrix, the diagonal of the pseudoinverse
+    // of the Laplacian matrix is `C[v][v] - 2 * rowsum(C)[v] / n + sum(C) / n^2`
+    let row_sums = laplacian.solve(&vec![1.0; n])?;
+    let diagonal = (0..n)
+        .into_par_iter()
+        .map(|v| match v {
+            0 => Ok(0.0),
+            _ => {
+                let mut b = vec![0.0; n];
+                b[v] = 1.0;
+                Ok(laplacian.solve(&b)?[v])
+            }
+        })
+        .collect::<Result<Vec<f64>, Error>>()?;
+    let total: f64 = row_sums.iter().sum();
+    let n = n as f64;
+    let scale = match normalized {
+        true => 1.0 / n,
+        false => 1.0,
+    };
+    Ok((0..diagonal.len())
+        .map(|v| {
+            let pseudoinverse = diagonal[v] - 2.0 * row_sums[v] / n + total / (n * n);
+            // the sum of the mean return times to `v` from every node is
+            // `n^2 * max_degree * pseudoinverse + n`
+            let variance = 2.0 * n * n * max_degree * pseudoinverse - n * n + n;
+            let name = graph.get_node_by_index(&v).unwrap().name.clone();
+            (name, variance.max(0.0).sqrt() * scale)
+        })
+        .collect())
+}
@@ -0,0 +1,380 @@
+use crate::{Error, Graph};
+use nohash::IntSet;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns an iterator over the maximal cliques of an undirected graph. A clique is a set of nodes
+that are all adjacent to each other; a maximal clique is one that isn't part of a larger clique.
+
+Uses the Bron-Kerbosch algorithm with the pivoting of Tomita et al., which takes O(3^(n/3))
+time in the worst case, the largest possible number of maximal cliques. The cliques are found
+as the iterator is advanced, so the search can be stopped early. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new(0, 1),
+    Edge::new(0, 2),
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let mut cliques: Vec<Vec<i32>> = clique::find_cliques(&graph)
+    .unwrap()
+    .map(|mut c| {
+        c.sort();
+        c
+    })
+    .collect();
+cliques.sort();
+assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3]]);
+```
+
+# References
+
+1. C. Bron and J. Kerbosch: Algorithm 457: finding all cliques of an undirected graph.
+   Communications of the ACM 16(9):575–577, 1973.
+2. E. Tomita, A. Tanaka and H. Takahashi: The worst-case time complexity for generating all
+   maximal cliques and computational experiments. Theoretical Computer Science 363(1):28–42,
+   2006.
+*/
+pub fn find_cliques<T, A>(graph: &Graph<T, A>) -> Result<impl Iterator<Item = Vec<T>> + '_, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let cliques = find_clique_indexes(graph)?;
+    Ok(cliques.map(|clique| {
+        clique
+            .into_iter()
+            .map(|i| graph.get_node_by_index(&i).unwrap().name.clone())
+            .collect()
+    }))
+}
+
+/**
+Returns an iterator over all the cliques of an undirected graph, in order of increasing size:
+the single nodes, then the edges, then the triangles and so on. Self-loops are ignored.
+
+There can be many more cliques than maximal cliques, and the cliques of each size are held in
+memory while the next size is enumerated, so this is only practical for sparse graphs or when
+the iterator is stopped early. See [find_cliques](./fn.find_cliques.html) for the maximal
+cliques.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::classic::complete_graph(4, false);
+let cliques: Vec<Vec<i32>> = clique::enumerate_all_cliques(&graph).unwrap().collect();
+assert_eq!(cliques.len(), 15);
+assert_eq!(cliques[0], vec![0]);
+assert_eq!(cliques[14], vec![0, 1, 2, 3]);
+```
+
+# References
+
+1. N. Zhang, Y. Tian, W. Wang et al.: Genome-scale computational approaches to
+   memory-intensive applications in systems biology. In: Proceedings of the ACM/IEEE
+   Conference on Supercomputing, 2005.
+*/
+pub fn enumerate_all_cliques<T, A>(
+    graph: &Graph<T, A>,
+) -> Result<impl Iterator<Item = Vec<T>> + '_, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    // only the neighbors with a larger index, so that each clique is found once
+    let later_neighbors: Vec<Vec<usize>> = get_adjacency(graph)
+        .into_iter()
+        .enumerate()
+        .map(|(u, neighbors)| {
+            let mut later: Vec<usize> = neighbors.into_iter().filter(|v| *v > u).collect();
+            later.sort_unstable();
+            later
+        })
+        .collect();
+    let queue: VecDeque<(Vec<usize>, Vec<usize>)> = (0..graph.number_of_nodes())
+        .map(|u| (vec![u], later_neighbors[u].clone()))
+        .collect();
+    let cliques = AllCliques {
+        later_neighbors: later_neighbors
+            .into_iter()
+            .map(|n| n.into_iter().collect())
+            .collect(),
+        queue,
+    };
+    Ok(cliques.map(|clique| {
+        clique
+            .into_iter()
+            .map(|i| graph.get_node_by_index(&i).unwrap().name.clone())
+            .collect()
+    }))
+}
+
+/**
+Returns, for each node, the size of the largest maximal clique that contains it.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the nodes to return values for
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+let result = clique::node_clique_number(&graph, Some(&[0, 9])).unwrap();
+assert_eq!(result[&0], 5);
+assert_eq!(result[&9], 2);
+```
+*/
+pub fn node_clique_number<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut numbers = vec![0; graph.number_of_nodes()];
+    for clique in find_clique_indexes(graph)? {
+        for u in clique.iter() {
+            numbers[*u] = numbers[*u].max(clique.len());
+        }
+    }
+    get_values_for_nodes(graph, node_names, numbers)
+}
+
+/**
+Returns, for each node, the number of maximal cliques that contain it.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `node_names`: node names that optionally define a subset of the nodes to return values for
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+let result = clique::number_of_cliques(&graph, None).unwrap();
+assert_eq!(result[&0], 13);
+assert_eq!(result[&33], 14);
+```
+*/
+pub fn number_of_cliques<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut numbers = vec![0; graph.number_of_nodes()];
+    for clique in find_clique_indexes(graph)? {
+        for u in clique.iter() {
+            numbers[*u] += 1;
+        }
+    }
+    get_values_for_nodes(graph, node_names, numbers)
+}
+
+/**
+Returns the neighbors of each node, not including the node itself.
+*/
+pub(super) fn get_adjacency<T, A>(graph: &Graph<T, A>) -> Vec<IntSet<usize>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes())
+        .map(|u| {
+            let mut neighbors = graph.get_neighbors_nodes_by_index(&u);
+            neighbors.remove(&u);
+            neighbors
+        })
+        .collect()
+}
+
+/**
+Returns the maximal cliques as node indexes.
+*/
+fn find_clique_indexes<T, A>(graph: &Graph<T, A>) -> Result<MaximalCliques, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let all: IntSet<usize> = (0..graph.number_of_nodes()).collect();
+    let mut cliques = MaximalCliques {
+        adjacency: get_adjacency(graph),
+        clique: vec![],
+        subgraph: IntSet::default(),
+        candidates: IntSet::default(),
+        extension: vec![],
+        stack: vec![],
+    };
+    if !all.is_empty() {
+        cliques.clique.push(usize::MAX);
+        cliques.extension = cliques.get_extension(&all, &all);
+        cliques.subgraph = all.clone();
+        cliques.candidates = all;
+    }
+    Ok(cliques)
+}
+
+fn get_values_for_nodes<T, A>(
+    graph: &Graph<T, A>,
+    node_names: Option<&[T]>,
+    values: Vec<usize>,
+) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    match node_names {
+        None => Ok(values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (graph.get_node_by_index(&i).unwrap().name.clone(), v))
+            .collect()),
+        Some(node_names) => node_names
+            .iter()
+            .map(|name| Ok((name.clone(), values[graph.get_node_index(name)?])))
+            .collect(),
+    }
+}
+
+/**
+The state of an iterative Bron-Kerbosch search for maximal cliques.
+*/
+struct MaximalCliques {
+    adjacency: Vec<IntSet<usize>>,
+    /// The clique being grown; the last node is the one being tried.
+    clique: Vec<usize>,
+    /// The nodes adjacent to every node of the clique before the last.
+    subgraph: IntSet<usize>,
+    /// The nodes of `subgraph` that haven't been tried yet.
+    candidates: IntSet<usize>,
+    /// The candidates that aren't neighbors of the pivot, which are still to be tried.
+    extension: Vec<usize>,
+    stack: Vec<(IntSet<usize>, IntSet<usize>, Vec<usize>)>,
+}
+
+impl MaximalCliques {
+    /// Chooses the pivot: the node of `subgraph` with the most neighbors among `candidates`.
+    /// Returns the candidates that aren't neighbors of the pivot.
+    fn get_extension(&self, subgraph: &IntSet<usize>, candidates: &IntSet<usize>) -> Vec<usize> {
+        let pivot = subgraph
+            .iter()
+            .max_by_key(|u| {
+                let neighbors = &self.adjacency[**u];
+                candidates.iter().filter(|v| neighbors.contains(v)).count()
+            })
+            .unwrap();
+        let mut extension: Vec<usize> = candidates
+            .iter()
+            .filter(|v| !self.adjacency[*pivot].contains(v))
+            .cloned()
+            .collect();
+        extension.sort_unstable_by(|a, b| b.cmp(a));
+        extension
+    }
+}
+
+impl Iterator for MaximalCliques {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        while !self.clique.is_empty() {
+            let q = match self.extension.pop() {
+                None => {
+                    self.clique.pop();
+                    match self.stack.pop() {
+                        None => return None,
+                        Some((subgraph, candidates, extension)) => {
+                            self.subgraph = subgraph;
+                            self.candidates = candidates;
+                            self.extension = extension;
+                        }
+                    }
+                    continue;
+                }
+                Some(q) => q,
+            };
+            self.candidates.remove(&q);
+            *self.clique.last_mut().unwrap() = q;
+            let neighbors = &self.adjacency[q];
+            let subgraph_q: IntSet<usize> = self
+                .subgraph
+                .iter()
+                .filter(|v| neighbors.contains(v))
+                .cloned()
+                .collect();
+            if subgraph_q.is_empty() {
+                return Some(self.clique.clone());
+            }
+            let candidates_q: IntSet<usize> = self
+                .candidates
+                .iter()
+                .filter(|v| neighbors.contains(v))
+                .cloned()
+                .collect();
+            if !candidates_q.is_empty() {
+                let extension_q = self.get_extension(&subgraph_q, &candidates_q);
+                let subgraph = std::mem::replace(&mut self.subgraph, subgraph_q);
+                let candidates = std::mem::replace(&mut self.candidates, candidates_q);
+                let extension = std::mem::replace(&mut self.extension, extension_q);
+                self.stack.push((subgraph, candidates, extension));
+                self.clique.push(usize::MAX);
+            }
+        }
+        None
+    }
+}
+
+/**
+The state of a breadth-first enumeration of all cliques. Each entry of `queue` is a clique and
+the nodes that could extend it, all with larger indexes than the clique's nodes.
+*/
+struct AllCliques {
+    later_neighbors: Vec<IntSet<usize>>,
+    queue: VecDeque<(Vec<usize>, Vec<usize>)>,
+}
+
+impl Iterator for AllCliques {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let (clique, candidates) = self.queue.pop_front()?;
+        for (i, u) in candidates.iter().enumerate() {
+            let mut larger = clique.clone();
+            larger.push(*u);
+            let larger_candidates = candidates[(i + 1)..]
+                .iter()
+                .filter(|v| self.later_neighbors[*u].contains(v))
+                .cloned()
+                .collect();
+            self.queue.push_back((larger, larger_candidates));
+        }
+        Some(clique)
+    }
+}
@@ -0,0 +1,159 @@
+use super::enumeration::get_adjacency;
+use crate::{Error, ErrorKind, Graph};
+use nohash::IntSet;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a clique of an undirected graph with the largest total node weight, and its weight.
+Without node weights every node has a weight of one, and the clique is a maximum clique: one
+with as many nodes as possible.
+
+Finding a maximum weight clique is NP-hard. The search is a branch and bound, which bounds the
+weight that a set of nodes can add to a clique by greedily splitting it into independent sets;
+it is practical for graphs of up to a few hundred nodes. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance
+* `node_weights`: the weight of each node, which can't be negative; optional. An
+  `ErrorKind::InvalidArgument` error is returned if a node has no weight or a negative weight.
+
+# Examples
+
+```
+use graphrs::{algorithms::clique, generators};
+let graph = generators::social::karate_club_graph();
+let (nodes, weight) = clique::max_weight_clique(&graph, None).unwrap();
+assert_eq!(weight, 5.0);
+assert_eq!(nodes.len(), 5);
+```
+
+# References
+
+1. D. Warren: Maximum weight clique search. In: Proceedings of the Principles and Practice of
+   Constraint Programming, 2016.
+*/
+pub fn max_weight_clique<T, A>(
+    graph: &Graph<T, A>,
+    node_weights: Option<&HashMap<T, f64>>,
+) -> Result<(Vec<T>, f64), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    let weights: Vec<f64> = match node_weights {
+        None => vec![1.0; n],
+        Some(node_weights) => (0..n)
+            .map(|i| {
+                let name = &graph.get_node_by_index(&i).unwrap().name;
+                match node_weights.get(name) {
+                    Some(w) if *w >= 0.0 => Ok(*w),
+                    Some(_) => Err(Error {
+                        kind: ErrorKind::InvalidArgument,
+                        message: format!("The weight of node {} is negative.", name),
+                    }),
+                    None => Err(Error {
+                        kind: ErrorKind::InvalidArgument,
+                        message: format!("No weight was given for node {}.", name),
+                    }),
+                }
+            })
+            .collect::<Result<Vec<f64>, Error>>()?,
+    };
+    let mut search = MaxWeightClique {
+        adjacency: get_adjacency(graph),
+        weights,
+        best: vec![],
+        best_weight: 0.0,
+    };
+    let mut nodes: Vec<usize> = (0..n).collect();
+    nodes.sort_by(|a, b| search.weights[*b].total_cmp(&search.weights[*a]));
+    search.expand(&mut vec![], 0.0, nodes);
+    let clique = search
+        .best
+        .iter()
+        .map(|i| graph.get_node_by_index(i).unwrap().name.clone())
+        .collect();
+    Ok((clique, search.best_weight))
+}
+
+/**
+The state of a branch and bound search for a maximum weight clique.
+*/
+struct MaxWeightClique {
+    adjacency: Vec<IntSet<usize>>,
+    weights: Vec<f64>,
+    best: Vec<usize>,
+    best_weight: f64,
+}
+
+impl MaxWeightClique {
+    /// Searches the cliques that extend `clique` with nodes from `candidates`, all of which are
+    /// adjacent to every node of `clique`.
+    fn expand(&mut self, clique: &mut Vec<usize>, weight: f64, mut candidates: Vec<usize>) {
+        if weight > self.best_weight {
+            self.best = clique.clone();
+            self.best_weight = weight;
+        }
+        let mut branching = self.get_branching_nodes(&candidates, self.best_weight - weight);
+        while let Some(v) = branching.pop() {
+            candidates.retain(|u| *u != v);
+            let neighbors = &self.adjacency[v];
+            let new_candidates = candidates
+                .iter()
+                .filter(|u| neighbors.contains(u))
+                .cloned()
+                .collect();
+            clique.push(v);
+            self.expand(clique, weight + self.weights[v], new_candidates);
+            clique.pop();
+        }
+    }
+
+    /**
+    Returns the candidates that must be branched on to find a clique that adds more than
+    `target` to the weight. The candidates are split greedily into independent sets, each of
+    which can add at most the weight of its heaviest node to a clique; once those bounds add up
+    to more than `target`, the remaining candidates must be branched on.
+    */
+    fn get_branching_nodes(&self, candidates: &[usize], target: f64) -> Vec<usize> {
+        let mut residual: HashMap<usize, f64> =
+            candidates.iter().map(|v| (*v, self.weights[*v])).collect();
+        let mut total = 0.0;
+        let mut remaining = candidates.to_vec();
+        while !remaining.is_empty() {
+            let independent_set = self.get_independent_set(&remaining);
+            let smallest = independent_set
+                .iter()
+                .map(|v| residual[v])
+                .min_by(|a, b| a.total_cmp(b))
+                .unwrap();
+            total += smallest;
+            if total > target {
+                break;
+            }
+            for v in independent_set {
+                *residual.get_mut(&v).unwrap() -= smallest;
+            }
+            remaining.retain(|v| residual[v] != 0.0);
+        }
+        remaining
+    }
+
+    fn get_independent_set(&self, nodes: &[usize]) -> Vec<usize> {
+        let mut independent_set: Vec<usize> = vec![];
+        for v in nodes.iter() {
+            if independent_set
+                .iter()
+                .all(|u| !self.adjacency[*u].contains(v))
+            {
+                independent_set.push(*v);
+            }
+        }
+        independent_set
+    }
+}
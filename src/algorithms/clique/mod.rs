@@ -0,0 +1,4 @@
+mod enumeration;
+pub use enumeration::*;
+mod max_weight;
+pub use max_weight::*;
@@ -0,0 +1,393 @@
+use super::greedy::{get_adjacency, get_colors, is_coloring};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns an equitable coloring of a graph with `num_colors` colors: a coloring in which no two
+adjacent nodes have the same color, and the numbers of nodes of any two colors differ by at most
+one. The colors are `0..num_colors`.
+
+By the Hajnal-Szemerédi theorem such a coloring exists if `num_colors` is greater than the
+largest degree in the graph; an `ErrorKind::InvalidArgument` error is returned otherwise. The
+coloring is found with the algorithm of Kierstead et al. in O(num_colors n²) time. The direction
+of edges is ignored and self-loops are ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `num_colors`: the number of colors to use
+
+# Examples
+
+```
+use graphrs::{algorithms::coloring, Edge, Graph, GraphSpecs};
+// split 20 jobs into 5 shifts of 4, keeping conflicting jobs apart
+let edges = (0..20).map(|i| Edge::new(i, (i + 1) % 20)).collect();
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let shifts = coloring::equitable_color(&graph, 5).unwrap();
+assert!(coloring::is_equitable(&graph, &shifts, Some(5)).unwrap());
+assert!((0..5).all(|s| shifts.values().filter(|c| **c == s).count() == 4));
+```
+
+# References
+
+1. H. A. Kierstead, A. V. Kostochka, M. Mydlarz and E. Szemerédi: A fast algorithm for
+   equitable coloring. Combinatorica 30(2):217–224, 2010.
+*/
+pub fn equitable_color<T, A>(
+    graph: &Graph<T, A>,
+    num_colors: usize,
+) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut adjacency = get_adjacency(graph);
+    let n = adjacency.len();
+    let max_degree = adjacency.iter().map(|a| a.len()).max().unwrap_or(0);
+    if max_degree >= num_colors {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The graph has a maximum degree of {}, so {} colors are needed for an \
+                 equitable coloring; {} were given.",
+                max_degree,
+                max_degree + 1,
+                num_colors
+            ),
+        });
+    }
+    // pad the graph with a clique so that its number of nodes is a multiple of num_colors;
+    // the clique's nodes have fewer than num_colors neighbors
+    if !n.is_multiple_of(num_colors) {
+        let padding = num_colors - n % num_colors;
+        for u in n..(n + padding) {
+            adjacency.push((n..(n + padding)).filter(|v| *v != u).collect());
+        }
+    }
+    let mut coloring = EquitableColoring::new(adjacency.len(), num_colors);
+    // add the edges of each node in turn, keeping the coloring equitable
+    for (u, neighbors) in adjacency.iter().enumerate() {
+        for v in neighbors.iter().filter(|v| **v > u) {
+            coloring.add_edge(u, *v);
+        }
+        if coloring.neighbor_counts[u][coloring.colors[u]] != 0 {
+            let x = coloring.colors[u];
+            let y = (0..num_colors)
+                .find(|k| coloring.neighbor_counts[u][*k] == 0)
+                .unwrap();
+            coloring.change_color(u, x, y);
+            coloring.balance(x, y, vec![false; num_colors]);
+        }
+    }
+    Ok(coloring.colors[..n]
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), *c))
+        .collect())
+}
+
+/**
+Returns `true` if `coloring` is an equitable coloring of the graph: a
+[coloring](./fn.is_coloring.html) in which the numbers of nodes of any two colors differ by at
+most one.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `coloring`: the color of each node; a `NodeNotFound` error is returned if one of the nodes
+  isn't in the graph
+* `num_colors`: if given, the colors are `0..num_colors`, and a color that no node has counts as
+  a color with no nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::coloring, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 0)];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let coloring = vec![(0, 0), (1, 1), (2, 0), (3, 2)].into_iter().collect();
+assert!(coloring::is_equitable(&graph, &coloring, None).unwrap());
+assert!(!coloring::is_equitable(&graph, &coloring, Some(4)).unwrap());
+```
+*/
+pub fn is_equitable<T, A>(
+    graph: &Graph<T, A>,
+    coloring: &HashMap<T, usize>,
+    num_colors: Option<usize>,
+) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if !is_coloring(graph, coloring)? {
+        return Ok(false);
+    }
+    let colors = get_colors(graph, coloring)?.unwrap();
+    let mut sizes: HashMap<usize, usize> = HashMap::new();
+    if let Some(num_colors) = num_colors {
+        if colors.iter().any(|c| *c >= num_colors) {
+            return Ok(false);
+        }
+        sizes.extend((0..num_colors).map(|c| (c, 0)));
+    }
+    for c in colors {
+        *sizes.entry(c).or_insert(0) += 1;
+    }
+    let smallest = sizes.values().min().cloned().unwrap_or(0);
+    let largest = sizes.values().max().cloned().unwrap_or(0);
+    Ok(largest - smallest <= 1)
+}
+
+/**
+The state of the equitable coloring algorithm: a coloring of the graph of the edges added so
+far, with counts that show which nodes could move to another color class.
+*/
+struct EquitableColoring {
+    num_colors: usize,
+    /// The color of each node.
+    colors: Vec<usize>,
+    /// The nodes of each color, in the order they got the color.
+    classes: Vec<Vec<usize>>,
+    /// The neighbors of each node among the edges added so far.
+    neighbors: Vec<Vec<usize>>,
+    /// `neighbor_counts[u][k]` is the number of neighbors of `u` with color `k`.
+    neighbor_counts: Vec<Vec<usize>>,
+    /// `movable[j][k]` is the number of nodes of color `j` with no neighbors of color `k`,
+    /// which could move to class `k`.
+    movable: Vec<Vec<i64>>,
+}
+
+impl EquitableColoring {
+    /// Colors the nodes without edges round-robin.
+    fn new(n: usize, num_colors: usize) -> EquitableColoring {
+        let colors: Vec<usize> = (0..n).map(|u| u % num_colors).collect();
+        let mut classes = vec![vec![]; num_colors];
+        for (u, c) in colors.iter().enumerate() {
+            classes[*c].push(u);
+        }
+        let movable = (0..num_colors)
+            .map(|j| vec![classes[j].len() as i64; num_colors])
+            .collect();
+        EquitableColoring {
+            num_colors,
+            colors,
+            classes,
+            neighbors: vec![vec![]; n],
+            neighbor_counts: vec![vec![0; num_colors]; n],
+            movable,
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.neighbors[u].push(v);
+        self.neighbors[v].push(u);
+        let (cu, cv) = (self.colors[u], self.colors[v]);
+        self.neighbor_counts[u][cv] += 1;
+        self.neighbor_counts[v][cu] += 1;
+        if cu != cv {
+            if self.neighbor_counts[u][cv] == 1 {
+                self.movable[cu][cv] -= 1;
+            }
+            if self.neighbor_counts[v][cu] == 1 {
+                self.movable[cv][cu] -= 1;
+            }
+        }
+    }
+
+    /// Moves `u` from class `x` to class `y`.
+    fn change_color(&mut self, u: usize, x: usize, y: usize) {
+        self.colors[u] = y;
+        for k in 0..self.num_colors {
+            if self.neighbor_counts[u][k] == 0 {
+                self.movable[x][k] -= 1;
+                self.movable[y][k] += 1;
+            }
+        }
+        for v in self.neighbors[u].iter().cloned() {
+            let cv = self.colors[v];
+            self.neighbor_counts[v][x] -= 1;
+            self.neighbor_counts[v][y] += 1;
+            if self.neighbor_counts[v][x] == 0 {
+                self.movable[cv][x] += 1;
+            }
+            if self.neighbor_counts[v][y] == 1 {
+                self.movable[cv][y] -= 1;
+            }
+        }
+        let position = self.classes[x].iter().position(|w| *w == u).unwrap();
+        self.classes[x].remove(position);
+        self.classes[y].push(u);
+    }
+
+    /// Moves a node from each class of `path` to the next class of `path`.
+    fn move_witnesses(&mut self, path: &[usize]) {
+        for pair in path.windows(2) {
+            let (x, y) = (pair[0], pair[1]);
+            let w = self.classes[x]
+                .iter()
+                .cloned()
+                .find(|w| self.neighbor_counts[*w][y] == 0)
+                .unwrap();
+            self.change_color(w, x, y);
+        }
+    }
+
+    /**
+    Makes a nearly equitable coloring, in which class `small` has one node too few and class
+    `large` one node too many, equitable. This is procedure P of Kierstead et al.; the classes
+    in `excluded` are left alone.
+    */
+    fn balance(&mut self, small: usize, large: usize, excluded: Vec<bool>) {
+        let r = self.num_colors;
+        // the classes that can reach `small` by moving nodes, and the next class on the way
+        let mut accessible = vec![false; r];
+        let mut next = vec![usize::MAX; r];
+        let mut order = vec![small];
+        accessible[small] = true;
+        let mut i = 0;
+        while i < order.len() {
+            let to = order[i];
+            i += 1;
+            for k in 0..r {
+                if self.movable[k][to] > 0 && !accessible[k] && !excluded[k] {
+                    accessible[k] = true;
+                    next[k] = to;
+                    order.push(k);
+                }
+            }
+        }
+        let path_to_small = |from: usize| {
+            let mut path = vec![from];
+            while *path.last().unwrap() != small {
+                path.push(next[*path.last().unwrap()]);
+            }
+            path
+        };
+        if accessible[large] {
+            self.move_witnesses(&path_to_small(large));
+            return;
+        }
+        let number_inaccessible = (0..r).filter(|k| !accessible[*k] && !excluded[*k]).count();
+        let mut terminal = vec![false; r];
+        let mut number_terminal = 0;
+        for w1 in order.iter().rev().cloned() {
+            for v in self.classes[w1].clone() {
+                let x = (0..r)
+                    .rev()
+                    .find(|u| self.neighbor_counts[v][*u] == 0 && accessible[*u] && *u != w1);
+                let x = match x {
+                    None => continue,
+                    Some(x) => x,
+                };
+                let inaccessible = |u: &usize| !accessible[*u] && !excluded[*u];
+                for x_prime in
+                    (0..r).filter(|u| self.neighbor_counts[v][*u] >= 1 && inaccessible(u))
+                {
+                    let y = self.neighbors[v]
+                        .iter()
+                        .cloned()
+                        .find(|y| self.colors[*y] == x_prime && self.neighbor_counts[*y][w1] == 1);
+                    if let Some(y) = y {
+                        self.change_color(v, w1, x);
+                        self.move_witnesses(&path_to_small(x));
+                        self.change_color(y, x_prime, w1);
+                        let excluded = (0..r).map(|k| excluded[k] || accessible[k]).collect();
+                        self.balance(x_prime, large, excluded);
+                        return;
+                    }
+                }
+            }
+            terminal[w1] = true;
+            number_terminal += 1;
+            if number_terminal == number_inaccessible {
+                self.balance_from_large(large, &excluded, &accessible, &terminal, path_to_small);
+                return;
+            }
+        }
+    }
+
+    /**
+    The last case of procedure P, when every inaccessible class is terminal: moves nodes so that
+    a node that is the only neighbor in a terminal class of two nodes of classes reachable from
+    `large` can move, and continues with a smaller problem.
+    */
+    fn balance_from_large(
+        &mut self,
+        large: usize,
+        excluded: &[bool],
+        accessible: &[bool],
+        terminal: &[bool],
+        path_to_small: impl Fn(usize) -> Vec<usize>,
+    ) {
+        let r = self.num_colors;
+        // the classes reachable from `large` by moving nodes, and the previous class on the way
+        let mut reachable = vec![false; r];
+        let mut previous = vec![usize::MAX; r];
+        let mut order = vec![large];
+        reachable[large] = true;
+        let mut i = 0;
+        while i < order.len() {
+            let from = order[i];
+            i += 1;
+            for k in 0..r {
+                if self.movable[from][k] > 0 && !reachable[k] && !excluded[k] {
+                    reachable[k] = true;
+                    previous[k] = from;
+                    order.push(k);
+                }
+            }
+        }
+        let candidates: Vec<usize> = self.classes[large]
+            .iter()
+            .chain(order.iter().flat_map(|k| self.classes[*k].iter()))
+            .cloned()
+            .collect();
+        let mut covered = vec![false; self.colors.len()];
+        // the node of a reachable class that each node of a terminal class is the only neighbor of
+        let mut covering: HashMap<usize, usize> = HashMap::new();
+        for z in candidates {
+            if covered[z] || !reachable[self.colors[z]] {
+                continue;
+            }
+            covered[z] = true;
+            for w in self.neighbors[z].clone() {
+                covered[w] = true;
+                let cw = self.colors[w];
+                if !terminal[cw] || self.neighbor_counts[z][cw] != 1 {
+                    continue;
+                }
+                let z1 = match covering.get(&w) {
+                    None => {
+                        covering.insert(w, z);
+                        continue;
+                    }
+                    Some(z1) => *z1,
+                };
+                let cz1 = self.colors[z1];
+                self.move_witnesses(&path_to_small(cw));
+                let mut path_from_large = vec![cz1];
+                while *path_from_large.last().unwrap() != large {
+                    path_from_large.push(previous[*path_from_large.last().unwrap()]);
+                }
+                path_from_large.reverse();
+                self.move_witnesses(&path_from_large);
+                self.change_color(z1, cz1, cw);
+                let w_plus = (0..r)
+                    .find(|k| self.neighbor_counts[w][*k] == 0 && !accessible[*k] && !excluded[*k])
+                    .unwrap();
+                self.change_color(w, cw, w_plus);
+                let excluded = (0..r)
+                    .map(|k| excluded[k] || (k != cw && !reachable[k]))
+                    .collect();
+                self.balance(cw, w_plus, excluded);
+                return;
+            }
+        }
+        unreachable!("a node of a terminal class is the only neighbor of two nodes");
+    }
+}
@@ -0,0 +1,347 @@
+use crate::algorithms::community::utility::get_shuffled_node_indexes;
+use crate::{Error, Graph};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The order in which [greedy_color](./fn.greedy_color.html) colors the nodes. The degree of a
+node is its number of distinct neighbors.
+
+`LargestFirst`: in order of decreasing degree.
+
+`SmallestLast`: repeatedly removes a node of smallest degree from the graph, and colors the
+nodes in the reverse of the order they were removed. Uses at most `d + 1` colors, where `d` is
+the degeneracy of the graph; at most six for a planar graph.
+
+`RandomSequential`: in a random order; the `u64` is an optional seed for deterministic results.
+
+`ConnectedSequentialBfs`: in the order of a breadth-first search of each connected component.
+
+`ConnectedSequentialDfs`: in the order of a depth-first search of each connected component.
+
+`Dsatur`: repeatedly colors the node with the most distinctly colored neighbors, breaking ties
+by degree. Colors bipartite graphs with two colors.
+
+`IndependentSet`: repeatedly finds a maximal independent set of the nodes that are still to be
+colored, choosing nodes of smallest degree first, and gives it the next color.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColoringStrategy {
+    LargestFirst,
+    SmallestLast,
+    RandomSequential(Option<u64>),
+    ConnectedSequentialBfs,
+    ConnectedSequentialDfs,
+    Dsatur,
+    IndependentSet,
+}
+
+/**
+Colors the nodes of a graph so that no two adjacent nodes have the same color, visiting the
+nodes in an order given by `strategy` and giving each node the smallest color that none of its
+neighbors has. The colors are `0, 1, 2, ...`.
+
+Finding a coloring with the fewest colors is NP-hard; a greedy coloring uses at most one more
+color than the largest degree in the graph. The direction of edges is ignored and self-loops
+are ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `strategy`: the order in which to color the nodes
+
+# Examples
+
+```
+use graphrs::{algorithms::coloring, Edge, Graph, GraphSpecs};
+// jobs that can't run at the same time because they use the same resource
+let edges = vec![
+    Edge::new("backup", "reindex"),
+    Edge::new("backup", "vacuum"),
+    Edge::new("reindex", "vacuum"),
+    Edge::new("vacuum", "report"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let slots = coloring::greedy_color(&graph, coloring::ColoringStrategy::Dsatur);
+assert_eq!(slots.values().max(), Some(&2));
+assert!(coloring::is_coloring(&graph, &slots).unwrap());
+```
+
+# References
+
+1. D. W. Matula and L. L. Beck: Smallest-last ordering and clustering and graph coloring
+   algorithms. Journal of the ACM 30(3):417–427, 1983.
+2. D. Brélaz: New methods to color the vertices of a graph. Communications of the ACM
+   22(4):251–256, 1979.
+3. A. Kosowski and K. Manuszewski: Classical coloring of graphs. In: Graph Colorings,
+   Contemporary Mathematics 352, 2004.
+*/
+pub fn greedy_color<T, A>(graph: &Graph<T, A>, strategy: ColoringStrategy) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let adjacency = get_adjacency(graph);
+    let colors = match strategy {
+        ColoringStrategy::LargestFirst => {
+            color_in_order(&adjacency, &get_largest_first_order(&adjacency))
+        }
+        ColoringStrategy::SmallestLast => {
+            color_in_order(&adjacency, &get_smallest_last_order(&adjacency))
+        }
+        ColoringStrategy::RandomSequential(seed) => {
+            color_in_order(&adjacency, &get_shuffled_node_indexes(graph, seed))
+        }
+        ColoringStrategy::ConnectedSequentialBfs => {
+            color_in_order(&adjacency, &get_traversal_order(&adjacency, false))
+        }
+        ColoringStrategy::ConnectedSequentialDfs => {
+            color_in_order(&adjacency, &get_traversal_order(&adjacency, true))
+        }
+        ColoringStrategy::Dsatur => color_by_saturation(&adjacency),
+        ColoringStrategy::IndependentSet => {
+            // the independent sets are ordered one after another, so each gets its own color
+            color_in_order(&adjacency, &get_independent_set_order(&adjacency))
+        }
+    };
+    colors
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), c))
+        .collect()
+}
+
+/**
+Returns `true` if `coloring` is a coloring of the graph: every node has a color, and no two
+adjacent nodes have the same color. The direction of edges is ignored and self-loops are ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `coloring`: the color of each node; a `NodeNotFound` error is returned if one of the nodes
+  isn't in the graph
+
+# Examples
+
+```
+use graphrs::{algorithms::coloring, generators};
+let graph = generators::classic::complete_graph(3, false);
+let coloring = vec![(0, 0), (1, 1), (2, 2)].into_iter().collect();
+assert!(coloring::is_coloring(&graph, &coloring).unwrap());
+let coloring = vec![(0, 0), (1, 1), (2, 1)].into_iter().collect();
+assert!(!coloring::is_coloring(&graph, &coloring).unwrap());
+```
+*/
+pub fn is_coloring<T, A>(graph: &Graph<T, A>, coloring: &HashMap<T, usize>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(get_colors(graph, coloring)?
+        .map(|colors| {
+            get_adjacency(graph)
+                .iter()
+                .enumerate()
+                .all(|(u, neighbors)| neighbors.iter().all(|v| colors[u] != colors[*v]))
+        })
+        .unwrap_or(false))
+}
+
+/**
+Returns the color of each node by index, or `None` if a node has no color.
+*/
+pub(super) fn get_colors<T, A>(
+    graph: &Graph<T, A>,
+    coloring: &HashMap<T, usize>,
+) -> Result<Option<Vec<usize>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut colors = vec![None; graph.number_of_nodes()];
+    for (node, color) in coloring {
+        colors[graph.get_node_index(node)?] = Some(*color);
+    }
+    Ok(colors.into_iter().collect())
+}
+
+/**
+Returns the distinct neighbors of each node, sorted, ignoring the direction of edges and
+self-loops.
+*/
+pub(super) fn get_adjacency<T, A>(graph: &Graph<T, A>) -> Vec<Vec<usize>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes())
+        .map(|u| {
+            let mut neighbors: Vec<usize> = graph
+                .get_neighbors_nodes_by_index(&u)
+                .into_iter()
+                .filter(|v| *v != u)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors
+        })
+        .collect()
+}
+
+/**
+Gives each node in `order` the smallest color that none of its colored neighbors has.
+*/
+fn color_in_order(adjacency: &[Vec<usize>], order: &[usize]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut colors = vec![usize::MAX; n];
+    // used[c] == u if color c is used by a neighbor of u, which avoids clearing it for each node
+    let mut used = vec![usize::MAX; n + 1];
+    for u in order.iter().cloned() {
+        for v in adjacency[u].iter() {
+            if colors[*v] != usize::MAX {
+                used[colors[*v]] = u;
+            }
+        }
+        colors[u] = (0..).find(|c| used[*c] != u).unwrap();
+    }
+    colors
+}
+
+fn get_largest_first_order(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..adjacency.len()).collect();
+    order.sort_by_key(|u| Reverse(adjacency[*u].len()));
+    order
+}
+
+fn get_smallest_last_order(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut degrees: Vec<usize> = adjacency.iter().map(|a| a.len()).collect();
+    let mut queue: BTreeSet<(usize, usize)> =
+        degrees.iter().enumerate().map(|(u, d)| (*d, u)).collect();
+    let mut removed = vec![false; adjacency.len()];
+    let mut order = Vec::with_capacity(adjacency.len());
+    while let Some((_, u)) = queue.pop_first() {
+        removed[u] = true;
+        order.push(u);
+        for v in adjacency[u].iter().filter(|v| !removed[**v]) {
+            queue.remove(&(degrees[*v], *v));
+            degrees[*v] -= 1;
+            queue.insert((degrees[*v], *v));
+        }
+    }
+    order.reverse();
+    order
+}
+
+fn get_traversal_order(adjacency: &[Vec<usize>], depth_first: bool) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    for source in 0..n {
+        if visited[source] {
+            continue;
+        }
+        visited[source] = true;
+        order.push(source);
+        match depth_first {
+            true => {
+                // each entry is a node and the position of its next neighbor to explore
+                let mut stack = vec![(source, 0)];
+                while let Some((u, next)) = stack.last_mut() {
+                    match adjacency[*u].get(*next) {
+                        None => {
+                            stack.pop();
+                        }
+                        Some(v) => {
+                            *next += 1;
+                            if !visited[*v] {
+                                visited[*v] = true;
+                                order.push(*v);
+                                stack.push((*v, 0));
+                            }
+                        }
+                    }
+                }
+            }
+            false => {
+                let mut queue = VecDeque::from([source]);
+                while let Some(u) = queue.pop_front() {
+                    for v in adjacency[u].iter() {
+                        if !visited[*v] {
+                            visited[*v] = true;
+                            order.push(*v);
+                            queue.push_back(*v);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+fn get_independent_set_order(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut ordered = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        // find a maximal independent set of the nodes not yet ordered, taking nodes of
+        // smallest degree among the nodes that are still candidates
+        let mut candidate: Vec<bool> = ordered.iter().map(|o| !o).collect();
+        let mut degrees: Vec<usize> = (0..n)
+            .map(|u| match candidate[u] {
+                true => adjacency[u].iter().filter(|v| candidate[**v]).count(),
+                false => 0,
+            })
+            .collect();
+        let mut queue: BTreeSet<(usize, usize)> = (0..n)
+            .filter(|u| candidate[*u])
+            .map(|u| (degrees[u], u))
+            .collect();
+        while let Some((_, u)) = queue.pop_first() {
+            ordered[u] = true;
+            order.push(u);
+            candidate[u] = false;
+            let removed: Vec<usize> = adjacency[u]
+                .iter()
+                .cloned()
+                .filter(|v| candidate[*v])
+                .collect();
+            for v in removed.iter() {
+                candidate[*v] = false;
+                queue.remove(&(degrees[*v], *v));
+            }
+            for v in removed.iter() {
+                for w in adjacency[*v].iter().filter(|w| candidate[**w]) {
+                    queue.remove(&(degrees[*w], *w));
+                    degrees[*w] -= 1;
+                    queue.insert((degrees[*w], *w));
+                }
+            }
+        }
+    }
+    order
+}
+
+fn color_by_saturation(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut colors = vec![usize::MAX; n];
+    // the distinct colors of the colored neighbors of each node
+    let mut neighbor_colors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    // the most saturated node is last, with ties broken by degree and then by index
+    let key = |u: usize, saturation: usize| (saturation, adjacency[u].len(), Reverse(u));
+    let mut queue: BTreeSet<(usize, usize, Reverse<usize>)> = (0..n).map(|u| key(u, 0)).collect();
+    while let Some((_, _, Reverse(u))) = queue.pop_last() {
+        let color = (0..).find(|c| !neighbor_colors[u].contains(c)).unwrap();
+        colors[u] = color;
+        for v in adjacency[u].iter() {
+            if colors[*v] == usize::MAX && !neighbor_colors[*v].contains(&color) {
+                queue.remove(&key(*v, neighbor_colors[*v].len()));
+                neighbor_colors[*v].insert(color);
+                queue.insert(key(*v, neighbor_colors[*v].len()));
+            }
+        }
+    }
+    colors
+}
@@ -0,0 +1,4 @@
+mod equitable;
+pub use equitable::*;
+mod greedy;
+pub use greedy::*;
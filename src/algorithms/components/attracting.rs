@@ -0,0 +1,60 @@
+use super::strong_connectivity::get_component_membership;
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the attracting components of a directed graph.
+
+An attracting component is a strongly connected component with the property that a random
+walker on the graph will never leave the component once it enters it. In other words, it's a
+strongly connected component that has no edges leaving it; a sink of the
+[condensation](./fn.condensation.html).
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n2"),
+    Edge::new("n1", "n4"),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::attracting_components(&graph).unwrap();
+assert_eq!(result.len(), 2);
+```
+*/
+pub fn attracting_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let (membership, num_components) = get_component_membership(graph);
+    let mut has_out_edges = vec![false; num_components];
+    for v in 0..graph.number_of_nodes() {
+        for adj in graph.get_successor_nodes_by_index(&v) {
+            if membership[v] != membership[adj.node_index] {
+                has_out_edges[membership[v]] = true;
+            }
+        }
+    }
+    let mut components: Vec<HashSet<T>> = vec![HashSet::new(); num_components];
+    for (v, c) in membership.into_iter().enumerate() {
+        if !has_out_edges[c] {
+            components[c].insert(graph.get_node_by_index(&v).unwrap().name.clone());
+        }
+    }
+    Ok(components
+        .into_iter()
+        .filter(|component| !component.is_empty())
+        .collect())
+}
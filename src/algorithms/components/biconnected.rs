@@ -0,0 +1,441 @@
+use crate::algorithms::centrality::fringe_node::{push_fringe_node, FringeNode};
+use crate::{Error, Graph};
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the biconnected components of an undirected graph.
+
+A biconnected component is a maximal subgraph that cannot be disconnected by removing a single
+node (and all edges incident on that node). Every edge belongs to exactly one biconnected
+component but a node can belong to several; the nodes that do are the
+[articulation points](./fn.articulation_points.html). Isolated nodes and self-loops are
+ignored.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::biconnected_components(&graph).unwrap();
+assert_eq!(result.len(), 2);
+```
+
+# References:
+
+1. Hopcroft, J. and Tarjan, R. (1973). Efficient algorithms for graph manipulation.
+   Communications of the ACM 16: 372–378.
+*/
+pub fn biconnected_components<T, A>(graph: &Graph<T, A>) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let result = biconnected_dfs(graph, None);
+    Ok(result
+        .components
+        .into_iter()
+        .map(|edges| {
+            edges
+                .into_iter()
+                .flat_map(|(u, v)| vec![u, v])
+                .collect::<HashSet<usize>>()
+                .into_iter()
+                .map(|i| get_name(graph, i))
+                .collect()
+        })
+        .collect())
+}
+
+/**
+Returns the edges of each of the biconnected components of an undirected graph.
+
+See [biconnected_components](./fn.biconnected_components.html) for a definition.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::biconnected_component_edges(&graph).unwrap();
+assert_eq!(result, vec![vec![("n3", "n4")], vec![("n1", "n2"), ("n2", "n3"), ("n3", "n1")]]);
+```
+*/
+pub fn biconnected_component_edges<T, A>(graph: &Graph<T, A>) -> Result<Vec<Vec<(T, T)>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let result = biconnected_dfs(graph, None);
+    Ok(result
+        .components
+        .into_iter()
+        .map(|edges| {
+            edges
+                .into_iter()
+                .map(|(u, v)| (get_name(graph, u), get_name(graph, v)))
+                .collect()
+        })
+        .collect())
+}
+
+/**
+Returns the articulation points, or cut vertices, of an undirected graph.
+
+An articulation point is a node whose removal (along with all its edges) increases the
+number of connected components of the graph.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::articulation_points(&graph).unwrap();
+assert_eq!(result, vec!["n3"].into_iter().collect());
+```
+*/
+pub fn articulation_points<T, A>(graph: &Graph<T, A>) -> Result<HashSet<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let result = biconnected_dfs(graph, None);
+    Ok(result
+        .articulation_points
+        .into_iter()
+        .map(|i| get_name(graph, i))
+        .collect())
+}
+
+/**
+Returns the bridges of an undirected graph.
+
+A bridge is an edge whose removal increases the number of connected components of the graph.
+In a graph with multiple edges between two nodes, none of those edges are bridges.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected.
+* `root`: if specified, only the bridges in the connected component containing this node
+  are returned.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n1"),
+    Edge::new("n3", "n4"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::bridges(&graph, None).unwrap();
+assert_eq!(result, vec![("n3", "n4")]);
+```
+*/
+pub fn bridges<T, A>(graph: &Graph<T, A>, root: Option<T>) -> Result<Vec<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let root = match root {
+        None => None,
+        Some(r) => Some(graph.get_node_index(&r)?),
+    };
+    let result = biconnected_dfs(graph, root);
+    Ok(result
+        .bridges
+        .into_iter()
+        .filter(|(u, v)| {
+            !graph.specs.multi_edges || graph.get_edges_by_indexes(*u, *v).unwrap().len() == 1
+        })
+        .map(|(u, v)| (get_name(graph, u), get_name(graph, v)))
+        .collect())
+}
+
+/**
+Returns the local bridges of an undirected graph.
+
+A local bridge is an edge whose endpoints have no common neighbors; that is, the edge is not
+part of a triangle in the graph. The span of a local bridge is the shortest path length
+between its endpoints if the local bridge is removed; it's infinite if the local bridge is
+also a [bridge](./fn.bridges.html).
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected and must not
+  have multiple edges between two nodes.
+* `with_span`: if `true` the span of each local bridge is computed and returned.
+* `weighted`: set to `true` to use edge weights when computing the span; otherwise each edge
+  has a length of 1.
+
+# Returns:
+
+A list of `(u, v, span)` tuples; `span` is `None` when `with_span` is `false`.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+    Edge::new("n4", "n1"),
+    Edge::new("n1", "n3"),
+    Edge::new("n4", "n5"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = components::local_bridges(&graph, true, false).unwrap();
+assert_eq!(result, vec![("n4", "n5", Some(f64::INFINITY))]);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn local_bridges<T, A>(
+    graph: &Graph<T, A>,
+    with_span: bool,
+    weighted: bool,
+) -> Result<Vec<(T, T, Option<f64>)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    graph.ensure_not_multi_edges()?;
+    if weighted && with_span {
+        graph.ensure_weighted()?;
+    }
+    let mut result = vec![];
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u).unwrap();
+        let v = graph.get_node_index(&edge.v).unwrap();
+        let u_neighbors = graph.get_neighbors_nodes_by_index(&u);
+        let v_neighbors = graph.get_neighbors_nodes_by_index(&v);
+        if u_neighbors.intersection(&v_neighbors).next().is_some() {
+            continue;
+        }
+        let span = match with_span {
+            false => None,
+            true => Some(get_span(graph, u, v, weighted)),
+        };
+        result.push((edge.u.clone(), edge.v.clone(), span));
+    }
+    Ok(result)
+}
+
+/**
+Returns `true` if the graph is biconnected, `false` otherwise.
+
+A graph is biconnected if it's connected and it can't be disconnected by removing a single
+node (and all edges incident on that node).
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, generators};
+let graph = generators::classic::complete_graph(4, false);
+assert!(components::is_biconnected(&graph).unwrap());
+```
+*/
+pub fn is_biconnected<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let components = biconnected_components(graph)?;
+    Ok(components.len() == 1 && components[0].len() == graph.number_of_nodes())
+}
+
+struct BiconnectedResult {
+    components: Vec<Vec<(usize, usize)>>,
+    articulation_points: Vec<usize>,
+    bridges: Vec<(usize, usize)>,
+}
+
+/**
+Tarjan's depth-first search for biconnected components, articulation points and bridges.
+Uses an explicit stack so that large graphs don't overflow the call stack. If `root` is
+specified only the connected component containing `root` is searched.
+*/
+fn biconnected_dfs<T, A>(graph: &Graph<T, A>, root: Option<usize>) -> BiconnectedResult
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let mut result = BiconnectedResult {
+        components: vec![],
+        articulation_points: vec![],
+        bridges: vec![],
+    };
+    let mut discovery = vec![usize::MAX; num_nodes];
+    let mut low = vec![usize::MAX; num_nodes];
+    let mut is_articulation_point = vec![false; num_nodes];
+    let mut counter = 0;
+    let starts: Vec<usize> = match root {
+        Some(r) => vec![r],
+        None => (0..num_nodes).collect(),
+    };
+    for start in starts {
+        if discovery[start] != usize::MAX {
+            continue;
+        }
+        discovery[start] = counter;
+        low[start] = counter;
+        counter += 1;
+        let mut root_children = 0;
+        let mut edge_stack: Vec<(usize, usize)> = vec![];
+        // (grandparent, parent, position in the parent's adjacency list)
+        let mut stack: Vec<(usize, usize, usize)> = vec![(start, start, 0)];
+        while let Some((grandparent, parent, position)) = stack.last_mut() {
+            let (grandparent, parent) = (*grandparent, *parent);
+            let successors = graph.get_successor_nodes_by_index(&parent);
+            if *position < successors.len() {
+                let child = successors[*position].node_index;
+                *position += 1;
+                if child == grandparent || child == parent {
+                    continue;
+                }
+                if discovery[child] == usize::MAX {
+                    discovery[child] = counter;
+                    low[child] = counter;
+                    counter += 1;
+                    edge_stack.push((parent, child));
+                    stack.push((parent, child, 0));
+                } else if discovery[child] <= discovery[parent] {
+                    // a back edge
+                    low[parent] = low[parent].min(discovery[child]);
+                    edge_stack.push((parent, child));
+                }
+                continue;
+            }
+            stack.pop();
+            if stack.is_empty() {
+                continue;
+            }
+            if low[parent] > discovery[grandparent] {
+                result.bridges.push((grandparent, parent));
+            }
+            if stack.len() > 1 && low[parent] < discovery[grandparent] {
+                low[grandparent] = low[grandparent].min(low[parent]);
+                continue;
+            }
+            // `grandparent` separates the subtree rooted at `parent` from the rest of the graph
+            if stack.len() > 1 {
+                is_articulation_point[grandparent] = true;
+            } else {
+                root_children += 1;
+            }
+            let index = edge_stack
+                .iter()
+                .rposition(|e| *e == (grandparent, parent))
+                .unwrap();
+            result.components.push(edge_stack.split_off(index));
+        }
+        if root_children > 1 {
+            is_articulation_point[start] = true;
+        }
+    }
+    result.articulation_points = (0..num_nodes)
+        .filter(|i| is_articulation_point[*i])
+        .collect();
+    result
+}
+
+/**
+Returns the length of the shortest path between `u` and `v` that doesn't use the edge
+between them.
+*/
+fn get_span<T, A>(graph: &Graph<T, A>, u: usize, v: usize, weighted: bool) -> f64
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let mut distance = vec![f64::INFINITY; num_nodes];
+    let mut done = vec![false; num_nodes];
+    let mut fringe = BinaryHeap::<FringeNode>::new();
+    distance[u] = 0.0;
+    push_fringe_node(&mut fringe, u, u, 0.0);
+    while let Some(fringe_node) = fringe.pop() {
+        let x = fringe_node.v;
+        if done[x] {
+            continue;
+        }
+        if x == v {
+            return -fringe_node.distance;
+        }
+        done[x] = true;
+        let dist = -fringe_node.distance;
+        for adj in graph.get_successor_nodes_by_index(&x) {
+            let y = adj.node_index;
+            if (x == u && y == v) || (x == v && y == u) {
+                continue;
+            }
+            let weight = match weighted {
+                true => adj.weight,
+                false => 1.0,
+            };
+            let xy_dist = dist + weight;
+            if !done[y] && xy_dist < distance[y] {
+                distance[y] = xy_dist;
+                push_fringe_node(&mut fringe, x, y, xy_dist);
+            }
+        }
+    }
+    f64::INFINITY
+}
+
+#[inline]
+fn get_name<T, A>(graph: &Graph<T, A>, index: usize) -> T
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.get_node_by_index(&index).unwrap().name.clone()
+}
@@ -0,0 +1,298 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Maintains the connected components of a graph as edges are added to it.
+
+`IncrementalComponents` is a disjoint-set (union-find) structure with path compression and
+union by rank; adding an edge and querying the components both take near-constant amortized
+time. Edges are treated as undirected, so for a directed graph the components are the weakly
+connected components. Edges can't be removed.
+
+Queries take `&mut self` because they compress the paths they traverse.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components::IncrementalComponents, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n3", "n4"),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let mut components = IncrementalComponents::from_graph(&graph);
+assert_eq!(components.number_of_components(), 2);
+assert!(!components.same_component(&"n1", &"n4").unwrap());
+components.add_edge("n2", "n3");
+assert_eq!(components.number_of_components(), 1);
+assert_eq!(components.component_size(&"n1").unwrap(), 4);
+```
+*/
+pub struct IncrementalComponents<T> {
+    node_indexes: HashMap<T, usize>,
+    node_names: Vec<T>,
+    union_find: UnionFind,
+}
+
+impl<T> IncrementalComponents<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Creates an `IncrementalComponents` with no nodes.
+    */
+    pub fn new() -> IncrementalComponents<T> {
+        IncrementalComponents {
+            node_indexes: HashMap::new(),
+            node_names: vec![],
+            union_find: UnionFind::new(0),
+        }
+    }
+
+    /**
+    Creates an `IncrementalComponents` seeded with the nodes and edges of a
+    [Graph](../../struct.Graph.html).
+
+    # Arguments:
+
+    * `graph`: the [Graph](../../struct.Graph.html) to seed the components from; if it's
+      directed the weakly connected components are tracked.
+    */
+    pub fn from_graph<A>(graph: &Graph<T, A>) -> IncrementalComponents<T>
+    where
+        A: Clone + Send + Sync,
+    {
+        let num_nodes = graph.number_of_nodes();
+        let node_names: Vec<T> = (0..num_nodes)
+            .map(|i| graph.get_node_by_index(&i).unwrap().name.clone())
+            .collect();
+        let node_indexes = node_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), i))
+            .collect();
+        let mut union_find = UnionFind::new(num_nodes);
+        for u in 0..num_nodes {
+            for adj in graph.get_successor_nodes_by_index(&u) {
+                union_find.union(u, adj.node_index);
+            }
+        }
+        IncrementalComponents {
+            node_indexes,
+            node_names,
+            union_find,
+        }
+    }
+
+    /**
+    Adds a node, in a component of its own, if it isn't already present.
+    Returns `true` if the node was added.
+
+    # Arguments:
+
+    * `node`: the node to add
+    */
+    pub fn add_node(&mut self, node: T) -> bool {
+        if self.node_indexes.contains_key(&node) {
+            return false;
+        }
+        self.get_or_add_node(node);
+        true
+    }
+
+    /**
+    Adds an edge between two nodes, adding either node if it isn't already present.
+    Returns `true` if the edge joined two components that were previously separate.
+
+    # Arguments:
+
+    * `u`: one end of the edge
+    * `v`: the other end of the edge
+    */
+    pub fn add_edge(&mut self, u: T, v: T) -> bool {
+        let u = self.get_or_add_node(u);
+        let v = self.get_or_add_node(v);
+        self.union_find.union(u, v)
+    }
+
+    /**
+    Returns `true` if `u` and `v` are in the same component.
+
+    # Arguments:
+
+    * `u`: a node
+    * `v`: another node
+    */
+    pub fn same_component(&mut self, u: &T, v: &T) -> Result<bool, Error> {
+        let u = self.get_node_index(u)?;
+        let v = self.get_node_index(v)?;
+        Ok(self.union_find.find(u) == self.union_find.find(v))
+    }
+
+    /**
+    Returns the number of nodes in the component that contains `node`.
+
+    # Arguments:
+
+    * `node`: the node whose component size is returned
+    */
+    pub fn component_size(&mut self, node: &T) -> Result<usize, Error> {
+        let index = self.get_node_index(node)?;
+        Ok(self.union_find.component_size(index))
+    }
+
+    /**
+    Returns the number of components.
+    */
+    pub fn number_of_components(&self) -> usize {
+        self.union_find.number_of_components
+    }
+
+    /**
+    Returns the number of nodes.
+    */
+    pub fn number_of_nodes(&self) -> usize {
+        self.node_names.len()
+    }
+
+    /**
+    Returns the components, each as a set of nodes.
+    */
+    pub fn components(&mut self) -> Vec<HashSet<T>> {
+        let mut components: HashMap<usize, HashSet<T>> = HashMap::new();
+        for (i, name) in self.node_names.iter().enumerate() {
+            components
+                .entry(self.union_find.find(i))
+                .or_default()
+                .insert(name.clone());
+        }
+        components.into_values().collect()
+    }
+
+    fn get_or_add_node(&mut self, node: T) -> usize {
+        match self.node_indexes.get(&node) {
+            Some(index) => *index,
+            None => {
+                let index = self.union_find.add();
+                self.node_indexes.insert(node.clone(), index);
+                self.node_names.push(node);
+                index
+            }
+        }
+    }
+
+    fn get_node_index(&self, node: &T) -> Result<usize, Error> {
+        match self.node_indexes.get(node) {
+            Some(index) => Ok(*index),
+            None => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Node '{}' not found.", node),
+            }),
+        }
+    }
+}
+
+impl<T> Default for IncrementalComponents<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+A disjoint-set forest over the integers `0..n`, with path compression and union by rank.
+*/
+#[derive(Clone)]
+pub(crate) struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    pub number_of_components: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            number_of_components: n,
+        }
+    }
+
+    /// Adds a new singleton set and returns its element.
+    pub fn add(&mut self) -> usize {
+        let x = self.parent.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        self.number_of_components += 1;
+        x
+    }
+
+    /// Returns the representative of the set that contains `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merges the sets that contain `x` and `y`; returns `false` if they were already the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        let (root, child) = match self.rank[x] < self.rank[y] {
+            true => (y, x),
+            false => (x, y),
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.number_of_components -= 1;
+        true
+    }
+
+    /// Returns the number of elements in the set that contains `x`.
+    pub fn component_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::UnionFind;
+
+    #[test]
+    fn test_union_find_1() {
+        let mut uf = UnionFind::new(5);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert!(!uf.union(1, 0));
+        assert_eq!(uf.number_of_components, 3);
+        assert!(uf.union(1, 3));
+        assert_eq!(uf.find(0), uf.find(2));
+        assert_eq!(uf.component_size(3), 4);
+        assert_eq!(uf.component_size(4), 1);
+        assert_eq!(uf.add(), 5);
+        assert_eq!(uf.number_of_components, 3);
+    }
+}
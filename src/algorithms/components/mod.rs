@@ -1,6 +1,18 @@
+mod attracting;
+pub use attracting::*;
+
+mod biconnected;
+pub use biconnected::*;
+
 mod connectivity;
 pub use connectivity::*;
 
+mod incremental;
+pub use incremental::*;
+
+mod semiconnected;
+pub use semiconnected::*;
+
 mod strong_connectivity;
 pub use strong_connectivity::*;
 
@@ -0,0 +1,59 @@
+use super::strong_connectivity::get_component_membership;
+use crate::{Error, ErrorKind, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns `true` if a directed graph is semiconnected, `false` otherwise.
+
+A graph is semiconnected if and only if for any pair of nodes, either one is reachable from
+the other, or they are mutually reachable. This is the case exactly when the
+[condensation](./fn.condensation.html) of the graph is a single path.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed and have at
+  least one node.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new("n1", "n2"), Edge::new("n2", "n3")];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(components::is_semiconnected(&graph).unwrap());
+let edges = vec![Edge::new("n1", "n2"), Edge::new("n1", "n3")];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(!components::is_semiconnected(&graph).unwrap());
+```
+*/
+pub fn is_semiconnected<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    if graph.number_of_nodes() == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Connectivity is undefined for a graph with no nodes.".to_string(),
+        });
+    }
+    // components are numbered in topological order, so the condensation is a path
+    // exactly when each component has an edge to the next one
+    let (membership, num_components) = get_component_membership(graph);
+    let mut has_edge_to_next = vec![false; num_components];
+    for v in 0..graph.number_of_nodes() {
+        for adj in graph.get_successor_nodes_by_index(&v) {
+            if membership[adj.node_index] == membership[v] + 1 {
+                has_edge_to_next[membership[v]] = true;
+            }
+        }
+    }
+    Ok(has_edge_to_next
+        .into_iter()
+        .take(num_components - 1)
+        .all(|b| b))
+}
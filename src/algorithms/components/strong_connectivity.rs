@@ -1,4 +1,4 @@
-use crate::{ext::vec::VecExt, Error, Graph};
+use crate::{ext::vec::VecExt, Edge, Error, Graph, GraphSpecs, Node};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
@@ -98,3 +98,181 @@ where
     }
     Ok(components)
 }
+
+/**
+Returns the condensation of a directed graph.
+
+The condensation of a graph is the directed acyclic graph that results from contracting
+each strongly connected component to a single node. The nodes of the condensation are
+named `0..k`, where `k` is the number of strongly connected components, and are numbered
+in topological order: every edge in the condensation goes from a lower number to a higher one.
+
+The weight of each edge in the condensation is the sum of the weights of the edges between the
+two components it connects. Edges that don't have a weight count as `1.0`, so for an
+unweighted graph the weight is the number of edges between the two components.
+
+# Arguments:
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed
+
+# Returns:
+
+A tuple of the condensation and a `HashMap` that maps each node of `graph` to the
+component, a node of the condensation, that it belongs to.
+
+# Examples:
+
+```
+use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new("n1", "n2"),
+    Edge::new("n2", "n1"),
+    Edge::new("n2", "n3"),
+    Edge::new("n3", "n4"),
+    Edge::new("n4", "n3"),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let (condensed, mapping) = components::condensation(&graph).unwrap();
+assert_eq!(condensed.number_of_nodes(), 2);
+assert_eq!(condensed.number_of_edges(), 1);
+assert_eq!(mapping.get("n1"), mapping.get("n2"));
+assert!(condensed.get_edge(mapping["n2"], mapping["n3"]).is_ok());
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn condensation<T, A>(
+    graph: &Graph<T, A>,
+) -> Result<(Graph<usize, ()>, HashMap<T, usize>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let (membership, num_components) = get_component_membership(graph);
+    let mut weights: HashMap<(usize, usize), f64> = HashMap::new();
+    for edge in graph.get_all_edges() {
+        let cu = membership[graph.get_node_index(&edge.u).unwrap()];
+        let cv = membership[graph.get_node_index(&edge.v).unwrap()];
+        if cu != cv {
+            let weight = match edge.weight.is_nan() {
+                true => 1.0,
+                false => edge.weight,
+            };
+            *weights.entry((cu, cv)).or_insert(0.0) += weight;
+        }
+    }
+    let mut weights: Vec<((usize, usize), f64)> = weights.into_iter().collect();
+    weights.sort_by_key(|w| w.0);
+    let nodes = (0..num_components).map(Node::from_name).collect();
+    let edges = weights
+        .into_iter()
+        .map(|((u, v), weight)| Edge::with_weight(u, v, weight))
+        .collect();
+    let condensed = Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed())?;
+    let mapping = membership
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (graph.get_node_by_index(&i).unwrap().name.clone(), c))
+        .collect();
+    Ok((condensed, mapping))
+}
+
+/**
+Returns, for each node index, the strongly connected component the node belongs to, and
+the number of components. Components are numbered in topological order.
+*/
+pub(crate) fn get_component_membership<T, A>(graph: &Graph<T, A>) -> (Vec<usize>, usize)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let num_nodes = graph.number_of_nodes();
+    let successors: Vec<Vec<usize>> = (0..num_nodes)
+        .map(|v| {
+            graph
+                .get_successor_nodes_by_index(&v)
+                .iter()
+                .map(|adj| adj.node_index)
+                .collect()
+        })
+        .collect();
+    let components = strongly_connected_components_by_index(&successors, &vec![true; num_nodes]);
+    let num_components = components.len();
+    let mut membership = vec![0; num_nodes];
+    for (i, component) in components.into_iter().rev().enumerate() {
+        for v in component {
+            membership[v] = i;
+        }
+    }
+    (membership, num_components)
+}
+
+/**
+Returns the strongly connected components of the subgraph induced by the nodes where
+`include` is `true`, using an iterative version of Tarjan's algorithm over node indexes.
+
+Components are returned in reverse topological order: a component is only emitted after
+every component it can reach.
+*/
+pub(crate) fn strongly_connected_components_by_index(
+    successors: &[Vec<usize>],
+    include: &[bool],
+) -> Vec<Vec<usize>> {
+    let num_nodes = successors.len();
+    let mut preorder = vec![usize::MAX; num_nodes];
+    let mut lowlink = vec![0; num_nodes];
+    let mut on_stack = vec![false; num_nodes];
+    let mut scc_stack: Vec<usize> = vec![];
+    let mut call_stack: Vec<(usize, usize)> = vec![];
+    let mut components: Vec<Vec<usize>> = vec![];
+    let mut counter = 0;
+    for root in 0..num_nodes {
+        if !include[root] || preorder[root] != usize::MAX {
+            continue;
+        }
+        preorder[root] = counter;
+        lowlink[root] = counter;
+        counter += 1;
+        scc_stack.push(root);
+        on_stack[root] = true;
+        call_stack.push((root, 0));
+        while let Some((v, position)) = call_stack.last_mut() {
+            let v = *v;
+            if *position < successors[v].len() {
+                let w = successors[v][*position];
+                *position += 1;
+                if !include[w] {
+                    continue;
+                }
+                if preorder[w] == usize::MAX {
+                    preorder[w] = counter;
+                    lowlink[w] = counter;
+                    counter += 1;
+                    scc_stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(preorder[w]);
+                }
+            } else {
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    lowlink[*parent] = lowlink[*parent].min(lowlink[v]);
+                }
+                if lowlink[v] == preorder[v] {
+                    let mut component = vec![];
+                    while let Some(w) = scc_stack.pop() {
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+    }
+    components
+}
@@ -0,0 +1,244 @@
+use crate::algorithms::flow::residual::ResidualNetwork;
+use crate::algorithms::flow::{run_algorithm, FlowAlgorithm};
+use crate::{Error, ErrorKind, Graph};
+use nohash::IntSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Builds a flow network where every edge has a capacity of one. An undirected edge can be used
+in either direction. Multiple edges between two nodes each add a unit of capacity and
+self-loops are ignored.
+*/
+pub(crate) fn build_edge_network<T, A>(graph: &Graph<T, A>) -> ResidualNetwork
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut network = ResidualNetwork::new(graph.number_of_nodes());
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u).unwrap();
+        let v = graph.get_node_index(&edge.v).unwrap();
+        if u == v {
+            continue;
+        }
+        match graph.specs.directed {
+            true => network.add_arc(u, v, 1.0, 0.0),
+            false => network.add_arc(u, v, 1.0, 1.0),
+        };
+    }
+    network
+}
+
+/**
+Builds the auxiliary flow network used to compute node connectivity. Every node `i` is split
+into an "in" node `2i` and an "out" node `2i + 1` joined by an arc with a capacity of one, and
+every edge `(u, v)` becomes an arc from the out node of `u` to the in node of `v` with a
+capacity of `edge_capacity`.
+*/
+pub(crate) fn build_node_network<T, A>(graph: &Graph<T, A>, edge_capacity: f64) -> ResidualNetwork
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut network = ResidualNetwork::new(2 * n);
+    for i in 0..n {
+        network.add_arc(2 * i, 2 * i + 1, 1.0, 0.0);
+    }
+    for u in 0..n {
+        let successors: IntSet<usize> = graph
+            .get_successor_nodes_by_index(&u)
+            .iter()
+            .map(|adj| adj.node_index)
+            .filter(|v| *v != u)
+            .collect();
+        for v in successors {
+            network.add_arc(2 * u + 1, 2 * v, edge_capacity, 0.0);
+        }
+    }
+    network
+}
+
+/**
+Computes a maximum flow from `source` to `target` in a copy of `network`. Returns the copy,
+which holds the flow, and the value of the flow.
+*/
+pub(crate) fn run_unit_flow(
+    network: &ResidualNetwork,
+    source: usize,
+    target: usize,
+) -> (ResidualNetwork, usize) {
+    let mut network = network.clone();
+    run_algorithm(&mut network, source, target, FlowAlgorithm::Dinic);
+    let value = network.net_inflow(target).round() as usize;
+    (network, value)
+}
+
+/**
+Splits an integral flow from `source` to `target` into paths, each carrying one unit of flow.
+Cycles in the flow are discarded. The flow in `network` is consumed.
+*/
+pub(crate) fn decompose_into_paths(
+    network: &mut ResidualNetwork,
+    source: usize,
+    target: usize,
+) -> Vec<Vec<usize>> {
+    let mut paths = vec![];
+    let mut position: Vec<Option<usize>> = vec![None; network.num_nodes];
+    loop {
+        let mut path = vec![source];
+        position[source] = Some(0);
+        let mut current = source;
+        while current != target {
+            let arc = network.adjacency[current]
+                .iter()
+                .find(|e| network.flow[**e] > 0.5)
+                .cloned();
+            let arc = match arc {
+                None => break,
+                Some(arc) => arc,
+            };
+            network.push(arc, -1.0);
+            current = network.head[arc];
+            match position[current] {
+                // a cycle: drop it from the path
+                Some(p) => {
+                    for node in path.drain((p + 1)..) {
+                        position[node] = None;
+                    }
+                }
+                None => {
+                    position[current] = Some(path.len());
+                    path.push(current);
+                }
+            }
+        }
+        for node in path.iter() {
+            position[*node] = None;
+        }
+        if current != target {
+            return paths;
+        }
+        paths.push(path);
+    }
+}
+
+/**
+Returns the nodes adjacent to `node`, ignoring the direction of edges and self-loops.
+*/
+pub(crate) fn get_neighbors<T, A>(graph: &Graph<T, A>, node: usize) -> IntSet<usize>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut neighbors = graph.get_neighbors_nodes_by_index(&node);
+    neighbors.remove(&node);
+    neighbors
+}
+
+/**
+Returns `true` if the graph is connected, ignoring the direction of edges.
+*/
+pub(crate) fn is_weakly_connected<T, A>(graph: &Graph<T, A>) -> bool
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut seen = vec![false; n];
+    seen[0] = true;
+    let mut stack = vec![0];
+    let mut count = 1;
+    while let Some(u) = stack.pop() {
+        for v in get_neighbors(graph, u) {
+            if !seen[v] {
+                seen[v] = true;
+                count += 1;
+                stack.push(v);
+            }
+        }
+    }
+    count == n
+}
+
+/**
+Finds a dominating set greedily, starting with `start`. Every node is either in the set or
+adjacent to a node in the set.
+*/
+pub(crate) fn dominating_set<T, A>(graph: &Graph<T, A>, start: usize) -> Vec<usize>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut dominated = vec![false; n];
+    let mut dominating = vec![];
+    for v in std::iter::once(start).chain(0..n) {
+        if dominated[v] {
+            continue;
+        }
+        dominating.push(v);
+        dominated[v] = true;
+        for w in get_neighbors(graph, v) {
+            dominated[w] = true;
+        }
+    }
+    dominating
+}
+
+pub(crate) fn ensure_has_nodes<T, A>(graph: &Graph<T, A>) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    match graph.number_of_nodes() {
+        0 => Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has no nodes.".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/**
+Checks that either both `source` and `target` are given, or neither; returns their indexes.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn get_source_and_target<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+    target: Option<T>,
+) -> Result<Option<(usize, usize)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    match (source, target) {
+        (None, None) => Ok(None),
+        (Some(source), Some(target)) => {
+            let s = graph.get_node_index(&source)?;
+            let t = graph.get_node_index(&target)?;
+            if s == t {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: "The source and target nodes are the same.".to_string(),
+                });
+            }
+            Ok(Some((s, t)))
+        }
+        _ => Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "Both source and target must be specified.".to_string(),
+        }),
+    }
+}
+
+#[inline]
+pub(crate) fn get_name<T, A>(graph: &Graph<T, A>, index: usize) -> T
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.get_node_by_index(&index).unwrap().name.clone()
+}
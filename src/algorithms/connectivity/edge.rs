@@ -0,0 +1,269 @@
+use super::auxiliary::{
+    build_edge_network, decompose_into_paths, dominating_set, ensure_has_nodes, get_name,
+    get_source_and_target, is_weakly_connected, run_unit_flow,
+};
+use crate::algorithms::flow::residual::ResidualNetwork;
+use crate::{Error, ErrorKind, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the edge connectivity of a graph: the minimum number of edges that must be removed to
+disconnect the graph. If `source` and `target` are given, returns the local edge connectivity
+instead: the minimum number of edges that must be removed to break all paths from `source` to
+`target`.
+
+Each edge has a capacity of one in the underlying maximum flow computations, so multiple edges
+between two nodes add to the connectivity. Self-loops are ignored.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance; for a directed graph the direction of
+  the edges is respected
+* `source`: the source node; optional, but must be given along with `target`
+* `target`: the target node; optional, but must be given along with `source`
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(connectivity::edge_connectivity(&graph, None, None).unwrap(), 1);
+assert_eq!(connectivity::edge_connectivity(&graph, Some(0), Some(33)).unwrap(), 10);
+```
+
+# References
+
+1. A. H. Esfahanian: Connectivity algorithms. In: Topics in Structural Graph Theory,
+   Cambridge University Press, 2013.
+*/
+pub fn edge_connectivity<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+    target: Option<T>,
+) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if let Some((s, t)) = get_source_and_target(graph, source, target)? {
+        let network = build_edge_network(graph);
+        return Ok(run_unit_flow(&network, s, t).1);
+    }
+    ensure_has_nodes(graph)?;
+    if !is_weakly_connected(graph) {
+        return Ok(0);
+    }
+    let network = build_edge_network(graph);
+    let (_, mut k) = get_min_degree_node(graph, &network);
+    for (x, y) in get_pairs_to_separate(graph) {
+        k = k.min(run_unit_flow(&network, x, y).1);
+    }
+    Ok(k)
+}
+
+/**
+Returns a set of edges of minimum size that disconnects the graph. If `source` and `target`
+are given, returns a set of edges of minimum size that breaks all paths from `source` to
+`target`.
+
+The edges are returned as `(u, v)` tuples, sorted, where `u` is on the same side of the cut as
+`source` (or as the node that is separated from the rest of the graph). Multiple edges between
+two nodes appear once for each edge.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be connected (weakly
+  connected if directed) when `source` and `target` aren't given
+* `source`: the source node; optional, but must be given along with `target`
+* `target`: the target node; optional, but must be given along with `source`
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+let cut = connectivity::minimum_edge_cut(&graph, None, None).unwrap();
+assert_eq!(cut, vec![(11, 0)]);
+let cut = connectivity::minimum_edge_cut(&graph, Some(0), Some(33)).unwrap();
+assert_eq!(cut.len(), 10);
+```
+*/
+pub fn minimum_edge_cut<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+    target: Option<T>,
+) -> Result<Vec<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let network = build_edge_network(graph);
+    let cut = match get_source_and_target(graph, source, target)? {
+        Some((s, t)) => minimum_st_edge_cut(&network, s, t),
+        None => {
+            ensure_has_nodes(graph)?;
+            if !is_weakly_connected(graph) {
+                return Err(Error {
+                    kind: ErrorKind::InvalidArgument,
+                    message: "The graph is not connected.".to_string(),
+                });
+            }
+            let (v, _) = get_min_degree_node(graph, &network);
+            let mut min_cut = get_incident_arcs(graph, &network, v);
+            for (x, y) in get_pairs_to_separate(graph) {
+                let cut = minimum_st_edge_cut(&network, x, y);
+                if cut.len() <= min_cut.len() {
+                    min_cut = cut;
+                }
+            }
+            min_cut
+        }
+    };
+    let mut cut: Vec<(T, T)> = cut
+        .into_iter()
+        .map(|e| {
+            (
+                get_name(graph, network.tail(e)),
+                get_name(graph, network.head[e]),
+            )
+        })
+        .collect();
+    cut.sort();
+    Ok(cut)
+}
+
+/**
+Returns edge-disjoint paths between `source` and `target`: paths that don't share any edges.
+The number of paths equals the local edge connectivity of `source` and `target`. Returns an
+empty `Vec` if there is no path.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance; for a directed graph the direction of
+  the edges is respected
+* `source`: the node the paths start at
+* `target`: the node the paths end at
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+let paths = connectivity::edge_disjoint_paths(&graph, 0, 33).unwrap();
+assert_eq!(paths.len(), 10);
+assert!(paths.iter().all(|p| p[0] == 0 && p[p.len() - 1] == 33));
+```
+*/
+pub fn edge_disjoint_paths<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (s, t) = get_source_and_target(graph, Some(source), Some(target))?.unwrap();
+    let network = build_edge_network(graph);
+    let (mut network, _) = run_unit_flow(&network, s, t);
+    Ok(decompose_into_paths(&mut network, s, t)
+        .into_iter()
+        .map(|path| path.into_iter().map(|i| get_name(graph, i)).collect())
+        .collect())
+}
+
+/**
+Finds a minimum set of edges separating `source` and `target`. Returns the arcs of the cut,
+each oriented from the `source` side to the `target` side.
+*/
+fn minimum_st_edge_cut(network: &ResidualNetwork, source: usize, target: usize) -> Vec<usize> {
+    let (network, _) = run_unit_flow(network, source, target);
+    let reachable = network.reachable_from(source);
+    (0..network.head.len())
+        .filter(|e| {
+            network.capacity[*e] > 0.0
+                && reachable[network.tail(*e)]
+                && !reachable[network.head[*e]]
+        })
+        .collect()
+}
+
+/**
+Returns a node with the minimum degree and its degree. For a directed graph the degree is the
+smaller of the in-degree and the out-degree, each of which bounds the edge connectivity.
+*/
+fn get_min_degree_node<T, A>(graph: &Graph<T, A>, network: &ResidualNetwork) -> (usize, usize)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes())
+        .map(|i| {
+            let arcs = &network.adjacency[i];
+            let degree = match graph.specs.directed {
+                true => {
+                    let out_degree = arcs.iter().filter(|e| *e % 2 == 0).count();
+                    out_degree.min(arcs.len() - out_degree)
+                }
+                false => arcs.len(),
+            };
+            (i, degree)
+        })
+        .min_by_key(|(i, degree)| (*degree, *i))
+        .unwrap()
+}
+
+/**
+Returns the arcs that isolate node `v`: for an undirected graph, its edges; for a directed
+graph, its out-edges or in-edges, whichever there are fewer of.
+*/
+fn get_incident_arcs<T, A>(graph: &Graph<T, A>, network: &ResidualNetwork, v: usize) -> Vec<usize>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let arcs = &network.adjacency[v];
+    match graph.specs.directed {
+        false => arcs.clone(),
+        true => {
+            let out_arcs: Vec<usize> = arcs.iter().filter(|e| *e % 2 == 0).cloned().collect();
+            let in_arcs: Vec<usize> = arcs.iter().filter(|e| *e % 2 == 1).map(|e| e ^ 1).collect();
+            match out_arcs.len() <= in_arcs.len() {
+                true => out_arcs,
+                false => in_arcs,
+            }
+        }
+    }
+}
+
+/**
+Returns the pairs of nodes whose local edge connectivities determine the edge connectivity of
+the graph. For an undirected graph these are the pairs between a node of a dominating set with
+at least two nodes and each of the other nodes of the set; for a directed graph, each node and
+the next node in the graph, wrapping around at the end.
+*/
+fn get_pairs_to_separate<T, A>(graph: &Graph<T, A>) -> Vec<(usize, usize)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    match graph.specs.directed {
+        true if n < 2 => vec![],
+        true => (0..n).map(|i| (i, (i + 1) % n)).collect(),
+        false => {
+            // in a complete graph every dominating set has one node, and the minimum degree
+            // is the edge connectivity
+            let dominating = (0..n)
+                .map(|start| dominating_set(graph, start))
+                .find(|set| set.len() > 1)
+                .unwrap_or_default();
+            dominating
+                .iter()
+                .skip(1)
+                .map(|w| (dominating[0], *w))
+                .collect()
+        }
+    }
+}
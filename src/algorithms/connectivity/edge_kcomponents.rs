@@ -0,0 +1,111 @@
+use super::auxiliary::{build_edge_network, get_name, run_unit_flow};
+use crate::algorithms::components::UnionFind;
+use crate::algorithms::flow::{gusfield, FlowAlgorithm};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the k-edge-connected components of a graph: the maximal sets of nodes such that the
+local edge connectivity between every pair of nodes in the set is at least `k`. For a directed
+graph the connectivity must be at least `k` in both directions. Every node belongs to exactly
+one component; nodes that don't share a component with any other node are returned as
+singleton sets.
+
+The connectivity is measured in the whole graph, so the paths between two nodes of a
+component can pass through nodes outside of the component.
+
+For an undirected graph the components are read from a Gomory-Hu tree, which needs n - 1
+maximum flow computations. For a directed graph the local edge connectivities are computed
+between a representative of each component and the nodes that haven't been assigned to a
+component yet.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance; multiple edges between two nodes add
+  to the connectivity
+* `k`: the minimum edge connectivity; must be at least one
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, Edge, Graph, GraphSpecs};
+// two triangles joined by a single edge
+let edges = vec![
+    Edge::new(0, 1),
+    Edge::new(1, 2),
+    Edge::new(2, 0),
+    Edge::new(2, 3),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+    Edge::new(5, 3),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let mut components = connectivity::k_edge_components(&graph, 2).unwrap();
+components.sort_by_key(|c| *c.iter().min().unwrap());
+assert_eq!(components[0], vec![0, 1, 2].into_iter().collect());
+assert_eq!(components[1], vec![3, 4, 5].into_iter().collect());
+```
+
+# References
+
+1. T. Wang, Y. Zhang, F. Y. L. Chin, H.-F. Ting, Y. H. Tsin and S.-H. Poon: A simple algorithm
+   for finding all k-edge-connected components. PLoS ONE 10(9), 2015.
+*/
+pub fn k_edge_components<T, A>(graph: &Graph<T, A>, k: usize) -> Result<Vec<HashSet<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if k == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "k must be at least one.".to_string(),
+        });
+    }
+    let n = graph.number_of_nodes();
+    let network = build_edge_network(graph);
+    let mut union_find = UnionFind::new(n);
+    match graph.specs.directed {
+        false => {
+            // two nodes are k-edge-connected if every edge on the tree path between them
+            // has a weight of at least k
+            let (parents, weights) = gusfield(&network, FlowAlgorithm::Dinic)?;
+            for (i, parent) in parents.into_iter().enumerate() {
+                if let Some(p) = parent {
+                    if weights[i].round() as usize >= k {
+                        union_find.union(i, p);
+                    }
+                }
+            }
+        }
+        true => {
+            // being k-edge-connected in both directions is an equivalence relation
+            let mut assigned = vec![false; n];
+            for v in 0..n {
+                if assigned[v] {
+                    continue;
+                }
+                for (w, w_assigned) in assigned.iter_mut().enumerate().skip(v + 1) {
+                    if !*w_assigned
+                        && run_unit_flow(&network, v, w).1 >= k
+                        && run_unit_flow(&network, w, v).1 >= k
+                    {
+                        *w_assigned = true;
+                        union_find.union(v, w);
+                    }
+                }
+            }
+        }
+    }
+    let mut components: HashMap<usize, HashSet<T>> = HashMap::new();
+    for i in 0..n {
+        components
+            .entry(union_find.find(i))
+            .or_default()
+            .insert(get_name(graph, i));
+    }
+    Ok(components.into_values().collect())
+}
@@ -0,0 +1,7 @@
+pub(crate) mod auxiliary;
+mod edge;
+pub use edge::*;
+mod edge_kcomponents;
+pub use edge_kcomponents::*;
+mod node;
+pub use node::*;
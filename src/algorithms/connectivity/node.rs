@@ -0,0 +1,292 @@
+use super::auxiliary::{
+    build_node_network, decompose_into_paths, ensure_has_nodes, get_name, get_neighbors,
+    get_source_and_target, is_weakly_connected, run_unit_flow,
+};
+use crate::{AdjacentNode, Error, ErrorKind, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the node connectivity of a graph: the minimum number of nodes that must be removed to
+disconnect the graph or leave a single node; a directed graph is disconnected when it is no
+longer strongly connected. If `source` and `target` are given, returns the local node
+connectivity instead: the minimum number of nodes that must be removed to break all paths
+from `source` to `target`. For adjacent nodes the edge between them counts as one path.
+
+The connectivity is found with maximum flows in an auxiliary network where each node is split
+into two nodes joined by an arc of capacity one.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance; for a directed graph the direction of
+  the edges is respected
+* `source`: the source node; optional, but must be given along with `target`
+* `target`: the target node; optional, but must be given along with `source`
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+assert_eq!(connectivity::node_connectivity(&graph, None, None).unwrap(), 1);
+assert_eq!(connectivity::node_connectivity(&graph, Some(0), Some(33)).unwrap(), 6);
+```
+
+# References
+
+1. A. H. Esfahanian: Connectivity algorithms. In: Topics in Structural Graph Theory,
+   Cambridge University Press, 2013.
+2. F. Kammer and H. Täubig: Graph connectivity. In: Network Analysis: Methodological
+   Foundations, Springer, 2005.
+*/
+pub fn node_connectivity<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+    target: Option<T>,
+) -> Result<usize, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if let Some((s, t)) = get_source_and_target(graph, source, target)? {
+        let network = build_node_network(graph, 1.0);
+        return Ok(run_unit_flow(&network, 2 * s + 1, 2 * t).1);
+    }
+    ensure_has_nodes(graph)?;
+    if !is_weakly_connected(graph) {
+        return Ok(0);
+    }
+    let network = build_node_network(graph, 1.0);
+    let (v, adjacent) = get_min_degree_node(graph);
+    let mut k = adjacent.len();
+    for (x, y) in get_pairs_to_separate(graph, v) {
+        let (_, value) = run_unit_flow(&network, 2 * x + 1, 2 * y);
+        k = k.min(value);
+    }
+    Ok(k)
+}
+
+/**
+Returns a set of nodes of minimum size that disconnects the graph. If `source` and `target`
+are given, returns a set of nodes of minimum size that breaks all paths from `source` to
+`target`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be connected (weakly
+  connected if directed) when `source` and `target` aren't given
+* `source`: the source node; optional, but must be given along with `target`
+* `target`: the target node; optional, but must be given along with `source`. There must be
+  no edge from `source` to `target`, because then no set of nodes separates them.
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+let cut = connectivity::minimum_node_cut(&graph, None, None).unwrap();
+assert_eq!(cut, vec![0].into_iter().collect());
+let cut = connectivity::minimum_node_cut(&graph, Some(0), Some(33)).unwrap();
+assert_eq!(cut.len(), 6);
+```
+*/
+pub fn minimum_node_cut<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+    target: Option<T>,
+) -> Result<HashSet<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    // edges get a capacity larger than any node cut so that only split-node arcs are cut
+    let edge_capacity = graph.number_of_nodes() as f64;
+    if let Some((s, t)) = get_source_and_target(graph, source, target)? {
+        let successors = graph.get_successor_nodes_by_index(&s);
+        if successors.iter().any(|adj| adj.node_index == t) {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message:
+                    "The source and target nodes are adjacent so no set of nodes separates them."
+                        .to_string(),
+            });
+        }
+        let network = build_node_network(graph, edge_capacity);
+        let cut = minimum_st_node_cut(&network, s, t);
+        return Ok(cut.into_iter().map(|i| get_name(graph, i)).collect());
+    }
+    ensure_has_nodes(graph)?;
+    if !is_weakly_connected(graph) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not connected.".to_string(),
+        });
+    }
+    let network = build_node_network(graph, edge_capacity);
+    let (v, mut min_cut) = get_min_degree_node(graph);
+    for (x, y) in get_pairs_to_separate(graph, v) {
+        let cut = minimum_st_node_cut(&network, x, y);
+        if cut.len() <= min_cut.len() {
+            min_cut = cut;
+        }
+    }
+    Ok(min_cut.into_iter().map(|i| get_name(graph, i)).collect())
+}
+
+/**
+Returns node-disjoint paths between `source` and `target`: paths that share only their first
+and last nodes. The number of paths equals the local node connectivity of `source` and
+`target`. Returns an empty `Vec` if there is no path.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance; for a directed graph the direction of
+  the edges is respected
+* `source`: the node the paths start at
+* `target`: the node the paths end at
+
+# Examples
+
+```
+use graphrs::{algorithms::connectivity, generators};
+let graph = generators::social::karate_club_graph();
+let paths = connectivity::node_disjoint_paths(&graph, 0, 33).unwrap();
+assert_eq!(paths.len(), 6);
+assert!(paths.iter().all(|p| p[0] == 0 && p[p.len() - 1] == 33));
+```
+*/
+pub fn node_disjoint_paths<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (s, t) = get_source_and_target(graph, Some(source), Some(target))?.unwrap();
+    let network = build_node_network(graph, 1.0);
+    let (mut network, _) = run_unit_flow(&network, 2 * s + 1, 2 * t);
+    Ok(decompose_into_paths(&mut network, 2 * s + 1, 2 * t)
+        .into_iter()
+        .map(|path| {
+            // each node maps to an "in" node and an "out" node in the auxiliary network
+            let mut nodes: Vec<usize> = path.into_iter().map(|i| i / 2).collect();
+            nodes.dedup();
+            nodes.into_iter().map(|i| get_name(graph, i)).collect()
+        })
+        .collect())
+}
+
+/**
+Finds a minimum set of nodes separating `source` and `target`, which must not be adjacent, in
+an auxiliary network whose edge arcs can't be cut.
+*/
+fn minimum_st_node_cut(
+    network: &crate::algorithms::flow::residual::ResidualNetwork,
+    source: usize,
+    target: usize,
+) -> Vec<usize> {
+    let (network, _) = run_unit_flow(network, 2 * source + 1, 2 * target);
+    let reachable = network.reachable_from(2 * source + 1);
+    (0..(network.num_nodes / 2))
+        .filter(|i| reachable[2 * i] && !reachable[2 * i + 1])
+        .collect()
+}
+
+/**
+Returns a node with the minimum degree and the nodes its degree counts, which separate it from
+the rest of the graph. For a directed graph the degree is the smaller of the number of
+predecessors and the number of successors.
+*/
+fn get_min_degree_node<T, A>(graph: &Graph<T, A>) -> (usize, Vec<usize>)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes())
+        .flat_map(|i| {
+            let adjacent: Vec<Vec<usize>> = match graph.specs.directed {
+                true => vec![
+                    get_adjacent(graph.get_predecessor_nodes_by_index(&i), i),
+                    get_adjacent(graph.get_successor_nodes_by_index(&i), i),
+                ],
+                false => vec![get_neighbors(graph, i).into_iter().collect()],
+            };
+            adjacent.into_iter().map(move |nodes| (i, nodes))
+        })
+        .min_by_key(|(i, nodes)| (nodes.len(), *i))
+        .unwrap()
+}
+
+/**
+Returns the pairs of nodes whose local connectivities determine the node connectivity of the
+graph, following Esfahanian's algorithm. For an undirected graph these are `v` and each of its
+non-neighbors, and the non-adjacent pairs of neighbors of `v`. For a directed graph they are
+the ordered pairs `(v, w)` with no edge from `v` to `w`, the ordered pairs `(w, v)` with no
+edge from `w` to `v`, and the pairs `(x, y)` of a predecessor `x` and a successor `y` of `v`
+with no edge from `x` to `y`.
+*/
+fn get_pairs_to_separate<T, A>(graph: &Graph<T, A>, v: usize) -> Vec<(usize, usize)>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let get_successors = |x: usize| -> HashSet<usize> {
+        graph
+            .get_successor_nodes_by_index(&x)
+            .iter()
+            .map(|adj| adj.node_index)
+            .collect()
+    };
+    let mut pairs = vec![];
+    if graph.specs.directed {
+        let successors = get_adjacent(graph.get_successor_nodes_by_index(&v), v);
+        let predecessors = get_adjacent(graph.get_predecessor_nodes_by_index(&v), v);
+        for w in (0..graph.number_of_nodes()).filter(|w| *w != v) {
+            if !successors.contains(&w) {
+                pairs.push((v, w));
+            }
+            if !predecessors.contains(&w) {
+                pairs.push((w, v));
+            }
+        }
+        for x in predecessors {
+            let x_successors = get_successors(x);
+            for y in successors.iter().filter(|y| **y != x) {
+                if !x_successors.contains(y) {
+                    pairs.push((x, *y));
+                }
+            }
+        }
+        return pairs;
+    }
+    let neighbors = get_neighbors(graph, v);
+    for w in (0..graph.number_of_nodes()).filter(|w| *w != v && !neighbors.contains(w)) {
+        pairs.push((v, w));
+    }
+    let mut neighbors: Vec<usize> = neighbors.into_iter().collect();
+    neighbors.sort();
+    for (i, x) in neighbors.iter().enumerate() {
+        let x_successors = get_successors(*x);
+        for y in neighbors[(i + 1)..].iter() {
+            if !x_successors.contains(y) {
+                pairs.push((*x, *y));
+            }
+        }
+    }
+    pairs
+}
+
+/// Returns the sorted, distinct indexes of `adjacent`, without `node` itself.
+fn get_adjacent(adjacent: &[AdjacentNode], node: usize) -> Vec<usize> {
+    let mut nodes: Vec<usize> = adjacent
+        .iter()
+        .map(|adj| adj.node_index)
+        .filter(|i| *i != node)
+        .collect();
+    nodes.sort();
+    nodes.dedup();
+    nodes
+}
@@ -0,0 +1,345 @@
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the core number of each node. A *k*-core is a maximal subgraph in which every node
+has a degree of at least *k*; the core number of a node is the largest *k* for which it is in
+the *k*-core.
+
+Uses the algorithm of Batagelj and Zaversnik, which takes O(m) time. For directed graphs the
+degree of a node is the sum of its in-degree and out-degree.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance without self-loops or multi-edges; an
+  `ErrorKind::SelfLoopsFound` error is returned if it has self-loops
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let core_numbers = core::core_number(&graph).unwrap();
+assert_eq!(core_numbers[&0], 4);
+assert_eq!(core_numbers[&11], 1);
+```
+
+# References
+
+1. V. Batagelj and M. Zaversnik: An O(m) algorithm for cores decomposition of networks.
+   arXiv:cs/0310049, 2003.
+*/
+pub fn core_number<T, A>(graph: &Graph<T, A>) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    ensure_no_self_loops(graph)?;
+    let neighbors = get_neighbor_lists(graph);
+    let n = neighbors.len();
+    let mut degrees: Vec<usize> = neighbors.iter().map(|nbrs| nbrs.len()).collect();
+    let max_degree = degrees.iter().max().cloned().unwrap_or(0);
+    // the nodes sorted by degree, with the position at which each degree starts
+    let mut bin_starts = vec![0; max_degree + 2];
+    for d in degrees.iter() {
+        bin_starts[d + 1] += 1;
+    }
+    for d in 1..bin_starts.len() {
+        bin_starts[d] += bin_starts[d - 1];
+    }
+    let mut nodes = vec![0; n];
+    let mut positions = vec![0; n];
+    let mut next = bin_starts.clone();
+    for (v, d) in degrees.iter().enumerate() {
+        positions[v] = next[*d];
+        nodes[positions[v]] = v;
+        next[*d] += 1;
+    }
+    for i in 0..n {
+        let v = nodes[i];
+        for u in neighbors[v].iter() {
+            let du = degrees[*u];
+            if du > degrees[v] {
+                // move u to the start of its bin, then shrink the bin past it
+                let w = nodes[bin_starts[du]];
+                nodes.swap(positions[*u], bin_starts[du]);
+                positions.swap(*u, w);
+                bin_starts[du] += 1;
+                degrees[*u] -= 1;
+            }
+        }
+    }
+    Ok(degrees
+        .into_iter()
+        .enumerate()
+        .map(|(i, d)| (graph.get_node_by_index(&i).unwrap().name.clone(), d))
+        .collect())
+}
+
+/**
+Returns the *k*-core of a graph: the maximal subgraph in which every node has a degree of at
+least `k`. For directed graphs the degree of a node is the sum of its in-degree and out-degree.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance without self-loops or multi-edges
+* `k`: the order of the core; defaults to the largest core number, giving the main core
+* `core_numbers`: the core number of each node, as returned by
+  [core_number](./fn.core_number.html); optional. They are computed if not given.
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let main_core = core::k_core(&graph, None, None).unwrap();
+assert_eq!(main_core.number_of_nodes(), 10);
+let three_core = core::k_core(&graph, Some(3), None).unwrap();
+assert_eq!(three_core.number_of_nodes(), 22);
+```
+
+# References
+
+1. S. B. Seidman: Network structure and minimum degree. Social Networks 5(3):269–287, 1983.
+*/
+pub fn k_core<T, A>(
+    graph: &Graph<T, A>,
+    k: Option<usize>,
+    core_numbers: Option<&HashMap<T, usize>>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_core_subgraph(graph, core_numbers, |c, max_c| c >= k.unwrap_or(max_c))
+}
+
+/**
+Returns the *k*-shell of a graph: the subgraph induced by the nodes whose core number is
+exactly `k`. These are the nodes of the *k*-core that are not in the (*k*+1)-core.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance without self-loops or multi-edges
+* `k`: the order of the shell; defaults to the largest core number
+* `core_numbers`: the core number of each node, as returned by
+  [core_number](./fn.core_number.html); optional. They are computed if not given.
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let shell = core::k_shell(&graph, Some(2), None).unwrap();
+assert_eq!(shell.number_of_nodes(), 11);
+```
+
+# References
+
+1. S. Carmi, S. Havlin, S. Kirkpatrick, Y. Shavitt and E. Shir: A model of Internet topology
+   using k-shell decomposition. PNAS 104(27):11150–11154, 2007.
+*/
+pub fn k_shell<T, A>(
+    graph: &Graph<T, A>,
+    k: Option<usize>,
+    core_numbers: Option<&HashMap<T, usize>>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_core_subgraph(graph, core_numbers, |c, max_c| c == k.unwrap_or(max_c))
+}
+
+/**
+Returns the *k*-crust of a graph: the subgraph induced by the nodes whose core number is at
+most `k`. It is what is left of the graph when the (*k*+1)-core is removed.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance without self-loops or multi-edges
+* `k`: the order of the crust; defaults to one less than the largest core number, giving the
+  graph without its main core
+* `core_numbers`: the core number of each node, as returned by
+  [core_number](./fn.core_number.html); optional. They are computed if not given.
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let crust = core::k_crust(&graph, None, None).unwrap();
+assert_eq!(crust.number_of_nodes(), 24);
+```
+
+# References
+
+1. S. Carmi, S. Havlin, S. Kirkpatrick, Y. Shavitt and E. Shir: A model of Internet topology
+   using k-shell decomposition. PNAS 104(27):11150–11154, 2007.
+*/
+pub fn k_crust<T, A>(
+    graph: &Graph<T, A>,
+    k: Option<usize>,
+    core_numbers: Option<&HashMap<T, usize>>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    get_core_subgraph(graph, core_numbers, |c, max_c| match k {
+        Some(k) => c <= k,
+        None => c < max_c,
+    })
+}
+
+/**
+Returns the *k*-corona of a graph: the subgraph induced by the nodes of the *k*-core that have
+exactly `k` neighbors in the *k*-core. For directed graphs only the successors of a node are
+counted as its neighbors.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance without self-loops or multi-edges
+* `k`: the order of the corona
+* `core_numbers`: the core number of each node, as returned by
+  [core_number](./fn.core_number.html); optional. They are computed if not given.
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let corona = core::k_corona(&graph, 4, None).unwrap();
+let mut nodes = corona.get_all_node_names();
+nodes.sort();
+assert_eq!(nodes, vec![&7, &30, &32, &33]);
+```
+
+# References
+
+1. A. V. Goltsev, S. N. Dorogovtsev and J. F. F. Mendes: k-core (bootstrap) percolation on
+   complex networks: critical phenomena and nonlocal effects. Physical Review E 73, 056101,
+   2006.
+*/
+pub fn k_corona<T, A>(
+    graph: &Graph<T, A>,
+    k: usize,
+    core_numbers: Option<&HashMap<T, usize>>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let core_numbers = get_core_numbers(graph, core_numbers)?;
+    let nodes: Vec<T> = core_numbers
+        .iter()
+        .enumerate()
+        .filter(|(v, c)| {
+            **c == k
+                && graph
+                    .get_successor_nodes_by_index(v)
+                    .iter()
+                    .filter(|adj| core_numbers[adj.node_index] >= k)
+                    .count()
+                    == k
+        })
+        .map(|(v, _c)| graph.get_node_by_index(&v).unwrap().name.clone())
+        .collect();
+    graph.get_subgraph(&nodes)
+}
+
+/// Returns an `Err` if the `graph` has any self-loops.
+pub(super) fn ensure_no_self_loops<T, A>(graph: &Graph<T, A>) -> Result<(), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let has_self_loops = (0..graph.number_of_nodes()).any(|v| {
+        graph
+            .get_successor_nodes_by_index(&v)
+            .iter()
+            .any(|adj| adj.node_index == v)
+    });
+    if has_self_loops {
+        return Err(Error {
+            kind: ErrorKind::SelfLoopsFound,
+            message: "This method is not applicable to graphs with self-loops.".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the neighbors of each node by index. For directed graphs these are the successors
+/// followed by the predecessors, so a node is listed twice if the edges go both ways.
+pub(super) fn get_neighbor_lists<T, A>(graph: &Graph<T, A>) -> Vec<Vec<usize>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes())
+        .map(|v| {
+            let successors = graph.get_successor_nodes_by_index(&v).iter();
+            match graph.specs.directed {
+                true => successors
+                    .chain(graph.get_predecessor_nodes_by_index(&v).iter())
+                    .map(|adj| adj.node_index)
+                    .collect(),
+                false => successors.map(|adj| adj.node_index).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the core number of each node by index, either from `core_numbers` or computed.
+fn get_core_numbers<T, A>(
+    graph: &Graph<T, A>,
+    core_numbers: Option<&HashMap<T, usize>>,
+) -> Result<Vec<usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let computed;
+    let core_numbers = match core_numbers {
+        Some(core_numbers) => core_numbers,
+        None => {
+            computed = core_number(graph)?;
+            &computed
+        }
+    };
+    (0..graph.number_of_nodes())
+        .map(|v| {
+            let name = &graph.get_node_by_index(&v).unwrap().name;
+            core_numbers.get(name).cloned().ok_or_else(|| Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("No core number was given for node {}.", name),
+            })
+        })
+        .collect()
+}
+
+/// Returns the subgraph induced by the nodes for which `filter` returns true. `filter` is given
+/// the core number of a node and the largest core number.
+fn get_core_subgraph<T, A, F>(
+    graph: &Graph<T, A>,
+    core_numbers: Option<&HashMap<T, usize>>,
+    filter: F,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+    F: Fn(usize, usize) -> bool,
+{
+    let core_numbers = get_core_numbers(graph, core_numbers)?;
+    let max_core_number = core_numbers.iter().max().cloned().unwrap_or(0);
+    let nodes: Vec<T> = core_numbers
+        .iter()
+        .enumerate()
+        .filter(|(_v, c)| filter(**c, max_core_number))
+        .map(|(v, _c)| graph.get_node_by_index(&v).unwrap().name.clone())
+        .collect();
+    graph.get_subgraph(&nodes)
+}
@@ -0,0 +1,6 @@
+mod decomposition;
+pub use decomposition::*;
+mod onion;
+pub use onion::*;
+mod truss;
+pub use truss::*;
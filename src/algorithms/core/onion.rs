@@ -0,0 +1,106 @@
+use super::decomposition::{ensure_no_self_loops, get_neighbor_lists};
+use crate::{Error, Graph};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the layer of each node in the onion decomposition of an undirected graph.
+
+The onion decomposition refines the *k*-core decomposition. The nodes are removed in layers:
+each layer is made up of the nodes whose degree, among the nodes not yet removed, is at most
+the current core number, and the core number increases when no node has so low a degree.
+Isolated nodes make up the first layer. Layers are numbered from one.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance without self-loops or
+  multi-edges; an `ErrorKind::SelfLoopsFound` error is returned if it has self-loops
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let layers = core::onion_layers(&graph).unwrap();
+assert_eq!(layers[&11], 1);
+assert_eq!(layers[&0], 7);
+```
+
+# References
+
+1. L. Hébert-Dufresne, J. A. Grochow and A. Allard: Multi-scale structure and topological
+   anomaly detection via a new network statistic: The onion decomposition. Scientific Reports
+   6, 31708, 2016.
+*/
+pub fn onion_layers<T, A>(graph: &Graph<T, A>) -> Result<HashMap<T, usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    graph.ensure_not_multi_edges()?;
+    ensure_no_self_loops(graph)?;
+    let neighbors = get_neighbor_lists(graph);
+    let n = neighbors.len();
+    let mut degrees: Vec<usize> = neighbors.iter().map(|nbrs| nbrs.len()).collect();
+    let mut layers: Vec<Option<usize>> = vec![None; n];
+    let mut current_layer = 1;
+    if degrees.contains(&0) {
+        for v in (0..n).filter(|v| degrees[*v] == 0) {
+            layers[v] = Some(current_layer);
+        }
+        current_layer += 1;
+    }
+    // a heap of (degree, node) pairs; a pair is stale if the node's degree has since fallen
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = (0..n)
+        .filter(|v| degrees[*v] > 0)
+        .map(|v| Reverse((degrees[v], v)))
+        .collect();
+    let is_current = |layers: &Vec<Option<usize>>, degrees: &Vec<usize>, d: usize, v: usize| {
+        layers[v].is_none() && degrees[v] == d
+    };
+    let mut current_core = 1;
+    loop {
+        while let Some(Reverse((d, v))) = heap.peek() {
+            if is_current(&layers, &degrees, *d, *v) {
+                break;
+            }
+            heap.pop();
+        }
+        let min_degree = match heap.peek() {
+            None => break,
+            Some(Reverse((d, _v))) => *d,
+        };
+        current_core = current_core.max(min_degree);
+        let mut layer = vec![];
+        while let Some(Reverse((d, v))) = heap.peek() {
+            if *d > current_core {
+                break;
+            }
+            if is_current(&layers, &degrees, *d, *v) {
+                layer.push(*v);
+            }
+            heap.pop();
+        }
+        for v in layer.iter() {
+            layers[*v] = Some(current_layer);
+        }
+        for v in layer {
+            for u in neighbors[v].iter().filter(|u| layers[**u].is_none()) {
+                degrees[*u] -= 1;
+                heap.push(Reverse((degrees[*u], *u)));
+            }
+        }
+        current_layer += 1;
+    }
+    Ok(layers
+        .into_iter()
+        .enumerate()
+        .map(|(i, layer)| {
+            let name = graph.get_node_by_index(&i).unwrap().name.clone();
+            (name, layer.unwrap())
+        })
+        .collect())
+}
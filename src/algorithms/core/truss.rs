@@ -0,0 +1,106 @@
+use super::decomposition::ensure_no_self_loops;
+use crate::{Edge, Error, Graph, Node};
+use nohash::IntSet;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/**
+Returns the *k*-truss of an undirected graph: the maximal subgraph in which every edge is part
+of at least `k` - 2 triangles. Nodes that are left without edges are not part of the *k*-truss.
+
+Edges are removed while they are in too few triangles, updating the triangle counts of the
+edges they share triangles with, which takes O(m^1.5) time.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance without self-loops or
+  multi-edges; an `ErrorKind::SelfLoopsFound` error is returned if it has self-loops
+* `k`: the order of the truss
+
+# Examples
+
+```
+use graphrs::{algorithms::core, generators};
+let graph = generators::social::karate_club_graph();
+let truss = core::k_truss(&graph, 5).unwrap();
+assert_eq!(truss.number_of_nodes(), 6);
+assert_eq!(truss.number_of_edges(), 14);
+```
+
+# References
+
+1. J. Cohen: Trusses: Cohesive subgraphs for social network analysis. National Security Agency
+   Technical Report, 2008.
+*/
+pub fn k_truss<T, A>(graph: &Graph<T, A>, k: usize) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    graph.ensure_not_multi_edges()?;
+    ensure_no_self_loops(graph)?;
+    let min_support = k.saturating_sub(2);
+    let n = graph.number_of_nodes();
+    let mut adjacency: Vec<IntSet<usize>> = (0..n)
+        .map(|v| {
+            graph
+                .get_successor_nodes_by_index(&v)
+                .iter()
+                .map(|adj| adj.node_index)
+                .collect()
+        })
+        .collect();
+    // the number of triangles each edge is part of
+    let mut support: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut to_remove: Vec<(usize, usize)> = vec![];
+    for u in 0..n {
+        for v in adjacency[u].iter().filter(|v| u < **v) {
+            let triangles = adjacency[u].intersection(&adjacency[*v]).count();
+            support.insert((u, *v), triangles);
+            if triangles < min_support {
+                to_remove.push((u, *v));
+            }
+        }
+    }
+    while let Some((u, v)) = to_remove.pop() {
+        if !adjacency[u].remove(&v) {
+            continue;
+        }
+        adjacency[v].remove(&u);
+        let common: Vec<usize> = adjacency[u].intersection(&adjacency[v]).cloned().collect();
+        for w in common {
+            for edge in [ordered(u, w), ordered(v, w)] {
+                let triangles = support.get_mut(&edge).unwrap();
+                if *triangles == min_support {
+                    to_remove.push(edge);
+                }
+                *triangles -= 1;
+            }
+        }
+    }
+    let nodes: Vec<Arc<Node<T, A>>> = (0..n)
+        .filter(|v| !adjacency[*v].is_empty())
+        .map(|v| graph.get_node_by_index(&v).unwrap().clone())
+        .collect();
+    let edges: Vec<Arc<Edge<T, A>>> = graph
+        .get_all_edges()
+        .into_iter()
+        .filter(|e| {
+            let u = graph.get_node_index(&e.u).unwrap();
+            let v = graph.get_node_index(&e.v).unwrap();
+            adjacency[u].contains(&v)
+        })
+        .cloned()
+        .collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
+
+fn ordered(u: usize, v: usize) -> (usize, usize) {
+    match u < v {
+        true => (u, v),
+        false => (v, u),
+    }
+}
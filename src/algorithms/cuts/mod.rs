@@ -1,3 +1,6 @@
+mod stoer_wagner;
+pub use stoer_wagner::*;
+
 use crate::{
     algorithms::boundary::{edge_boundary, edge_boundary_by_indexes},
     Error, Graph,
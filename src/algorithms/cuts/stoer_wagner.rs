@@ -0,0 +1,234 @@
+use crate::algorithms::centrality::fringe_node::{push_fringe_node, FringeNode};
+use crate::{Error, ErrorKind, Graph};
+use nohash::IntMap;
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns the weighted minimum edge cut of an undirected graph, computed using the Stoer-Wagner
+algorithm.
+
+The global minimum cut is the partition of the nodes into two non-empty sets that minimizes
+the total weight of the edges between the two sets. Unlike an s-t cut no source or target is
+given. The algorithm runs in O(nm log n) time.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected, connected and
+  have at least two nodes. The weights of multiple edges between two nodes are summed and
+  self-loops are ignored.
+* `weighted`: if `true` the edge weights are used, and they must all be specified and
+  non-negative; if `false` every edge has a weight of 1.
+
+# Returns
+
+A tuple of the cut value and the two sets of nodes of the partition.
+
+# Examples
+
+```
+use graphrs::{algorithms::cuts, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("x", "a", 3.0),
+    Edge::with_weight("x", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("b", "c", 5.0),
+    Edge::with_weight("b", "d", 4.0),
+    Edge::with_weight("d", "e", 2.0),
+    Edge::with_weight("c", "y", 2.0),
+    Edge::with_weight("e", "y", 3.0),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let (cut_value, (partition1, partition2)) = cuts::stoer_wagner(&graph, true).unwrap();
+assert_eq!(cut_value, 4.0);
+assert_eq!(partition1.len() + partition2.len(), 7);
+```
+
+# References
+
+1. M. Stoer and F. Wagner: A simple min-cut algorithm. Journal of the ACM 44(4):585–591, 1997.
+*/
+#[allow(clippy::type_complexity)]
+pub fn stoer_wagner<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<(f64, (HashSet<T>, HashSet<T>)), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    if n < 2 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has less than two nodes.".to_string(),
+        });
+    }
+    let mut adjacency = get_weighted_adjacency(graph, weighted)?;
+    if !is_connected(&adjacency) {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph is not connected.".to_string(),
+        });
+    }
+
+    let mut removed = vec![false; n];
+    let mut in_a = vec![false; n];
+    let mut keys = vec![0.0; n];
+    let mut cut_value = f64::INFINITY;
+    let mut best_phase = 0;
+    let mut contractions: Vec<(usize, usize)> = Vec::with_capacity(n - 1);
+
+    for phase in 0..(n - 1) {
+        // run a maximum adjacency search from an arbitrary node
+        let mut u = removed.iter().position(|r| !r).unwrap();
+        in_a.fill(false);
+        keys.fill(0.0);
+        in_a[u] = true;
+        let mut heap = BinaryHeap::new();
+        add_to_keys(&adjacency[u], &in_a, &mut keys, &mut heap);
+        for _ in 0..(n - phase - 2) {
+            u = pop_max(&mut heap, &in_a, &keys);
+            in_a[u] = true;
+            add_to_keys(&adjacency[u], &in_a, &mut keys, &mut heap);
+        }
+        // the weight of the edges between the last node and all the others is the
+        // "cut of the phase"
+        let v = pop_max(&mut heap, &in_a, &keys);
+        if keys[v] < cut_value {
+            cut_value = keys[v];
+            best_phase = phase;
+        }
+        contractions.push((u, v));
+        // merge the last node into the second-to-last node
+        let v_adjacency = std::mem::take(&mut adjacency[v]);
+        for (x, w) in v_adjacency.into_iter() {
+            adjacency[x].remove(&v);
+            if x != u {
+                *adjacency[u].entry(x).or_insert(0.0) += w;
+                *adjacency[x].entry(u).or_insert(0.0) += w;
+            }
+        }
+        removed[v] = true;
+    }
+
+    // recover the partition: the nodes that were merged into the last node of the best phase
+    let mut merged: Vec<Vec<usize>> = vec![vec![]; n];
+    for (u, v) in contractions.iter().take(best_phase) {
+        merged[*u].push(*v);
+        merged[*v].push(*u);
+    }
+    let start = contractions[best_phase].1;
+    let mut reachable = vec![false; n];
+    reachable[start] = true;
+    let mut stack = vec![start];
+    while let Some(u) = stack.pop() {
+        for v in merged[u].iter() {
+            if !reachable[*v] {
+                reachable[*v] = true;
+                stack.push(*v);
+            }
+        }
+    }
+    let mut partition1 = HashSet::new();
+    let mut partition2 = HashSet::new();
+    for (i, r) in reachable.into_iter().enumerate() {
+        let name = graph.get_node_by_index(&i).unwrap().name.clone();
+        match r {
+            true => partition1.insert(name),
+            false => partition2.insert(name),
+        };
+    }
+    Ok((cut_value, (partition1, partition2)))
+}
+
+/**
+Gets the adjacency of the graph as maps of node index to the total weight of the edges
+between the two nodes, ignoring self-loops.
+*/
+fn get_weighted_adjacency<T, A>(
+    graph: &Graph<T, A>,
+    weighted: bool,
+) -> Result<Vec<IntMap<usize, f64>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let mut adjacency: Vec<IntMap<usize, f64>> = vec![IntMap::default(); graph.number_of_nodes()];
+    for edge in graph.get_all_edges() {
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        if weight < 0.0 {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "Edge weights must be non-negative.".to_string(),
+            });
+        }
+        let u = graph.get_node_index(&edge.u)?;
+        let v = graph.get_node_index(&edge.v)?;
+        if u == v {
+            continue;
+        }
+        *adjacency[u].entry(v).or_insert(0.0) += weight;
+        *adjacency[v].entry(u).or_insert(0.0) += weight;
+    }
+    Ok(adjacency)
+}
+
+fn is_connected(adjacency: &[IntMap<usize, f64>]) -> bool {
+    let mut seen = vec![false; adjacency.len()];
+    seen[0] = true;
+    let mut stack = vec![0];
+    let mut count = 1;
+    while let Some(u) = stack.pop() {
+        for v in adjacency[u].keys() {
+            if !seen[*v] {
+                seen[*v] = true;
+                count += 1;
+                stack.push(*v);
+            }
+        }
+    }
+    count == adjacency.len()
+}
+
+/**
+Adds the weights of the edges from a node that has just been added to the set A to the keys
+of its neighbors that are not in A.
+*/
+#[inline]
+fn add_to_keys(
+    neighbors: &IntMap<usize, f64>,
+    in_a: &[bool],
+    keys: &mut [f64],
+    heap: &mut BinaryHeap<FringeNode>,
+) {
+    for (v, w) in neighbors.iter() {
+        if !in_a[*v] {
+            keys[*v] += w;
+            // negate the key because `push_fringe_node` pops the smallest distance first
+            push_fringe_node(heap, 0, *v, -keys[*v]);
+        }
+    }
+}
+
+/**
+Pops the node not in the set A with the largest key, skipping stale heap entries.
+*/
+#[inline]
+fn pop_max(heap: &mut BinaryHeap<FringeNode>, in_a: &[bool], keys: &[f64]) -> usize {
+    loop {
+        let fringe_node = heap.pop().unwrap();
+        if !in_a[fringe_node.v] && fringe_node.distance == keys[fringe_node.v] {
+            return fringe_node.v;
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use crate::algorithms::centrality::fringe_node::{push_fringe_node, FringeNode};
+use crate::{Error, Graph};
+use nohash::IntSet;
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a list of cycles which form a basis for the cycles of an undirected graph.
+
+A basis for cycles of a graph is a minimal collection of cycles such that any cycle in the
+graph can be written as a sum of cycles in the basis. Here summation of cycles is defined as
+"exclusive or" of the edges. Cycle bases are useful, e.g. when deriving equations for electric
+circuits using Kirchhoff's Laws.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected
+* `root`: the node to start the search from; if `None` an arbitrary node is used and the
+  search is repeated for each connected component
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new(0, 1),
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+    Edge::new(3, 0),
+    Edge::new(0, 4),
+    Edge::new(4, 5),
+    Edge::new(5, 0),
+];
+let graph: Graph<i32, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let basis = cycles::cycle_basis(&graph, Some(0)).unwrap();
+assert_eq!(basis.len(), 2);
+```
+
+# References
+
+1. K. Paton: An algorithm for finding a fundamental set of cycles of a graph.
+   Comm. ACM 12(9):514-518, 1969.
+*/
+pub fn cycle_basis<T, A>(graph: &Graph<T, A>, root: Option<T>) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let num_nodes = graph.number_of_nodes();
+    let mut root = match root {
+        Some(r) => Some(graph.get_node_index(&r)?),
+        None => None,
+    };
+    let mut cycles: Vec<Vec<usize>> = vec![];
+    let mut pred = vec![usize::MAX; num_nodes];
+    let mut used: Vec<Option<IntSet<usize>>> = vec![None; num_nodes];
+    let mut remaining = 0..num_nodes;
+    loop {
+        let r = match root.take() {
+            Some(r) => r,
+            None => match remaining.find(|v| used[*v].is_none()) {
+                Some(r) => r,
+                None => break,
+            },
+        };
+        let mut stack = vec![r];
+        pred[r] = r;
+        used[r] = Some(IntSet::default());
+        while let Some(z) = stack.pop() {
+            for adj in graph.get_successor_nodes_by_index(&z) {
+                let nbr = adj.node_index;
+                if used[nbr].is_none() {
+                    pred[nbr] = z;
+                    stack.push(nbr);
+                    used[nbr] = Some(vec![z].into_iter().collect());
+                } else if nbr == z {
+                    cycles.push(vec![z]);
+                } else if !used[z].as_ref().unwrap().contains(&nbr) {
+                    let pn = used[nbr].as_ref().unwrap();
+                    let mut cycle = vec![nbr, z];
+                    let mut p = pred[z];
+                    while !pn.contains(&p) {
+                        cycle.push(p);
+                        p = pred[p];
+                    }
+                    cycle.push(p);
+                    cycles.push(cycle);
+                    used[nbr].as_mut().unwrap().insert(z);
+                }
+            }
+        }
+    }
+    Ok(cycles
+        .into_iter()
+        .map(|cycle| indexes_to_names(graph, &cycle))
+        .collect())
+}
+
+/**
+Returns a minimum weight cycle basis of an undirected graph.
+
+Minimum weight means a cycle basis for which the total weight (length for unweighted graphs)
+of all the cycles is minimum. The nodes of each cycle are not necessarily returned in the
+order in which they appear in the cycle.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected and must not
+  have multiple edges between two nodes
+* `weighted`: set to `true` to use edge weights, `false` to give every edge a weight of 1
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new(0, 1),
+    Edge::new(1, 2),
+    Edge::new(2, 3),
+    Edge::new(3, 0),
+    Edge::new(3, 4),
+    Edge::new(4, 5),
+    Edge::new(5, 0),
+];
+let graph: Graph<i32, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+        .unwrap();
+let basis = cycles::minimum_cycle_basis(&graph, false).unwrap();
+assert_eq!(basis.len(), 2);
+assert!(basis.iter().all(|cycle| cycle.len() == 4));
+```
+
+# References
+
+1. Telikepalli Kavitha, Kurt Mehlhorn, Dimitrios Michail and Katarzyna Paluch:
+   An Õ(m²n) Algorithm for Minimum Cycle Basis of Graphs. Algorithmica 52:333-349, 2008.
+
+2. J. de Pina: Applications of shortest path methods. Ph.D. thesis, University of
+   Amsterdam, 1995.
+*/
+pub fn minimum_cycle_basis<T, A>(graph: &Graph<T, A>, weighted: bool) -> Result<Vec<Vec<T>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    graph.ensure_not_multi_edges()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    let num_nodes = graph.number_of_nodes();
+    let mut seen = vec![false; num_nodes];
+    let mut basis = vec![];
+    for root in 0..num_nodes {
+        if seen[root] {
+            continue;
+        }
+        // a BFS spanning tree of the component; the chords are the edges not in the tree
+        let mut component = vec![root];
+        let mut tree_edges: HashSet<(usize, usize)> = HashSet::new();
+        seen[root] = true;
+        let mut i = 0;
+        while i < component.len() {
+            let v = component[i];
+            for adj in graph.get_successor_nodes_by_index(&v) {
+                if !seen[adj.node_index] {
+                    seen[adj.node_index] = true;
+                    tree_edges.insert(ordered_pair(v, adj.node_index));
+                    component.push(adj.node_index);
+                }
+            }
+            i += 1;
+        }
+        component.sort_unstable();
+        let edges: Vec<(usize, usize, f64)> = component
+            .iter()
+            .flat_map(|u| {
+                graph
+                    .get_successor_nodes_by_index(u)
+                    .iter()
+                    .filter(|adj| *u <= adj.node_index)
+                    .map(|adj| {
+                        let weight = match weighted {
+                            true => adj.weight,
+                            false => 1.0,
+                        };
+                        (*u, adj.node_index, weight)
+                    })
+                    .collect::<Vec<(usize, usize, f64)>>()
+            })
+            .collect();
+        let mut set_orth: Vec<HashSet<(usize, usize)>> = edges
+            .iter()
+            .filter(|(u, v, _)| !tree_edges.contains(&(*u, *v)))
+            .map(|(u, v, _)| vec![(*u, *v)].into_iter().collect())
+            .collect();
+        while let Some(base) = set_orth.pop() {
+            let cycle_edges = get_min_cycle(num_nodes, &component, &edges, &base);
+            basis.push(
+                cycle_edges
+                    .iter()
+                    .map(|(_, v)| graph.get_node_by_index(v).unwrap().name.clone())
+                    .collect(),
+            );
+            // make the remaining vectors orthogonal to the newly found cycle
+            for orth in set_orth.iter_mut() {
+                let count = cycle_edges
+                    .iter()
+                    .filter(|(u, v)| orth.contains(&ordered_pair(*u, *v)))
+                    .count();
+                if count % 2 == 1 {
+                    *orth = orth.symmetric_difference(&base).cloned().collect();
+                }
+            }
+        }
+    }
+    Ok(basis)
+}
+
+/**
+Computes the minimum weight cycle orthogonal to `orth`, as a list of edges.
+
+A "lifted" graph with two copies of every node is built: edges in `orth` cross between the
+copies, all other edges stay within a copy. The shortest path from a node to its own copy
+is the shortest cycle that uses an odd number of edges from `orth`.
+*/
+fn get_min_cycle(
+    num_nodes: usize,
+    component: &[usize],
+    edges: &[(usize, usize, f64)],
+    orth: &HashSet<(usize, usize)>,
+) -> Vec<(usize, usize)> {
+    let mut lifted: Vec<Vec<(usize, f64)>> = vec![vec![]; num_nodes * 2];
+    let mut add_edge = |u: usize, v: usize, weight: f64| {
+        lifted[u].push((v, weight));
+        lifted[v].push((u, weight));
+    };
+    for (u, v, weight) in edges {
+        match orth.contains(&(*u, *v)) {
+            true => {
+                add_edge(*u, v + num_nodes, *weight);
+                add_edge(u + num_nodes, *v, *weight);
+            }
+            false => {
+                add_edge(*u, *v, *weight);
+                add_edge(u + num_nodes, v + num_nodes, *weight);
+            }
+        }
+    }
+    let mut best: Option<(f64, usize, Vec<usize>)> = None;
+    for start in component {
+        let (distance, pred) = dijkstra(&lifted, *start);
+        let d = distance[start + num_nodes];
+        if best.is_none() || d < best.as_ref().unwrap().0 {
+            best = Some((d, *start, pred));
+        }
+    }
+    let (_, start, pred) = best.unwrap();
+    let mut path = vec![start + num_nodes];
+    let mut node = start + num_nodes;
+    while node != start {
+        node = pred[node];
+        path.push(node);
+    }
+    path.reverse();
+    let path: Vec<usize> = path.into_iter().map(|n| n % num_nodes).collect();
+    // remove the edges that occur twice
+    let edge_list: Vec<(usize, usize)> = path.windows(2).map(|w| (w[0], w[1])).collect();
+    let mut edge_set: HashSet<(usize, usize)> = HashSet::new();
+    for e in edge_list.iter() {
+        if !edge_set.remove(e) && !edge_set.remove(&(e.1, e.0)) {
+            edge_set.insert(*e);
+        }
+    }
+    let mut min_edge_list = vec![];
+    for e in edge_list {
+        if edge_set.remove(&e) {
+            min_edge_list.push(e);
+        } else if edge_set.remove(&(e.1, e.0)) {
+            min_edge_list.push((e.1, e.0));
+        }
+    }
+    min_edge_list
+}
+
+fn dijkstra(adjacency: &[Vec<(usize, f64)>], source: usize) -> (Vec<f64>, Vec<usize>) {
+    let mut distance = vec![f64::INFINITY; adjacency.len()];
+    let mut pred = vec![usize::MAX; adjacency.len()];
+    let mut done = vec![false; adjacency.len()];
+    let mut fringe = BinaryHeap::<FringeNode>::new();
+    distance[source] = 0.0;
+    push_fringe_node(&mut fringe, source, source, 0.0);
+    while let Some(fringe_node) = fringe.pop() {
+        let v = fringe_node.v;
+        if done[v] {
+            continue;
+        }
+        done[v] = true;
+        pred[v] = fringe_node.pred;
+        let dist = -fringe_node.distance;
+        for (w, weight) in adjacency[v].iter() {
+            let vw_dist = dist + weight;
+            if !done[*w] && vw_dist < distance[*w] {
+                distance[*w] = vw_dist;
+                push_fringe_node(&mut fringe, v, *w, vw_dist);
+            }
+        }
+    }
+    (distance, pred)
+}
+
+#[inline]
+fn ordered_pair(u: usize, v: usize) -> (usize, usize) {
+    match u > v {
+        true => (v, u),
+        false => (u, v),
+    }
+}
+
+fn indexes_to_names<T, A>(graph: &Graph<T, A>, indexes: &[usize]) -> Vec<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    indexes
+        .iter()
+        .map(|i| graph.get_node_by_index(i).unwrap().name.clone())
+        .collect()
+}
@@ -0,0 +1,5 @@
+mod cycle_basis;
+pub use cycle_basis::*;
+
+mod simple_cycles;
+pub use simple_cycles::*;
@@ -0,0 +1,463 @@
+use crate::algorithms::components::strongly_connected_components_by_index;
+use crate::{Error, Graph};
+use nohash::IntSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns an iterator over the simple cycles (elementary circuits) of a directed graph.
+
+A simple cycle is a closed path where no node appears twice. Each cycle is returned as
+a `Vec` of the nodes along the cycle; the first node is not repeated at the end. Self-loops
+are returned as single-node cycles and are yielded first.
+
+When `length_bound` is `None`, Johnson's algorithm is used. When a `length_bound` is given,
+only cycles with at most that many nodes are returned and the algorithm of Gupta and
+Suzumura is used, which avoids exploring paths that can't lead to short cycles.
+
+Cycles are generated lazily, so iteration can be stopped early on graphs that have an
+exponential number of cycles.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed
+* `length_bound`: if `Some`, only cycles with at most this many nodes are returned
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new(0, 0),
+    Edge::new(0, 1),
+    Edge::new(0, 2),
+    Edge::new(1, 2),
+    Edge::new(2, 0),
+    Edge::new(2, 1),
+    Edge::new(2, 2),
+];
+let specs = GraphSpecs {
+    self_loops: true,
+    ..GraphSpecs::directed_create_missing()
+};
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let cycles: Vec<Vec<i32>> = cycles::simple_cycles(&graph, None).unwrap().collect();
+assert_eq!(cycles.len(), 5);
+let short_cycles: Vec<Vec<i32>> = cycles::simple_cycles(&graph, Some(2)).unwrap().collect();
+assert_eq!(short_cycles.len(), 4);
+```
+
+# References
+
+1. Donald B. Johnson: Finding all the elementary circuits of a directed graph.
+   SIAM Journal on Computing 4(1):77-84, 1975.
+
+2. Anshul Gupta and Toyotaro Suzumura: Finding All Bounded-Length Simple Cycles in a
+   Directed Graph. <https://arxiv.org/abs/2105.10094>
+*/
+pub fn simple_cycles<T, A>(
+    graph: &Graph<T, A>,
+    length_bound: Option<usize>,
+) -> Result<SimpleCycles<'_, T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    Ok(SimpleCycles::new(graph, length_bound))
+}
+
+/**
+An iterator over the simple cycles of a directed graph. Created by
+[simple_cycles](./fn.simple_cycles.html).
+*/
+pub struct SimpleCycles<'a, T, A>
+where
+    T: PartialOrd + Send + Sync,
+    A: Clone,
+{
+    graph: &'a Graph<T, A>,
+    successors: Vec<Vec<usize>>,
+    length_bound: Option<usize>,
+    self_loops: Vec<usize>,
+    components: Vec<Vec<usize>>,
+    // state of the search for cycles through `start` within `component`
+    component: Vec<usize>,
+    start: Option<usize>,
+    active: Vec<bool>,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    positions: Vec<usize>,
+    // Johnson's algorithm
+    blocked: Vec<bool>,
+    closed: Vec<bool>,
+    // Gupta and Suzumura's algorithm
+    lock: Vec<usize>,
+    blen: Vec<usize>,
+    b: Vec<IntSet<usize>>,
+}
+
+impl<'a, T, A> SimpleCycles<'a, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    fn new(graph: &'a Graph<T, A>, length_bound: Option<usize>) -> SimpleCycles<'a, T, A> {
+        let num_nodes = graph.number_of_nodes();
+        let mut self_loops = vec![];
+        let successors: Vec<Vec<usize>> = (0..num_nodes)
+            .map(|v| {
+                graph
+                    .get_successor_nodes_by_index(&v)
+                    .iter()
+                    .map(|adj| adj.node_index)
+                    .filter(|w| {
+                        if *w == v {
+                            self_loops.push(v);
+                        }
+                        *w != v
+                    })
+                    .collect()
+            })
+            .collect();
+        if length_bound == Some(0) {
+            self_loops.clear();
+        }
+        self_loops.reverse();
+        let components = match length_bound {
+            Some(bound) if bound < 2 => vec![],
+            _ => strongly_connected_components_by_index(&successors, &vec![true; num_nodes])
+                .into_iter()
+                .filter(|c| c.len() > 1)
+                .collect(),
+        };
+        SimpleCycles {
+            graph,
+            successors,
+            length_bound,
+            self_loops,
+            components,
+            component: vec![],
+            start: None,
+            active: vec![false; num_nodes],
+            path: vec![],
+            on_path: vec![false; num_nodes],
+            positions: vec![],
+            blocked: vec![false; num_nodes],
+            closed: vec![],
+            lock: vec![0; num_nodes],
+            blen: vec![],
+            b: vec![IntSet::default(); num_nodes],
+        }
+    }
+
+    /// Prepares to search for all the cycles in `component` that pass through its
+    /// smallest node.
+    fn begin_component(&mut self, component: Vec<usize>) {
+        let bound = self.length_bound.unwrap_or(usize::MAX);
+        for v in component.iter() {
+            self.active[*v] = true;
+            self.blocked[*v] = false;
+            self.lock[*v] = bound;
+            self.b[*v].clear();
+        }
+        let start = *component.iter().min().unwrap();
+        self.blocked[start] = true;
+        self.lock[start] = 0;
+        self.on_path[start] = true;
+        self.path = vec![start];
+        self.positions = vec![0];
+        self.closed = vec![false];
+        self.blen = vec![bound];
+        self.start = Some(start);
+        self.component = component;
+    }
+
+    /// Removes `start` from the current component and queues the strongly connected
+    /// components of what remains.
+    fn finish_component(&mut self, start: usize) {
+        self.on_path[start] = false;
+        self.active[start] = false;
+        let components = strongly_connected_components_by_index(&self.successors, &self.active);
+        self.components
+            .extend(components.into_iter().filter(|c| c.len() > 1));
+        for v in self.component.iter() {
+            self.active[*v] = false;
+        }
+    }
+
+    fn path_names(&self) -> Vec<T> {
+        self.path
+            .iter()
+            .map(|v| self.graph.get_node_by_index(v).unwrap().name.clone())
+            .collect()
+    }
+
+    fn unblock(&mut self, v: usize) {
+        let mut stack = vec![v];
+        while let Some(u) = stack.pop() {
+            if self.blocked[u] {
+                self.blocked[u] = false;
+                stack.extend(self.b[u].drain());
+            }
+        }
+    }
+
+    /// Continues Johnson's search until a cycle is found or the search is exhausted.
+    fn johnson_step(&mut self, start: usize) -> Option<Vec<T>> {
+        while let Some(position) = self.positions.last_mut() {
+            let v = *self.path.last().unwrap();
+            if *position < self.successors[v].len() {
+                let w = self.successors[v][*position];
+                *position += 1;
+                if !self.active[w] {
+                    continue;
+                }
+                if w == start {
+                    *self.closed.last_mut().unwrap() = true;
+                    return Some(self.path_names());
+                } else if !self.blocked[w] {
+                    self.path.push(w);
+                    self.on_path[w] = true;
+                    self.closed.push(false);
+                    self.positions.push(0);
+                    self.blocked[w] = true;
+                }
+            } else {
+                self.positions.pop();
+                let v = self.path.pop().unwrap();
+                self.on_path[v] = false;
+                if self.closed.pop().unwrap() {
+                    if let Some(closed) = self.closed.last_mut() {
+                        *closed = true;
+                    }
+                    self.unblock(v);
+                } else {
+                    for w in self.successors[v].iter() {
+                        if self.active[*w] {
+                            self.b[*w].insert(v);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Continues Gupta and Suzumura's search until a cycle is found or the search is
+    /// exhausted.
+    fn bounded_step(&mut self, start: usize, bound: usize) -> Option<Vec<T>> {
+        while let Some(position) = self.positions.last_mut() {
+            let v = *self.path.last().unwrap();
+            if *position < self.successors[v].len() {
+                let w = self.successors[v][*position];
+                *position += 1;
+                if !self.active[w] {
+                    continue;
+                }
+                if w == start {
+                    *self.blen.last_mut().unwrap() = 1;
+                    return Some(self.path_names());
+                } else if self.path.len() < self.lock[w] {
+                    self.lock[w] = self.path.len();
+                    self.path.push(w);
+                    self.on_path[w] = true;
+                    self.blen.push(bound);
+                    self.positions.push(0);
+                }
+            } else {
+                self.positions.pop();
+                let v = self.path.pop().unwrap();
+                self.on_path[v] = false;
+                let bl = self.blen.pop().unwrap();
+                if let Some(last) = self.blen.last_mut() {
+                    *last = (*last).min(bl);
+                }
+                if bl < bound {
+                    let mut relax_stack = vec![(bl, v)];
+                    while let Some((bl, u)) = relax_stack.pop() {
+                        if self.lock[u] < bound - bl + 1 {
+                            self.lock[u] = bound - bl + 1;
+                            relax_stack.extend(
+                                self.b[u]
+                                    .iter()
+                                    .filter(|w| !self.on_path[**w])
+                                    .map(|w| (bl + 1, *w)),
+                            );
+                        }
+                    }
+                } else {
+                    for w in self.successors[v].iter() {
+                        if self.active[*w] {
+                            self.b[*w].insert(v);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T, A> Iterator for SimpleCycles<'_, T, A>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if let Some(v) = self.self_loops.pop() {
+            return Some(vec![self.graph.get_node_by_index(&v).unwrap().name.clone()]);
+        }
+        loop {
+            if self.positions.is_empty() {
+                if let Some(start) = self.start.take() {
+                    self.finish_component(start);
+                }
+                let component = self.components.pop()?;
+                self.begin_component(component);
+            }
+            let start = self.start.unwrap();
+            let cycle = match self.length_bound {
+                None => self.johnson_step(start),
+                Some(bound) => self.bounded_step(start, bound),
+            };
+            if cycle.is_some() {
+                return cycle;
+            }
+        }
+    }
+}
+
+/**
+Returns the first cycle found by a depth-first search, as a `Vec` of edges.
+
+For a directed graph the edges follow the direction of the edges in the graph. For an
+undirected graph the edges are oriented in the direction they were traversed. `None` is
+returned if the graph has no cycle.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `source`: the node to start the search from; if `None` the search is started from
+  every node in turn until a cycle is found
+
+# Examples
+
+```
+use graphrs::{algorithms::cycles, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::new(0, 1),
+    Edge::new(0, 2),
+    Edge::new(1, 2),
+];
+let graph: Graph<i32, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+assert!(cycles::find_cycle(&graph, None).unwrap().is_none());
+let graph = graph.to_undirected(None).unwrap();
+let cycle = cycles::find_cycle(&graph, None).unwrap().unwrap();
+assert_eq!(cycle.len(), 3);
+```
+*/
+pub fn find_cycle<T, A>(
+    graph: &Graph<T, A>,
+    source: Option<T>,
+) -> Result<Option<Vec<(T, T)>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    const WHITE: u8 = 0;
+    const GRAY: u8 = 1;
+    const BLACK: u8 = 2;
+    let num_nodes = graph.number_of_nodes();
+    let starts: Vec<usize> = match source {
+        Some(s) => vec![graph.get_node_index(&s)?],
+        None => (0..num_nodes).collect(),
+    };
+    let directed = graph.specs.directed;
+    let mut color = vec![WHITE; num_nodes];
+    let mut parent = vec![usize::MAX; num_nodes];
+    for s in starts {
+        if color[s] != WHITE {
+            continue;
+        }
+        color[s] = GRAY;
+        let mut stack = vec![(s, 0)];
+        while let Some((v, position)) = stack.last_mut() {
+            let v = *v;
+            let successors = graph.get_successor_nodes_by_index(&v);
+            if *position < successors.len() {
+                let w = successors[*position].node_index;
+                *position += 1;
+                if !directed && w == parent[v] {
+                    continue;
+                }
+                match color[w] {
+                    WHITE => {
+                        color[w] = GRAY;
+                        parent[w] = v;
+                        stack.push((w, 0));
+                    }
+                    GRAY => {
+                        let mut nodes = vec![v];
+                        let mut u = v;
+                        while u != w {
+                            u = parent[u];
+                            nodes.push(u);
+                        }
+                        nodes.reverse();
+                        nodes.push(w);
+                        let edges = nodes
+                            .windows(2)
+                            .map(|pair| {
+                                (
+                                    graph.get_node_by_index(&pair[0]).unwrap().name.clone(),
+                                    graph.get_node_by_index(&pair[1]).unwrap().name.clone(),
+                                )
+                            })
+                            .collect();
+                        return Ok(Some(edges));
+                    }
+                    _ => {}
+                }
+            } else {
+                color[v] = BLACK;
+                stack.pop();
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{Edge, GraphSpecs};
+
+    #[test]
+    fn test_bounded_step_1() {
+        // the lock of a node on the path is its distance from the start, so that the bounded
+        // search only goes through it again along a shorter path
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(1, 3),
+            Edge::new(2, 3),
+            Edge::new(3, 0),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let mut cycles = SimpleCycles::new(&graph, Some(4));
+        assert_eq!(cycles.next(), Some(vec![0, 1, 2, 3]));
+        assert_eq!(cycles.path, vec![0, 1, 2, 3]);
+        for (distance, v) in cycles.path.iter().enumerate() {
+            assert_eq!(cycles.lock[*v], distance);
+        }
+        assert_eq!(cycles.next(), Some(vec![0, 1, 3]));
+        assert_eq!(cycles.next(), None);
+    }
+}
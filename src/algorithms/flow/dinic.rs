@@ -0,0 +1,89 @@
+use super::residual::ResidualNetwork;
+use std::collections::VecDeque;
+
+/**
+Computes a maximum flow with Dinic's algorithm: builds a level graph with a breadth-first
+search from the source and then saturates it with a blocking flow, until the target can no
+longer be reached. Runs in O(n²m) time.
+*/
+pub(crate) fn dinic(residual: &mut ResidualNetwork, source: usize, target: usize) {
+    let num_nodes = residual.num_nodes;
+    let mut level = vec![usize::MAX; num_nodes];
+    let mut next_arc = vec![0; num_nodes];
+    loop {
+        level.iter_mut().for_each(|l| *l = usize::MAX);
+        level[source] = 0;
+        let mut queue = VecDeque::from(vec![source]);
+        while let Some(u) = queue.pop_front() {
+            for e in residual.adjacency[u].iter() {
+                let v = residual.head[*e];
+                if level[v] == usize::MAX && residual.has_residual(*e) {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        if level[target] == usize::MAX {
+            return;
+        }
+        next_arc.iter_mut().for_each(|i| *i = 0);
+        blocking_flow(residual, source, target, &mut level, &mut next_arc);
+    }
+}
+
+/// Finds a blocking flow in the level graph with an iterative depth-first search.
+fn blocking_flow(
+    residual: &mut ResidualNetwork,
+    source: usize,
+    target: usize,
+    level: &mut [usize],
+    next_arc: &mut [usize],
+) {
+    let mut path: Vec<usize> = vec![];
+    let mut u = source;
+    loop {
+        if u == target {
+            let bottleneck = path
+                .iter()
+                .map(|e| residual.residual(*e))
+                .fold(f64::INFINITY, f64::min);
+            for e in path.iter() {
+                residual.push(*e, bottleneck);
+            }
+            // retreat to the tail of the first saturated arc
+            let saturated = path
+                .iter()
+                .position(|e| !residual.has_residual(*e))
+                .unwrap_or(0);
+            path.truncate(saturated);
+            u = match path.last() {
+                Some(e) => residual.head[*e],
+                None => source,
+            };
+            continue;
+        }
+        let mut advanced = false;
+        while next_arc[u] < residual.adjacency[u].len() {
+            let e = residual.adjacency[u][next_arc[u]];
+            let v = residual.head[e];
+            if level[v] == level[u] + 1 && residual.has_residual(e) {
+                path.push(e);
+                u = v;
+                advanced = true;
+                break;
+            }
+            next_arc[u] += 1;
+        }
+        if advanced {
+            continue;
+        }
+        if u == source {
+            return;
+        }
+        // a dead end: remove it from the level graph and retreat
+        level[u] = usize::MAX;
+        let e = path.pop().unwrap();
+        u = residual.tail(e);
+        next_arc[u] += 1;
+    }
+}
@@ -0,0 +1,42 @@
+use super::residual::ResidualNetwork;
+use std::collections::VecDeque;
+
+/**
+Computes a maximum flow with the Edmonds–Karp algorithm: repeatedly augments the flow along
+a shortest (fewest arcs) path with residual capacity. Runs in O(nm²) time.
+*/
+pub(crate) fn edmonds_karp(residual: &mut ResidualNetwork, source: usize, target: usize) {
+    let mut pred_arc = vec![usize::MAX; residual.num_nodes];
+    loop {
+        pred_arc.iter_mut().for_each(|p| *p = usize::MAX);
+        let mut queue = VecDeque::from(vec![source]);
+        'bfs: while let Some(u) = queue.pop_front() {
+            for e in residual.adjacency[u].iter() {
+                let v = residual.head[*e];
+                if v != source && pred_arc[v] == usize::MAX && residual.has_residual(*e) {
+                    pred_arc[v] = *e;
+                    if v == target {
+                        break 'bfs;
+                    }
+                    queue.push_back(v);
+                }
+            }
+        }
+        if pred_arc[target] == usize::MAX {
+            return;
+        }
+        let mut bottleneck = f64::INFINITY;
+        let mut v = target;
+        while v != source {
+            let e = pred_arc[v];
+            bottleneck = bottleneck.min(residual.residual(e));
+            v = residual.tail(e);
+        }
+        let mut v = target;
+        while v != source {
+            let e = pred_arc[v];
+            residual.push(e, bottleneck);
+            v = residual.tail(e);
+        }
+    }
+}
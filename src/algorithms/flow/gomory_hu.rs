@@ -0,0 +1,288 @@
+use super::maximum_flow::{run_algorithm, FlowAlgorithm};
+use super::residual::{build_residual_network, ResidualNetwork};
+use crate::{Edge, EdgeDedupeStrategy, Error, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+A Gomory-Hu tree of an undirected graph.
+
+The tree has the same nodes as the graph. For any pair of nodes `u` and `v`, the value of the
+minimum `u`-`v` cut in the graph equals the smallest weight on the path between `u` and `v` in
+the tree, and removing that tree edge splits the nodes into the two sides of a minimum cut.
+*/
+#[derive(Clone, Debug)]
+pub struct GomoryHuTree<T> {
+    node_names: Vec<T>,
+    node_indexes: HashMap<T, usize>,
+    /// The parent of each node in the tree, which is rooted at the first node of the graph.
+    parents: Vec<Option<usize>>,
+    /// The weight of the edge between each node and its parent.
+    weights: Vec<f64>,
+    depths: Vec<usize>,
+}
+
+impl<T> GomoryHuTree<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Returns the value of the minimum cut between `u` and `v`. Runs in time proportional to
+    the length of the path between `u` and `v` in the tree.
+
+    # Arguments
+
+    * `u`: a node in the tree
+    * `v`: another node in the tree
+    */
+    pub fn min_cut(&self, u: &T, v: &T) -> Result<f64, Error> {
+        let (_, cut_value) = self.min_cut_edge(u, v)?;
+        Ok(cut_value)
+    }
+
+    /**
+    Returns a minimum cut between `u` and `v` as the sets of nodes on the `u` side and on the
+    `v` side of the cut.
+
+    # Arguments
+
+    * `u`: a node in the tree
+    * `v`: another node in the tree
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn min_cut_partition(&self, u: &T, v: &T) -> Result<(HashSet<T>, HashSet<T>), Error> {
+        let (child, _) = self.min_cut_edge(u, v)?;
+        // the nodes in the subtree rooted at `child` are on one side of the cut
+        let mut in_subtree: Vec<Option<bool>> = vec![None; self.node_names.len()];
+        in_subtree[child] = Some(true);
+        for i in 0..self.node_names.len() {
+            self.is_in_subtree(i, &mut in_subtree);
+        }
+        let mut subtree = HashSet::new();
+        let mut rest = HashSet::new();
+        for (i, s) in in_subtree.into_iter().enumerate() {
+            match s {
+                Some(true) => subtree.insert(self.node_names[i].clone()),
+                _ => rest.insert(self.node_names[i].clone()),
+            };
+        }
+        match subtree.contains(u) {
+            true => Ok((subtree, rest)),
+            false => Ok((rest, subtree)),
+        }
+    }
+
+    /**
+    Returns the tree as an undirected [Graph](../../struct.Graph.html) where the weight of
+    each edge is the value of the minimum cut between its nodes.
+    */
+    pub fn to_graph(&self) -> Graph<T, ()> {
+        let edges = self
+            .parents
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                p.map(|p| {
+                    Edge::with_weight(
+                        self.node_names[i].clone(),
+                        self.node_names[p].clone(),
+                        self.weights[i],
+                    )
+                })
+            })
+            .collect();
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Error,
+            edge_dedupe_strategy: EdgeDedupeStrategy::Error,
+            ..GraphSpecs::undirected()
+        };
+        let nodes = self
+            .node_names
+            .iter()
+            .map(|name| crate::Node::from_name(name.clone()))
+            .collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+    }
+
+    /**
+    Finds the lightest edge on the tree path between `u` and `v`. Returns the child node of
+    that edge and its weight.
+    */
+    fn min_cut_edge(&self, u: &T, v: &T) -> Result<(usize, f64), Error> {
+        let mut a = self.get_index(u)?;
+        let mut b = self.get_index(v)?;
+        if a == b {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: "The two nodes are the same.".to_string(),
+            });
+        }
+        let mut best = (a, f64::INFINITY);
+        while a != b {
+            let x = match self.depths[a] >= self.depths[b] {
+                true => &mut a,
+                false => &mut b,
+            };
+            if self.weights[*x] < best.1 {
+                best = (*x, self.weights[*x]);
+            }
+            *x = self.parents[*x].unwrap();
+        }
+        Ok(best)
+    }
+
+    fn is_in_subtree(&self, node: usize, in_subtree: &mut [Option<bool>]) -> bool {
+        // walk up until a node with a known answer is found, then fill in the path
+        let mut path = vec![];
+        let mut current = node;
+        let answer = loop {
+            if let Some(answer) = in_subtree[current] {
+                break answer;
+            }
+            path.push(current);
+            match self.parents[current] {
+                Some(p) => current = p,
+                None => break false,
+            }
+        };
+        for i in path {
+            in_subtree[i] = Some(answer);
+        }
+        answer
+    }
+
+    fn get_index(&self, node: &T) -> Result<usize, Error> {
+        self.node_indexes.get(node).cloned().ok_or_else(|| Error {
+            kind: ErrorKind::NodeNotFound,
+            message: format!("Node '{}' not found in the graph.", node),
+        })
+    }
+}
+
+/**
+Builds a Gomory-Hu tree of an undirected graph using Gusfield's algorithm, which needs
+n - 1 maximum flow computations.
+
+The [GomoryHuTree](./struct.GomoryHuTree.html) answers minimum cut queries between any pair
+of nodes without computing any more flows.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be undirected, must not have
+  multiple edges between two nodes and must have at least one node
+* `capacity`: a function that returns the capacity of an edge; if `None` the edge weight is
+  used. A capacity of `f64::NAN`, such as the weight of an unweighted edge, is infinite.
+* `algorithm`: the [FlowAlgorithm](./enum.FlowAlgorithm.html) used to find the maximum flows
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, generators};
+let graph = generators::social::karate_club_graph();
+let tree = flow::gomory_hu_tree(&graph, None, flow::FlowAlgorithm::Dinic).unwrap();
+assert_eq!(tree.min_cut(&0, &33).unwrap(), 22.0);
+let minimum_cut = flow::minimum_cut(&graph, 5, 25, None, flow::FlowAlgorithm::Dinic).unwrap();
+assert_eq!(tree.min_cut(&5, &25).unwrap(), minimum_cut.cut_value);
+```
+
+# References
+
+1. R. E. Gomory and T. C. Hu: Multi-terminal network flows. Journal of the Society for
+   Industrial and Applied Mathematics 9(4):551–570, 1961.
+2. D. Gusfield: Very simple methods for all pairs network flow analysis. SIAM Journal on
+   Computing 19(1):143–155, 1990.
+*/
+#[allow(clippy::type_complexity)]
+pub fn gomory_hu_tree<T, A>(
+    graph: &Graph<T, A>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    algorithm: FlowAlgorithm,
+) -> Result<GomoryHuTree<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has no nodes.".to_string(),
+        });
+    }
+    let (residual, _) = build_residual_network(graph, capacity)?;
+
+    let (parents, weights) = gusfield(&residual, algorithm)?;
+
+    let mut depths = vec![usize::MAX; n];
+    depths[0] = 0;
+    for i in 0..n {
+        let mut path = vec![];
+        let mut current = i;
+        while depths[current] == usize::MAX {
+            path.push(current);
+            current = parents[current].unwrap();
+        }
+        let mut depth = depths[current];
+        for node in path.into_iter().rev() {
+            depth += 1;
+            depths[node] = depth;
+        }
+    }
+
+    let node_names: Vec<T> = (0..n)
+        .map(|i| graph.get_node_by_index(&i).unwrap().name.clone())
+        .collect();
+    let node_indexes = node_names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.clone(), i))
+        .collect();
+    Ok(GomoryHuTree {
+        node_names,
+        node_indexes,
+        parents,
+        weights,
+        depths,
+    })
+}
+
+/**
+Runs Gusfield's algorithm on a residual network with symmetric capacities. Returns the parent of
+each node in the tree, which is rooted at node 0, and the weight of the edge between each node
+and its parent.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn gusfield(
+    residual: &ResidualNetwork,
+    algorithm: FlowAlgorithm,
+) -> Result<(Vec<Option<usize>>, Vec<f64>), Error> {
+    // every node starts attached to the root, node 0
+    let n = residual.num_nodes;
+    let mut parents: Vec<Option<usize>> = (0..n).map(|i| (i > 0).then_some(0)).collect();
+    let mut weights = vec![f64::INFINITY; n];
+    for source in 1..n {
+        let target = parents[source].unwrap();
+        let mut network = residual.clone();
+        network.replace_infinite_capacities(source, target)?;
+        run_algorithm(&mut network, source, target, algorithm);
+        let cut_value = network.net_inflow(target);
+        let source_side = network.reachable_from(source);
+        weights[source] = cut_value;
+        for node in 0..n {
+            if node != source && source_side[node] && parents[node] == Some(target) {
+                parents[node] = Some(source);
+            }
+        }
+        if let Some(grandparent) = parents[target] {
+            if source_side[grandparent] {
+                parents[source] = Some(grandparent);
+                parents[target] = Some(source);
+                weights[source] = weights[target];
+                weights[target] = cut_value;
+            }
+        }
+    }
+    Ok((parents, weights))
+}
@@ -0,0 +1,243 @@
+use super::dinic::dinic;
+use super::edmonds_karp::edmonds_karp;
+use super::preflow_push::preflow_push;
+use super::residual::{build_residual_network, ResidualNetwork};
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The algorithm to use to compute a maximum flow.
+
+`EdmondsKarp`: augments along shortest paths; O(nm²). Good for sparse graphs.
+
+`Dinic`: augments along blocking flows in level graphs; O(n²m). A good default.
+
+`PreflowPush`: the FIFO push-relabel algorithm of Goldberg and Tarjan; O(n³). Good for
+dense graphs.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlowAlgorithm {
+    EdmondsKarp,
+    Dinic,
+    PreflowPush,
+}
+
+/**
+The result of a maximum flow computation.
+*/
+#[derive(Clone, Debug)]
+pub struct MaximumFlow<T> {
+    /// The value of the maximum flow.
+    pub flow_value: f64,
+    /// The flow on each edge, keyed by `(u, v)`. For an undirected graph both `(u, v)` and
+    /// `(v, u)` are present; at most one of them has a non-zero flow.
+    pub flows: HashMap<(T, T), f64>,
+}
+
+/**
+The result of a minimum cut computation.
+*/
+#[derive(Clone, Debug)]
+pub struct MinimumCut<T> {
+    /// The capacity of the cut, which equals the value of the maximum flow.
+    pub cut_value: f64,
+    /// The nodes on the source side and the nodes on the target side of the cut.
+    pub partition: (HashSet<T>, HashSet<T>),
+    /// The edges that cross the cut, as `(u, v)` where `u` is on the source side.
+    pub cut_edges: Vec<(T, T)>,
+}
+
+/**
+Finds a maximum single-commodity flow from `source` to `target`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must not have multiple edges
+  between two nodes; for an undirected graph each edge can carry flow in either direction
+* `source`: the node the flow starts at
+* `target`: the node the flow ends at
+* `capacity`: a function that returns the capacity of an edge; if `None` the edge weight is
+  used. A capacity of `f64::NAN`, such as the weight of an unweighted edge, is infinite.
+* `algorithm`: the [FlowAlgorithm](./enum.FlowAlgorithm.html) to use
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("x", "a", 3.0),
+    Edge::with_weight("x", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("b", "c", 5.0),
+    Edge::with_weight("b", "d", 4.0),
+    Edge::with_weight("d", "e", 2.0),
+    Edge::with_weight("c", "y", 2.0),
+    Edge::with_weight("e", "y", 3.0),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+let result = flow::maximum_flow(&graph, "x", "y", None, flow::FlowAlgorithm::Dinic).unwrap();
+assert_eq!(result.flow_value, 3.0);
+assert_eq!(result.flows[&("x", "a")], 2.0);
+```
+
+# References
+
+1. J. Edmonds and R. M. Karp: Theoretical improvements in algorithmic efficiency for network
+   flow problems. Journal of the ACM 19(2):248–264, 1972.
+2. E. A. Dinic: Algorithm for solution of a problem of maximum flow in a network with power
+   estimation. Soviet Math. Doklady 11:1277–1280, 1970.
+3. A. V. Goldberg and R. E. Tarjan: A new approach to the maximum-flow problem. Journal of
+   the ACM 35(4):921–940, 1988.
+*/
+#[allow(clippy::type_complexity)]
+pub fn maximum_flow<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    algorithm: FlowAlgorithm,
+) -> Result<MaximumFlow<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (residual, arcs, _, t) = run_maximum_flow(graph, source, target, capacity, algorithm)?;
+    let flows = arcs
+        .into_iter()
+        .map(|(u, v, e)| {
+            let key = (get_name(graph, u), get_name(graph, v));
+            (key, residual.flow[e].max(0.0))
+        })
+        .collect();
+    Ok(MaximumFlow {
+        flow_value: residual.net_inflow(t),
+        flows,
+    })
+}
+
+/**
+Finds a minimum (weight) cut that separates `source` and `target`. By the max-flow min-cut
+theorem the capacity of the cut equals the value of the maximum flow.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must not have multiple edges
+  between two nodes
+* `source`: the node on the source side of the cut
+* `target`: the node on the target side of the cut
+* `capacity`: a function that returns the capacity of an edge; if `None` the edge weight is
+  used. A capacity of `f64::NAN`, such as the weight of an unweighted edge, is infinite.
+* `algorithm`: the [FlowAlgorithm](./enum.FlowAlgorithm.html) used to find the maximum flow
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("x", "a", 3.0),
+    Edge::with_weight("x", "b", 1.0),
+    Edge::with_weight("a", "c", 3.0),
+    Edge::with_weight("b", "c", 5.0),
+    Edge::with_weight("b", "d", 4.0),
+    Edge::with_weight("d", "e", 2.0),
+    Edge::with_weight("c", "y", 2.0),
+    Edge::with_weight("e", "y", 3.0),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+let cut = flow::minimum_cut(&graph, "x", "y", None, flow::FlowAlgorithm::PreflowPush).unwrap();
+assert_eq!(cut.cut_value, 3.0);
+assert_eq!(cut.cut_edges, vec![("c", "y"), ("x", "b")]);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn minimum_cut<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    algorithm: FlowAlgorithm,
+) -> Result<MinimumCut<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let (residual, arcs, s, t) = run_maximum_flow(graph, source, target, capacity, algorithm)?;
+    let reachable = residual.reachable_from(s);
+    let mut cut_edges: Vec<(T, T)> = arcs
+        .into_iter()
+        .filter(|(u, v, _)| reachable[*u] && !reachable[*v])
+        .map(|(u, v, _)| (get_name(graph, u), get_name(graph, v)))
+        .collect();
+    cut_edges.sort();
+    let mut source_side = HashSet::new();
+    let mut target_side = HashSet::new();
+    for (i, r) in reachable.into_iter().enumerate() {
+        match r {
+            true => source_side.insert(get_name(graph, i)),
+            false => target_side.insert(get_name(graph, i)),
+        };
+    }
+    Ok(MinimumCut {
+        cut_value: residual.net_inflow(t),
+        partition: (source_side, target_side),
+        cut_edges,
+    })
+}
+
+/**
+Builds the residual network of `graph` and runs `algorithm` on it. Returns the residual
+network, the arcs of the graph's edges, and the indexes of `source` and `target`.
+*/
+#[allow(clippy::type_complexity)]
+fn run_maximum_flow<T, A>(
+    graph: &Graph<T, A>,
+    source: T,
+    target: T,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    algorithm: FlowAlgorithm,
+) -> Result<(ResidualNetwork, Vec<(usize, usize, usize)>, usize, usize), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let s = graph.get_node_index(&source)?;
+    let t = graph.get_node_index(&target)?;
+    if s == t {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The source and target nodes are the same.".to_string(),
+        });
+    }
+    let (mut residual, arcs) = build_residual_network(graph, capacity)?;
+    residual.replace_infinite_capacities(s, t)?;
+    run_algorithm(&mut residual, s, t, algorithm);
+    Ok((residual, arcs, s, t))
+}
+
+pub(crate) fn run_algorithm(
+    residual: &mut ResidualNetwork,
+    source: usize,
+    target: usize,
+    algorithm: FlowAlgorithm,
+) {
+    match algorithm {
+        FlowAlgorithm::EdmondsKarp => edmonds_karp(residual, source, target),
+        FlowAlgorithm::Dinic => dinic(residual, source, target),
+        FlowAlgorithm::PreflowPush => preflow_push(residual, source, target),
+    }
+}
+
+#[inline]
+fn get_name<T, A>(graph: &Graph<T, A>, index: usize) -> T
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.get_node_by_index(&index).unwrap().name.clone()
+}
@@ -0,0 +1,404 @@
+use super::dinic::dinic;
+use super::residual::{get_capacity, ResidualNetwork};
+use crate::algorithms::centrality::fringe_node::{push_fringe_node, FringeNode};
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+The result of a minimum cost flow computation.
+*/
+#[derive(Clone, Debug)]
+pub struct MinCostFlow<T> {
+    /// The total cost of the flow: the sum over all edges of the flow times the edge cost.
+    pub cost: f64,
+    /// The flow on each edge, keyed by `(u, v)`.
+    pub flows: HashMap<(T, T), f64>,
+}
+
+/**
+Finds a minimum cost flow that satisfies all the node demands, using the successive shortest
+path algorithm.
+
+A node with a negative demand is a supply node: flow leaves it. A node with a positive demand
+consumes flow. The demands must sum to zero.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed and must not
+  have multiple edges between two nodes
+* `demand`: the demand of each node; nodes that are not present have a demand of zero
+* `capacity`: a function that returns the capacity of an edge; if `None`, or if the function
+  returns `f64::NAN`, the capacity is infinite
+* `cost`: a function that returns the cost per unit of flow of an edge; if `None` the edge
+  weight is used, and an edge without a weight has a cost of zero
+
+# Errors
+
+An error of kind `Infeasible` is returned if no flow satisfies all the node demands, and an
+error of kind `Unbounded` if there's a cycle of negative cost and infinite capacity.
+Feasibility is checked first, so as in NetworkX an instance that is both infeasible and
+unbounded gives an `Infeasible` error.
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+let edges = vec![
+    Edge::with_weight("a", "b", 3.0),
+    Edge::with_weight("a", "c", 6.0),
+    Edge::with_weight("b", "d", 1.0),
+    Edge::with_weight("c", "d", 2.0),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+let demand: HashMap<&str, f64> = vec![("a", -5.0), ("d", 5.0)].into_iter().collect();
+let capacity = |edge: &Edge<&str, ()>| match (edge.u, edge.v) {
+    ("a", "c") => 10.0,
+    ("c", "d") => 5.0,
+    _ => 4.0,
+};
+let result = flow::min_cost_flow(&graph, &demand, Some(&capacity), None).unwrap();
+assert_eq!(result.cost, 24.0);
+assert_eq!(result.flows[&("a", "b")], 4.0);
+assert_eq!(result.flows[&("a", "c")], 1.0);
+```
+*/
+#[allow(clippy::type_complexity)]
+pub fn min_cost_flow<T, A>(
+    graph: &Graph<T, A>,
+    demand: &HashMap<T, f64>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    cost: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+) -> Result<MinCostFlow<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let problem = get_cost_flow_problem(graph, demand, capacity, cost)?;
+    let active_edges = problem.active_edges();
+    problem.check_feasible(&active_edges)?;
+    problem.check_infinite_negative_cycles(&active_edges)?;
+    let flows = successive_shortest_paths(&problem, &active_edges)?;
+    Ok(problem.to_result(graph, flows))
+}
+
+/**
+An instance of the minimum cost flow problem over node indexes.
+*/
+pub(crate) struct CostFlowProblem {
+    pub num_nodes: usize,
+    pub demands: Vec<f64>,
+    /// `(u, v, capacity, cost)` for each edge of the graph, in the order of `get_all_edges`.
+    pub edges: Vec<(usize, usize, f64, f64)>,
+}
+
+impl CostFlowProblem {
+    /// Returns the indexes of the edges the algorithms need to consider: self-loops and edges
+    /// with zero capacity never carry flow in an optimal solution (self-loops with a
+    /// negative cost are handled separately).
+    pub fn active_edges(&self) -> Vec<usize> {
+        (0..self.edges.len())
+            .filter(|i| self.edges[*i].0 != self.edges[*i].1 && self.edges[*i].2 != 0.0)
+            .collect()
+    }
+
+    /// Sets the flow on the self-loops: saturated if their cost is negative.
+    fn self_loop_flows(&self, flows: &mut [f64]) {
+        for (i, (u, v, capacity, cost)) in self.edges.iter().enumerate() {
+            if u == v && *cost < 0.0 {
+                flows[i] = *capacity;
+            }
+        }
+    }
+
+    /// Returns an `Infeasible` error if no flow satisfies all the node demands, whatever the
+    /// edge costs: that's when the maximum flow from a super source, joined to the supply
+    /// nodes, to a super sink, joined to the demand nodes, doesn't meet the total demand.
+    pub fn check_feasible(&self, active_edges: &[usize]) -> Result<(), Error> {
+        let n = self.num_nodes;
+        let total_demand: f64 = self.demands.iter().filter(|d| **d > 0.0).sum();
+        let bound = 1.0
+            + self.demands.iter().map(|d| d.abs()).sum::<f64>()
+            + active_edges
+                .iter()
+                .map(|i| self.edges[*i].2)
+                .filter(|c| c.is_finite())
+                .sum::<f64>();
+        let mut residual = ResidualNetwork::new(n + 2);
+        for i in active_edges.iter() {
+            let (u, v, c, _) = self.edges[*i];
+            residual.add_arc(u, v, c.min(bound), 0.0);
+        }
+        for (v, d) in self.demands.iter().enumerate() {
+            if *d < 0.0 {
+                residual.add_arc(n, v, -d, 0.0);
+            } else if *d > 0.0 {
+                residual.add_arc(v, n + 1, *d, 0.0);
+            }
+        }
+        residual.tolerance = bound * 1e-12;
+        dinic(&mut residual, n, n + 1);
+        match residual.net_inflow(n + 1) < total_demand - residual.tolerance {
+            true => Err(infeasible_error()),
+            false => Ok(()),
+        }
+    }
+
+    /**
+    Returns an `Unbounded` error if there's a self-loop with a negative cost and infinite
+    capacity, or if the edges with infinite capacity contain a cycle with a negative cost,
+    found with the Bellman-Ford algorithm.
+    */
+    pub fn check_infinite_negative_cycles(&self, active_edges: &[usize]) -> Result<(), Error> {
+        if self
+            .edges
+            .iter()
+            .any(|(u, v, c, w)| u == v && *w < 0.0 && *c == f64::INFINITY)
+        {
+            return Err(unbounded_error());
+        }
+        let edges: Vec<&(usize, usize, f64, f64)> = active_edges
+            .iter()
+            .map(|i| &self.edges[*i])
+            .filter(|(_, _, c, _)| *c == f64::INFINITY)
+            .collect();
+        let mut distance = vec![0.0; self.num_nodes];
+        for _ in 0..self.num_nodes {
+            let mut changed = false;
+            for (u, v, _, w) in edges.iter() {
+                if distance[*u] + w < distance[*v] {
+                    distance[*v] = distance[*u] + w;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return Ok(());
+            }
+        }
+        Err(unbounded_error())
+    }
+
+    pub fn to_result<T, A>(&self, graph: &Graph<T, A>, mut flows: Vec<f64>) -> MinCostFlow<T>
+    where
+        T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+        A: Clone + Send + Sync,
+    {
+        self.self_loop_flows(&mut flows);
+        let cost = self
+            .edges
+            .iter()
+            .zip(flows.iter())
+            .map(|((_, _, _, c), f)| c * f)
+            .sum::<f64>();
+        // a sum of negative costs times zero flows is `-0.0`
+        let cost = match cost == 0.0 {
+            true => 0.0,
+            false => cost,
+        };
+        let flows = self
+            .edges
+            .iter()
+            .zip(flows)
+            .map(|((u, v, _, _), f)| {
+                let u = graph.get_node_by_index(u).unwrap().name.clone();
+                let v = graph.get_node_by_index(v).unwrap().name.clone();
+                ((u, v), f)
+            })
+            .collect();
+        MinCostFlow { cost, flows }
+    }
+}
+
+/**
+Validates the inputs to a minimum cost flow problem and converts them to node indexes.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn get_cost_flow_problem<T, A>(
+    graph: &Graph<T, A>,
+    demand: &HashMap<T, f64>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    cost: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+) -> Result<CostFlowProblem, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    graph.ensure_not_multi_edges()?;
+    let num_nodes = graph.number_of_nodes();
+    if num_nodes == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "The graph has no nodes.".to_string(),
+        });
+    }
+    let mut demands = vec![0.0; num_nodes];
+    for (node, d) in demand.iter() {
+        if !d.is_finite() {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("Node '{}' has a demand that is not finite.", node),
+            });
+        }
+        demands[graph.get_node_index(node)?] = *d;
+    }
+    let mut edges = vec![];
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u)?;
+        let v = graph.get_node_index(&edge.v)?;
+        let c = match capacity {
+            Some(_) => get_capacity(edge, capacity)?,
+            None => f64::INFINITY,
+        };
+        let w = match cost {
+            Some(f) => f(edge),
+            None => edge.weight,
+        };
+        let w = match w.is_nan() {
+            true => 0.0,
+            false => w,
+        };
+        if w.is_infinite() {
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message: format!("Edge ({}, {}) has an infinite cost.", edge.u, edge.v),
+            });
+        }
+        edges.push((u, v, c, w));
+    }
+    let total_demand: f64 = demands.iter().sum();
+    let scale: f64 = demands.iter().map(|d| d.abs()).sum();
+    if total_demand.abs() > scale * 1e-12 {
+        return Err(Error {
+            kind: ErrorKind::Infeasible,
+            message: "The total node demand is not zero.".to_string(),
+        });
+    }
+    Ok(CostFlowProblem {
+        num_nodes,
+        demands,
+        edges,
+    })
+}
+
+/**
+Solves a minimum cost flow problem with the successive shortest path algorithm. Node
+potentials keep the reduced costs non-negative so that Dijkstra's algorithm can be used to
+find each augmenting path.
+*/
+fn successive_shortest_paths(
+    problem: &CostFlowProblem,
+    active_edges: &[usize],
+) -> Result<Vec<f64>, Error> {
+    let num_nodes = problem.num_nodes;
+    // an optimal flow never exceeds the total supply plus the total finite capacity on any edge
+    let bound = 1.0
+        + problem.demands.iter().map(|d| d.abs()).sum::<f64>()
+        + active_edges
+            .iter()
+            .map(|i| problem.edges[*i].2)
+            .filter(|c| c.is_finite())
+            .sum::<f64>();
+    let mut residual = ResidualNetwork::new(num_nodes);
+    let mut cost = vec![];
+    let mut arcs = vec![];
+    for i in active_edges.iter() {
+        let (u, v, c, w) = problem.edges[*i];
+        arcs.push(residual.add_arc(u, v, c.min(bound), 0.0));
+        cost.push(w);
+        cost.push(-w);
+    }
+    residual.tolerance = bound * 1e-12;
+    let mut excess: Vec<f64> = problem.demands.iter().map(|d| -d).collect();
+    // saturate the negative cost edges so that all residual arcs have a non-negative cost
+    for e in arcs.iter() {
+        if cost[*e] < 0.0 {
+            let amount = residual.residual(*e);
+            residual.push(*e, amount);
+            excess[residual.tail(*e)] -= amount;
+            excess[residual.head[*e]] += amount;
+        }
+    }
+    let mut potential = vec![0.0; num_nodes];
+    let mut distance = vec![f64::INFINITY; num_nodes];
+    let mut pred_arc = vec![usize::MAX; num_nodes];
+    let mut done = vec![false; num_nodes];
+    while let Some(s) = (0..num_nodes).find(|v| excess[*v] > residual.tolerance) {
+        distance.iter_mut().for_each(|d| *d = f64::INFINITY);
+        done.iter_mut().for_each(|d| *d = false);
+        let mut fringe = BinaryHeap::<FringeNode>::new();
+        distance[s] = 0.0;
+        push_fringe_node(&mut fringe, s, s, 0.0);
+        let mut target = None;
+        while let Some(fringe_node) = fringe.pop() {
+            let u = fringe_node.v;
+            if done[u] {
+                continue;
+            }
+            done[u] = true;
+            if excess[u] < -residual.tolerance {
+                target = Some(u);
+                break;
+            }
+            for e in residual.adjacency[u].iter() {
+                let v = residual.head[*e];
+                if done[v] || !residual.has_residual(*e) {
+                    continue;
+                }
+                let reduced_cost = (cost[*e] + potential[u] - potential[v]).max(0.0);
+                let d = distance[u] + reduced_cost;
+                if d < distance[v] {
+                    distance[v] = d;
+                    pred_arc[v] = *e;
+                    push_fringe_node(&mut fringe, u, v, d);
+                }
+            }
+        }
+        let t = match target {
+            Some(t) => t,
+            None => return Err(infeasible_error()),
+        };
+        for v in 0..num_nodes {
+            potential[v] += distance[v].min(distance[t]);
+        }
+        let mut amount = excess[s].min(-excess[t]);
+        let mut v = t;
+        while v != s {
+            amount = amount.min(residual.residual(pred_arc[v]));
+            v = residual.tail(pred_arc[v]);
+        }
+        let mut v = t;
+        while v != s {
+            residual.push(pred_arc[v], amount);
+            v = residual.tail(pred_arc[v]);
+        }
+        excess[s] -= amount;
+        excess[t] += amount;
+    }
+    if excess.iter().any(|e| *e < -residual.tolerance) {
+        return Err(infeasible_error());
+    }
+    let mut flows = vec![0.0; problem.edges.len()];
+    for (i, e) in active_edges.iter().zip(arcs) {
+        flows[*i] = residual.flow[e];
+    }
+    Ok(flows)
+}
+
+pub(crate) fn infeasible_error() -> Error {
+    Error {
+        kind: ErrorKind::Infeasible,
+        message: "No flow satisfies all the node demands.".to_string(),
+    }
+}
+
+pub(crate) fn unbounded_error() -> Error {
+    Error {
+        kind: ErrorKind::Unbounded,
+        message: "A cycle with a negative cost and infinite capacity was found.".to_string(),
+    }
+}
@@ -0,0 +1,12 @@
+mod dinic;
+mod edmonds_karp;
+mod gomory_hu;
+pub use gomory_hu::*;
+mod maximum_flow;
+pub use maximum_flow::*;
+mod min_cost_flow;
+pub use min_cost_flow::*;
+mod network_simplex;
+pub use network_simplex::*;
+mod preflow_push;
+pub(crate) mod residual;
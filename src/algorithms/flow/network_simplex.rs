@@ -0,0 +1,475 @@
+use super::min_cost_flow::{
+    get_cost_flow_problem, infeasible_error, unbounded_error, CostFlowProblem, MinCostFlow,
+};
+use crate::{Edge, Error, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Finds a minimum cost flow that satisfies all the node demands, using the primal network
+simplex method.
+
+A node with a negative demand is a supply node: flow leaves it. A node with a positive demand
+consumes flow. The demands must sum to zero. This function solves the same problem as
+[min_cost_flow](./fn.min_cost_flow.html) and is usually faster on large instances.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed and must not
+  have multiple edges between two nodes
+* `demand`: the demand of each node; nodes that are not present have a demand of zero
+* `capacity`: a function that returns the capacity of an edge; if `None`, or if the function
+  returns `f64::NAN`, the capacity is infinite
+* `cost`: a function that returns the cost per unit of flow of an edge; if `None` the edge
+  weight is used, and an edge without a weight has a cost of zero
+
+# Errors
+
+An error of kind `Infeasible` is returned if no flow satisfies all the node demands, and an
+error of kind `Unbounded` if there's a cycle of negative cost and infinite capacity.
+Feasibility is checked first, so as in NetworkX an instance that is both infeasible and
+unbounded gives an `Infeasible` error.
+
+# Examples
+
+```
+use graphrs::{algorithms::flow, Edge, Graph, GraphSpecs};
+use std::collections::HashMap;
+let edges = vec![
+    Edge::with_weight("a", "b", 3.0),
+    Edge::with_weight("a", "c", 6.0),
+    Edge::with_weight("b", "d", 1.0),
+    Edge::with_weight("c", "d", 2.0),
+];
+let graph: Graph<&str, ()> =
+    Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+        .unwrap();
+let demand: HashMap<&str, f64> = vec![("a", -5.0), ("d", 5.0)].into_iter().collect();
+let capacity = |edge: &Edge<&str, ()>| match (edge.u, edge.v) {
+    ("a", "c") => 10.0,
+    ("c", "d") => 5.0,
+    _ => 4.0,
+};
+let result = flow::network_simplex(&graph, &demand, Some(&capacity), None).unwrap();
+assert_eq!(result.cost, 24.0);
+assert_eq!(result.flows[&("c", "d")], 1.0);
+```
+
+# References
+
+1. Z. Kiraly, P. Kovacs: Efficient implementation of minimum-cost flow algorithms.
+   Acta Universitatis Sapientiae, Informatica 4(1):67--118, 2012.
+2. R. Barr, F. Glover, D. Klingman: Enhancement of spanning tree labeling procedures for
+   network optimization. INFOR 17(1):16--34, 1979.
+*/
+#[allow(clippy::type_complexity)]
+pub fn network_simplex<T, A>(
+    graph: &Graph<T, A>,
+    demand: &HashMap<T, f64>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+    cost: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+) -> Result<MinCostFlow<T>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let problem = get_cost_flow_problem(graph, demand, capacity, cost)?;
+    let active_edges = problem.active_edges();
+    problem.check_feasible(&active_edges)?;
+    problem.check_infinite_negative_cycles(&active_edges)?;
+    let mut simplex = NetworkSimplex::new(&problem, &active_edges);
+    simplex.solve();
+    let n = problem.num_nodes;
+    let m = active_edges.len();
+    if simplex.flow[m..m + n].iter().any(|f| *f != 0.0) {
+        return Err(infeasible_error());
+    }
+    if simplex.flow[..m]
+        .iter()
+        .any(|f| f * 2.0 >= simplex.faux_inf)
+    {
+        return Err(unbounded_error());
+    }
+    let mut flows = vec![0.0; problem.edges.len()];
+    for (i, f) in active_edges.iter().zip(simplex.flow) {
+        flows[*i] = f;
+    }
+    Ok(problem.to_result(graph, flows))
+}
+
+/**
+The state of the network simplex method. The spanning tree is rooted at an artificial node,
+with index `n`, that's joined to every node by an artificial edge; the artificial edges
+have indexes `m..m + n`. The tree is stored with parent pointers and a depth-first thread.
+*/
+struct NetworkSimplex {
+    m: usize,
+    faux_inf: f64,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    capacities: Vec<f64>,
+    weights: Vec<f64>,
+    flow: Vec<f64>,
+    potentials: Vec<f64>,
+    parent: Vec<Option<usize>>,
+    parent_edge: Vec<Option<usize>>,
+    subtree_size: Vec<usize>,
+    next_node_dft: Vec<usize>,
+    prev_node_dft: Vec<usize>,
+    last_descendent_dft: Vec<usize>,
+}
+
+impl NetworkSimplex {
+    fn new(problem: &CostFlowProblem, active_edges: &[usize]) -> NetworkSimplex {
+        let n = problem.num_nodes;
+        let m = active_edges.len();
+        let mut sources = Vec::with_capacity(m + n);
+        let mut targets = Vec::with_capacity(m + n);
+        let mut capacities = Vec::with_capacity(m + n);
+        let mut weights = Vec::with_capacity(m + n);
+        for i in active_edges {
+            let (u, v, c, w) = problem.edges[*i];
+            sources.push(u);
+            targets.push(v);
+            capacities.push(c);
+            weights.push(w);
+        }
+        for (i, d) in problem.demands.iter().enumerate() {
+            match *d > 0.0 {
+                true => {
+                    sources.push(n);
+                    targets.push(i);
+                }
+                false => {
+                    sources.push(i);
+                    targets.push(n);
+                }
+            }
+        }
+        let faux_inf = 3.0
+            * [
+                capacities.iter().filter(|c| c.is_finite()).sum::<f64>(),
+                weights.iter().map(|w| w.abs()).sum::<f64>(),
+                problem.demands.iter().map(|d| d.abs()).sum::<f64>(),
+            ]
+            .into_iter()
+            .fold(0.0, f64::max);
+        let faux_inf = match faux_inf == 0.0 {
+            true => 1.0,
+            false => faux_inf,
+        };
+        weights.extend(std::iter::repeat_n(faux_inf, n));
+        capacities.extend(std::iter::repeat_n(faux_inf, n));
+        let mut flow = vec![0.0; m];
+        flow.extend(problem.demands.iter().map(|d| d.abs()));
+        let mut potentials: Vec<f64> = problem
+            .demands
+            .iter()
+            .map(|d| match *d <= 0.0 {
+                true => faux_inf,
+                false => -faux_inf,
+            })
+            .collect();
+        potentials.push(0.0);
+        let mut parent = vec![Some(n); n];
+        parent.push(None);
+        let mut parent_edge: Vec<Option<usize>> = (m..m + n).map(Some).collect();
+        parent_edge.push(None);
+        let mut subtree_size = vec![1; n];
+        subtree_size.push(n + 1);
+        let mut next_node_dft: Vec<usize> = (1..n).collect();
+        next_node_dft.push(n);
+        next_node_dft.push(0);
+        let mut prev_node_dft = vec![n];
+        prev_node_dft.extend(0..n);
+        let mut last_descendent_dft: Vec<usize> = (0..n).collect();
+        last_descendent_dft.push(n - 1);
+        NetworkSimplex {
+            m,
+            faux_inf,
+            sources,
+            targets,
+            capacities,
+            weights,
+            flow,
+            potentials,
+            parent,
+            parent_edge,
+            subtree_size,
+            next_node_dft,
+            prev_node_dft,
+            last_descendent_dft,
+        }
+    }
+
+    fn solve(&mut self) {
+        if self.m == 0 {
+            return;
+        }
+        // block search pivoting
+        let block_size = (self.m as f64).sqrt().ceil() as usize;
+        let num_blocks = self.m.div_ceil(block_size);
+        let mut blocks_without_eligible = 0;
+        let mut first = 0;
+        while blocks_without_eligible < num_blocks {
+            let mut last = first + block_size;
+            let block: Vec<usize> = match last <= self.m {
+                true => (first..last).collect(),
+                false => {
+                    last -= self.m;
+                    (first..self.m).chain(0..last).collect()
+                }
+            };
+            first = last;
+            let mut i = block[0];
+            let mut c = self.reduced_cost(i);
+            for j in block.into_iter().skip(1) {
+                let cj = self.reduced_cost(j);
+                if cj < c {
+                    i = j;
+                    c = cj;
+                }
+            }
+            if c >= 0.0 {
+                blocks_without_eligible += 1;
+                continue;
+            }
+            blocks_without_eligible = 0;
+            let (p, q) = match self.flow[i] == 0.0 {
+                true => (self.sources[i], self.targets[i]),
+                false => (self.targets[i], self.sources[i]),
+            };
+            self.pivot(i, p, q);
+        }
+    }
+
+    /// Adds the entering edge `i`, from `p` to `q`, to the spanning tree and removes the
+    /// leaving edge of the cycle that's formed.
+    fn pivot(&mut self, i: usize, p: usize, q: usize) {
+        let (cycle_nodes, cycle_edges) = self.find_cycle(i, p, q);
+        let (j, s, t) = self.find_leaving_edge(&cycle_nodes, &cycle_edges);
+        let amount = self.residual_capacity(j, s);
+        self.augment_flow(&cycle_nodes, &cycle_edges, amount);
+        if i == j {
+            return;
+        }
+        let (s, t) = match self.parent[t] == Some(s) {
+            true => (s, t),
+            false => (t, s),
+        };
+        let position_i = cycle_edges.iter().position(|e| *e == i).unwrap();
+        let position_j = cycle_edges.iter().position(|e| *e == j).unwrap();
+        let (p, q) = match position_i > position_j {
+            true => (q, p),
+            false => (p, q),
+        };
+        self.remove_edge(s, t);
+        self.make_root(q);
+        self.add_edge(i, p, q);
+        self.update_potentials(i, p, q);
+    }
+
+    fn reduced_cost(&self, i: usize) -> f64 {
+        let c =
+            self.weights[i] - self.potentials[self.sources[i]] + self.potentials[self.targets[i]];
+        match self.flow[i] == 0.0 {
+            true => c,
+            false => -c,
+        }
+    }
+
+    /// Returns the residual capacity of edge `i` in the direction away from its endpoint `p`.
+    fn residual_capacity(&self, i: usize, p: usize) -> f64 {
+        match self.sources[i] == p {
+            true => self.capacities[i] - self.flow[i],
+            false => self.flow[i],
+        }
+    }
+
+    /// Finds the lowest common ancestor of nodes `p` and `q` in the spanning tree.
+    fn find_apex(&self, mut p: usize, mut q: usize) -> usize {
+        let mut size_p = self.subtree_size[p];
+        let mut size_q = self.subtree_size[q];
+        loop {
+            while size_p < size_q {
+                p = self.parent[p].unwrap();
+                size_p = self.subtree_size[p];
+            }
+            while size_p > size_q {
+                q = self.parent[q].unwrap();
+                size_q = self.subtree_size[q];
+            }
+            if size_p == size_q {
+                if p == q {
+                    return p;
+                }
+                p = self.parent[p].unwrap();
+                size_p = self.subtree_size[p];
+                q = self.parent[q].unwrap();
+                size_q = self.subtree_size[q];
+            }
+        }
+    }
+
+    /// Returns the nodes and edges on the path from node `p` to its ancestor `w`.
+    fn trace_path(&self, mut p: usize, w: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut nodes = vec![p];
+        let mut edges = vec![];
+        while p != w {
+            edges.push(self.parent_edge[p].unwrap());
+            p = self.parent[p].unwrap();
+            nodes.push(p);
+        }
+        (nodes, edges)
+    }
+
+    /// Returns the nodes and edges on the cycle formed when edge `i`, from `p` to `q`, is
+    /// added to the spanning tree. The cycle is oriented in the direction from `p` to `q`.
+    fn find_cycle(&self, i: usize, p: usize, q: usize) -> (Vec<usize>, Vec<usize>) {
+        let w = self.find_apex(p, q);
+        let (mut nodes, mut edges) = self.trace_path(p, w);
+        nodes.reverse();
+        edges.reverse();
+        if edges != [i] {
+            edges.push(i);
+        }
+        let (mut nodes_r, edges_r) = self.trace_path(q, w);
+        nodes_r.pop();
+        nodes.extend(nodes_r);
+        edges.extend(edges_r);
+        (nodes, edges)
+    }
+
+    /// Returns the leaving edge of a cycle, along with its endpoints `(s, t)` where `s` is
+    /// the endpoint the cycle leaves from.
+    fn find_leaving_edge(&self, nodes: &[usize], edges: &[usize]) -> (usize, usize, usize) {
+        let mut best: Option<(usize, usize, f64)> = None;
+        for (i, p) in edges.iter().zip(nodes.iter()).rev() {
+            let r = self.residual_capacity(*i, *p);
+            if best.is_none() || r < best.unwrap().2 {
+                best = Some((*i, *p, r));
+            }
+        }
+        let (j, s, _) = best.unwrap();
+        let t = match self.sources[j] == s {
+            true => self.targets[j],
+            false => self.sources[j],
+        };
+        (j, s, t)
+    }
+
+    fn augment_flow(&mut self, nodes: &[usize], edges: &[usize], amount: f64) {
+        for (i, p) in edges.iter().zip(nodes.iter()) {
+            match self.sources[*i] == *p {
+                true => self.flow[*i] += amount,
+                false => self.flow[*i] -= amount,
+            }
+        }
+    }
+
+    /// Returns the nodes in the subtree rooted at node `p`.
+    fn trace_subtree(&self, mut p: usize) -> Vec<usize> {
+        let mut nodes = vec![p];
+        let last = self.last_descendent_dft[p];
+        while p != last {
+            p = self.next_node_dft[p];
+            nodes.push(p);
+        }
+        nodes
+    }
+
+    /// Removes the edge `(s, t)`, where `s` is the parent of `t`, from the spanning tree.
+    fn remove_edge(&mut self, s: usize, t: usize) {
+        let size_t = self.subtree_size[t];
+        let prev_t = self.prev_node_dft[t];
+        let last_t = self.last_descendent_dft[t];
+        let next_last_t = self.next_node_dft[last_t];
+        self.parent[t] = None;
+        self.parent_edge[t] = None;
+        self.next_node_dft[prev_t] = next_last_t;
+        self.prev_node_dft[next_last_t] = prev_t;
+        self.next_node_dft[last_t] = t;
+        self.prev_node_dft[t] = last_t;
+        let mut s = Some(s);
+        while let Some(x) = s {
+            self.subtree_size[x] -= size_t;
+            if self.last_descendent_dft[x] == last_t {
+                self.last_descendent_dft[x] = prev_t;
+            }
+            s = self.parent[x];
+        }
+    }
+
+    /// Makes node `q` the root of the subtree that contains it.
+    fn make_root(&mut self, q: usize) {
+        let mut ancestors = vec![];
+        let mut x = Some(q);
+        while let Some(a) = x {
+            ancestors.push(a);
+            x = self.parent[a];
+        }
+        ancestors.reverse();
+        for pair in ancestors.windows(2) {
+            let (p, q) = (pair[0], pair[1]);
+            let size_p = self.subtree_size[p];
+            let mut last_p = self.last_descendent_dft[p];
+            let prev_q = self.prev_node_dft[q];
+            let last_q = self.last_descendent_dft[q];
+            let next_last_q = self.next_node_dft[last_q];
+            self.parent[p] = Some(q);
+            self.parent[q] = None;
+            self.parent_edge[p] = self.parent_edge[q];
+            self.parent_edge[q] = None;
+            self.subtree_size[p] = size_p - self.subtree_size[q];
+            self.subtree_size[q] = size_p;
+            self.next_node_dft[prev_q] = next_last_q;
+            self.prev_node_dft[next_last_q] = prev_q;
+            self.next_node_dft[last_q] = q;
+            self.prev_node_dft[q] = last_q;
+            if last_p == last_q {
+                self.last_descendent_dft[p] = prev_q;
+                last_p = prev_q;
+            }
+            self.prev_node_dft[p] = last_q;
+            self.next_node_dft[last_q] = p;
+            self.next_node_dft[last_p] = q;
+            self.prev_node_dft[q] = last_p;
+            self.last_descendent_dft[q] = last_p;
+        }
+    }
+
+    /// Adds edge `i`, from `p` to `q`, to the spanning tree where `q` is the root of a subtree.
+    fn add_edge(&mut self, i: usize, p: usize, q: usize) {
+        let last_p = self.last_descendent_dft[p];
+        let next_last_p = self.next_node_dft[last_p];
+        let size_q = self.subtree_size[q];
+        let last_q = self.last_descendent_dft[q];
+        self.parent[q] = Some(p);
+        self.parent_edge[q] = Some(i);
+        self.next_node_dft[last_p] = q;
+        self.prev_node_dft[q] = last_p;
+        self.prev_node_dft[next_last_p] = last_q;
+        self.next_node_dft[last_q] = next_last_p;
+        let mut x = Some(p);
+        while let Some(a) = x {
+            self.subtree_size[a] += size_q;
+            if self.last_descendent_dft[a] == last_p {
+                self.last_descendent_dft[a] = last_q;
+            }
+            x = self.parent[a];
+        }
+    }
+
+    /// Updates the potentials of the nodes in the subtree rooted at node `q`, which is joined
+    /// to its parent `p` by edge `i`.
+    fn update_potentials(&mut self, i: usize, p: usize, q: usize) {
+        let d = match q == self.targets[i] {
+            true => self.potentials[p] - self.weights[i] - self.potentials[q],
+            false => self.potentials[p] + self.weights[i] - self.potentials[q],
+        };
+        for x in self.trace_subtree(q) {
+            self.potentials[x] += d;
+        }
+    }
+}
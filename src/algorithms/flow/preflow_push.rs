@@ -0,0 +1,88 @@
+use super::residual::ResidualNetwork;
+use std::collections::VecDeque;
+
+/**
+Computes a maximum flow with the FIFO preflow-push (push-relabel) algorithm of Goldberg and
+Tarjan. Excess flow is pushed from active nodes towards nodes with a lower height; nodes
+with excess that can't reach the target are relabelled until the excess returns to the
+source. Runs in O(n³) time.
+*/
+pub(crate) fn preflow_push(residual: &mut ResidualNetwork, source: usize, target: usize) {
+    let num_nodes = residual.num_nodes;
+    let mut height = initial_heights(residual, target);
+    height[source] = num_nodes;
+    let mut excess = vec![0.0; num_nodes];
+    let mut active = vec![false; num_nodes];
+    let mut queue = VecDeque::new();
+    for i in 0..residual.adjacency[source].len() {
+        let e = residual.adjacency[source][i];
+        if residual.has_residual(e) {
+            let v = residual.head[e];
+            let amount = residual.residual(e);
+            residual.push(e, amount);
+            excess[v] += amount;
+            if v != target && !active[v] {
+                active[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+    let mut next_arc = vec![0; num_nodes];
+    while let Some(u) = queue.pop_front() {
+        active[u] = false;
+        // discharge u
+        while excess[u] > residual.tolerance {
+            if next_arc[u] == residual.adjacency[u].len() {
+                // relabel
+                let min_height = residual.adjacency[u]
+                    .iter()
+                    .filter(|e| residual.has_residual(**e))
+                    .map(|e| height[residual.head[*e]])
+                    .min();
+                match min_height {
+                    Some(h) => height[u] = h + 1,
+                    None => break,
+                }
+                next_arc[u] = 0;
+                continue;
+            }
+            let e = residual.adjacency[u][next_arc[u]];
+            let v = residual.head[e];
+            if residual.has_residual(e) && height[u] == height[v] + 1 {
+                let amount = excess[u].min(residual.residual(e));
+                residual.push(e, amount);
+                excess[u] -= amount;
+                excess[v] += amount;
+                if v != source && v != target && !active[v] {
+                    active[v] = true;
+                    queue.push_back(v);
+                }
+            } else {
+                next_arc[u] += 1;
+            }
+        }
+    }
+}
+
+/**
+Returns the distance of each node to `target` in the residual network, which is a valid
+labelling to start the algorithm with. Nodes that can't reach `target` get a height of
+the number of nodes.
+*/
+fn initial_heights(residual: &ResidualNetwork, target: usize) -> Vec<usize> {
+    let num_nodes = residual.num_nodes;
+    let mut height = vec![num_nodes; num_nodes];
+    height[target] = 0;
+    let mut queue = VecDeque::from(vec![target]);
+    while let Some(v) = queue.pop_front() {
+        for e in residual.adjacency[v].iter() {
+            // the reverse of arc `e` goes from `u` to `v`
+            let u = residual.head[*e];
+            if height[u] == num_nodes && u != target && residual.has_residual(e ^ 1) {
+                height[u] = height[v] + 1;
+                queue.push_back(u);
+            }
+        }
+    }
+    height
+}
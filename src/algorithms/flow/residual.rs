@@ -0,0 +1,215 @@
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+A residual network stored as a list of arcs. Arcs are created in pairs: arc `e` and arc `e ^ 1`
+are the reverse of each other, and the flow on one is always the negation of the flow on
+the other.
+*/
+#[derive(Clone)]
+pub(crate) struct ResidualNetwork {
+    pub num_nodes: usize,
+    /// The arcs leaving each node.
+    pub adjacency: Vec<Vec<usize>>,
+    /// The node each arc points to.
+    pub head: Vec<usize>,
+    pub capacity: Vec<f64>,
+    pub flow: Vec<f64>,
+    /// Residual capacities at or below this value are treated as zero.
+    pub tolerance: f64,
+}
+
+impl ResidualNetwork {
+    pub fn new(num_nodes: usize) -> ResidualNetwork {
+        ResidualNetwork {
+            num_nodes,
+            adjacency: vec![vec![]; num_nodes],
+            head: vec![],
+            capacity: vec![],
+            flow: vec![],
+            tolerance: 0.0,
+        }
+    }
+
+    /// Adds an arc from `u` to `v` and its reverse arc; returns the index of the forward arc.
+    pub fn add_arc(&mut self, u: usize, v: usize, capacity: f64, reverse_capacity: f64) -> usize {
+        let e = self.head.len();
+        self.head.push(v);
+        self.capacity.push(capacity);
+        self.flow.push(0.0);
+        self.adjacency[u].push(e);
+        self.head.push(u);
+        self.capacity.push(reverse_capacity);
+        self.flow.push(0.0);
+        self.adjacency[v].push(e + 1);
+        e
+    }
+
+    #[inline]
+    pub fn tail(&self, e: usize) -> usize {
+        self.head[e ^ 1]
+    }
+
+    #[inline]
+    pub fn residual(&self, e: usize) -> f64 {
+        self.capacity[e] - self.flow[e]
+    }
+
+    #[inline]
+    pub fn has_residual(&self, e: usize) -> bool {
+        self.residual(e) > self.tolerance
+    }
+
+    #[inline]
+    pub fn push(&mut self, e: usize, amount: f64) {
+        self.flow[e] += amount;
+        self.flow[e ^ 1] -= amount;
+    }
+
+    /// Returns the net flow into `node`.
+    pub fn net_inflow(&self, node: usize) -> f64 {
+        -self.adjacency[node]
+            .iter()
+            .map(|e| self.flow[*e])
+            .sum::<f64>()
+    }
+
+    /// Returns, for each node, whether it can be reached from `source` using arcs that have
+    /// residual capacity.
+    pub fn reachable_from(&self, source: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.num_nodes];
+        seen[source] = true;
+        let mut queue = VecDeque::from(vec![source]);
+        while let Some(u) = queue.pop_front() {
+            for e in self.adjacency[u].iter() {
+                let v = self.head[*e];
+                if !seen[v] && self.has_residual(*e) {
+                    seen[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+        seen
+    }
+
+    /**
+    Replaces infinite capacities with a finite value that is larger than the capacity of any
+    finite cut. Returns an error if there's a path of infinite capacity from `source` to
+    `target`, because then the maximum flow is unbounded.
+    */
+    pub fn replace_infinite_capacities(
+        &mut self,
+        source: usize,
+        target: usize,
+    ) -> Result<(), Error> {
+        let mut seen = vec![false; self.num_nodes];
+        seen[source] = true;
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            for e in self.adjacency[u].iter() {
+                let v = self.head[*e];
+                if !seen[v] && self.capacity[*e] == f64::INFINITY {
+                    if v == target {
+                        return Err(Error {
+                            kind: ErrorKind::Unbounded,
+                            message: "Infinite capacity path found: the maximum flow is unbounded."
+                                .to_string(),
+                        });
+                    }
+                    seen[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        let total: f64 = self.capacity.iter().filter(|c| c.is_finite()).sum::<f64>();
+        let infinity = match total > 0.0 {
+            true => 3.0 * total,
+            false => 1.0,
+        };
+        for c in self.capacity.iter_mut() {
+            if *c == f64::INFINITY {
+                *c = infinity;
+            }
+        }
+        self.tolerance = total * 1e-12;
+        Ok(())
+    }
+}
+
+/**
+Builds the residual network of a graph. An undirected edge gives arcs in both directions with
+the same capacity.
+
+Returns the residual network and, for each direction of each edge of the graph, a tuple of
+`(u, v, arc)` where `arc` is the index of the arc that carries the flow from `u` to `v`.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn build_residual_network<T, A>(
+    graph: &Graph<T, A>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+) -> Result<(ResidualNetwork, Vec<(usize, usize, usize)>), Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_not_multi_edges()?;
+    let mut residual = ResidualNetwork::new(graph.number_of_nodes());
+    let mut arcs = vec![];
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u)?;
+        let v = graph.get_node_index(&edge.v)?;
+        if u == v {
+            continue;
+        }
+        let c = get_capacity(edge, capacity)?;
+        match graph.specs.directed {
+            true => {
+                let e = residual.add_arc(u, v, c, 0.0);
+                arcs.push((u, v, e));
+            }
+            false => {
+                let e = residual.add_arc(u, v, c, c);
+                arcs.push((u, v, e));
+                arcs.push((v, u, e ^ 1));
+            }
+        }
+    }
+    let total: f64 = residual.capacity.iter().filter(|c| c.is_finite()).sum();
+    residual.tolerance = total * 1e-12;
+    Ok((residual, arcs))
+}
+
+/**
+Returns the capacity of an edge: the value returned by the `capacity` accessor if one was
+given, otherwise the edge weight. A capacity of `f64::NAN`, such as the weight of an
+unweighted edge, is an infinite capacity.
+*/
+#[allow(clippy::type_complexity)]
+pub(crate) fn get_capacity<T, A>(
+    edge: &Edge<T, A>,
+    capacity: Option<&dyn Fn(&Edge<T, A>) -> f64>,
+) -> Result<f64, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let c = match capacity {
+        Some(f) => f(edge),
+        None => edge.weight,
+    };
+    if c.is_nan() {
+        return Ok(f64::INFINITY);
+    }
+    if c < 0.0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: format!(
+                "The capacity of edge ({}, {}) is negative: {}.",
+                edge.u, edge.v, c
+            ),
+        });
+    }
+    Ok(c)
+}
@@ -0,0 +1,701 @@
+use crate::{Error, Graph};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a maximum weight matching of an undirected graph: a set of edges, no two of which share
+a node, whose sum of edge weights is as large as possible.
+
+The matching is found with Edmonds' blossom algorithm, in the O(n³) primal-dual form described
+by Galil, which works on general (not just bipartite) graphs.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance. Self-loops are ignored
+  and of multiple edges between two nodes only the heaviest is used.
+* `max_cardinality`: if `true` the matching has the largest possible number of edges, and
+  the largest possible weight among the matchings with that many edges
+* `weighted`: if `true` the edge weights are used and every edge must have a weight,
+  otherwise every edge has a weight of one
+
+# Returns
+
+The edges of the matching as `(u, v)` tuples, where `u` is less than `v`.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight(1, 2, 2.0),
+    Edge::with_weight(2, 3, 5.0),
+    Edge::with_weight(3, 4, 2.0),
+];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let result = matching::max_weight_matching(&graph, false, true).unwrap();
+assert_eq!(result, vec![(2, 3)].into_iter().collect());
+let result = matching::max_weight_matching(&graph, true, true).unwrap();
+assert_eq!(result, vec![(1, 2), (3, 4)].into_iter().collect());
+```
+
+# References
+
+1. J. Edmonds: Paths, trees, and flowers. Canadian Journal of Mathematics 17:449–467, 1965.
+2. Z. Galil: Efficient algorithms for finding maximum matching in graphs. ACM Computing
+   Surveys 18(1):23–38, 1986.
+*/
+pub fn max_weight_matching<T, A>(
+    graph: &Graph<T, A>,
+    max_cardinality: bool,
+    weighted: bool,
+) -> Result<HashSet<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    if weighted {
+        graph.ensure_weighted()?;
+    }
+    // the heaviest edge between each pair of nodes
+    let mut weights: HashMap<(usize, usize), f64> = HashMap::new();
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u).unwrap();
+        let v = graph.get_node_index(&edge.v).unwrap();
+        if u == v {
+            continue;
+        }
+        let weight = match weighted {
+            true => edge.weight,
+            false => 1.0,
+        };
+        let entry = weights.entry((u.min(v), u.max(v))).or_insert(weight);
+        *entry = entry.max(weight);
+    }
+    let mut edges: Vec<(usize, usize, f64)> =
+        weights.into_iter().map(|((u, v), w)| (u, v, w)).collect();
+    edges.sort_by_key(|e| (e.0, e.1));
+    let mates = Blossoms::new(graph.number_of_nodes(), edges, max_cardinality).solve();
+    Ok(mates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(u, mate)| mate.filter(|v| u < *v).map(|v| (u, v)))
+        .map(|(u, v)| {
+            let u = graph.get_node_by_index(&u).unwrap().name.clone();
+            let v = graph.get_node_by_index(&v).unwrap().name.clone();
+            match u < v {
+                true => (u, v),
+                false => (v, u),
+            }
+        })
+        .collect())
+}
+
+/**
+Returns a maximum cardinality matching of an undirected graph: a set of edges, no two of which
+share a node, with as many edges as possible. Edge weights are ignored.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance; self-loops are ignored
+
+# Returns
+
+The edges of the matching as `(u, v)` tuples, where `u` is less than `v`.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, generators};
+let graph = generators::social::karate_club_graph();
+let result = matching::max_cardinality_matching(&graph).unwrap();
+assert_eq!(result.len(), 13);
+```
+*/
+pub fn max_cardinality_matching<T, A>(graph: &Graph<T, A>) -> Result<HashSet<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    max_weight_matching(graph, true, false)
+}
+
+/**
+The state of the primal-dual blossom algorithm on a graph with nodes `0..n`.
+
+Each edge `k` has two endpoints, `2k` (the edge's `u`) and `2k + 1` (the edge's `v`), so that
+`p ^ 1` is the other end of endpoint `p`. Blossoms are numbered `n..2n`; the nodes themselves
+act as trivial blossoms. Nodes and top-level blossoms are labeled 0 (free), 1 (S, outer) or
+2 (T, inner); 5 temporarily marks a blossom while looking for a common ancestor.
+*/
+struct Blossoms {
+    n: usize,
+    edges: Vec<(usize, usize, f64)>,
+    max_cardinality: bool,
+    /// The node at each endpoint.
+    endpoint: Vec<usize>,
+    /// The remote endpoints of the edges of each node.
+    neighbor_endpoints: Vec<Vec<usize>>,
+    /// The remote endpoint of the matched edge of each node.
+    mate: Vec<Option<usize>>,
+    label: Vec<u8>,
+    /// The remote endpoint of the edge through which a node or blossom got its label.
+    label_end: Vec<Option<usize>>,
+    /// The top-level blossom each node belongs to.
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<Option<usize>>,
+    /// The sub-blossoms of each blossom, in order around the blossom's cycle, starting at
+    /// the sub-blossom that contains the base.
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<Option<usize>>,
+    /// The endpoints of the edges that join consecutive sub-blossoms of each blossom.
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// For each free node and each top-level S-blossom, the least-slack edge to an S-blossom.
+    best_edge: Vec<Option<usize>>,
+    /// For each top-level S-blossom, the least-slack edges to other S-blossoms.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<f64>,
+    /// Whether each edge has zero slack, and so can be used to grow the alternating trees.
+    allowed: Vec<bool>,
+    /// The S-nodes whose edges haven't been scanned yet.
+    queue: Vec<usize>,
+}
+
+impl Blossoms {
+    fn new(n: usize, edges: Vec<(usize, usize, f64)>, max_cardinality: bool) -> Blossoms {
+        let max_weight = edges.iter().map(|e| e.2).fold(0.0, f64::max);
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        let mut neighbor_endpoints = vec![vec![]; n];
+        for (k, (u, v, _)) in edges.iter().enumerate() {
+            endpoint.push(*u);
+            endpoint.push(*v);
+            neighbor_endpoints[*u].push(2 * k + 1);
+            neighbor_endpoints[*v].push(2 * k);
+        }
+        let m = edges.len();
+        Blossoms {
+            n,
+            edges,
+            max_cardinality,
+            endpoint,
+            neighbor_endpoints,
+            mate: vec![None; n],
+            label: vec![0; 2 * n],
+            label_end: vec![None; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![None; 2 * n],
+            blossom_children: vec![vec![]; 2 * n],
+            blossom_base: (0..n).map(Some).chain((0..n).map(|_| None)).collect(),
+            blossom_endpoints: vec![vec![]; 2 * n],
+            best_edge: vec![None; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..(2 * n)).collect(),
+            dual: (0..(2 * n))
+                .map(|i| if i < n { max_weight } else { 0.0 })
+                .collect(),
+            allowed: vec![false; m],
+            queue: vec![],
+        }
+    }
+
+    /// Runs the algorithm and returns the node each node is matched with.
+    fn solve(mut self) -> Vec<Option<usize>> {
+        let n = self.n;
+        // each stage augments the matching by one edge, or finds that it's optimal
+        for _ in 0..n {
+            self.label.fill(0);
+            self.best_edge.fill(None);
+            self.blossom_best_edges[n..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v].is_none() && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, None);
+                }
+            }
+            let mut augmented = false;
+            loop {
+                while !augmented {
+                    let v = match self.queue.pop() {
+                        None => break,
+                        Some(v) => v,
+                    };
+                    augmented = self.scan_node(v);
+                }
+                if augmented {
+                    break;
+                }
+                if !self.update_duals() {
+                    break;
+                }
+            }
+            if !augmented {
+                break;
+            }
+            // expand the S-blossoms whose dual variables have reached zero
+            for b in n..(2 * n) {
+                if self.blossom_parent[b].is_none()
+                    && self.blossom_base[b].is_some()
+                    && self.label[b] == 1
+                    && self.dual[b] == 0.0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+        self.mate
+            .iter()
+            .map(|p| p.map(|p| self.endpoint[p]))
+            .collect()
+    }
+
+    fn slack(&self, k: usize) -> f64 {
+        let (u, v, weight) = self.edges[k];
+        self.dual[u] + self.dual[v] - 2.0 * weight
+    }
+
+    /// Returns the nodes in blossom `b`.
+    fn blossom_leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            match b < self.n {
+                true => leaves.push(b),
+                false => stack.extend(self.blossom_children[b].iter().rev()),
+            }
+        }
+        leaves
+    }
+
+    /**
+    Scans the edges of S-node `v`, growing the alternating trees and making blossoms.
+    Returns `true` if the matching was augmented.
+    */
+    fn scan_node(&mut self, v: usize) -> bool {
+        for i in 0..self.neighbor_endpoints[v].len() {
+            let p = self.neighbor_endpoints[v][i];
+            let k = p / 2;
+            let w = self.endpoint[p];
+            if self.in_blossom[v] == self.in_blossom[w] {
+                continue;
+            }
+            let mut k_slack = 0.0;
+            if !self.allowed[k] {
+                k_slack = self.slack(k);
+                if k_slack <= 0.0 {
+                    self.allowed[k] = true;
+                }
+            }
+            if self.allowed[k] {
+                if self.label[self.in_blossom[w]] == 0 {
+                    // w is free: label it T and its mate S
+                    self.assign_label(w, 2, Some(p ^ 1));
+                } else if self.label[self.in_blossom[w]] == 1 {
+                    // w is an S-node: make a blossom or augment the matching
+                    match self.scan_blossom(v, w) {
+                        Some(base) => self.add_blossom(base, k),
+                        None => {
+                            self.augment_matching(k);
+                            return true;
+                        }
+                    }
+                } else if self.label[w] == 0 {
+                    // w is inside a T-blossom but hasn't been reached from an S-node yet
+                    self.label[w] = 2;
+                    self.label_end[w] = Some(p ^ 1);
+                }
+            } else if self.label[self.in_blossom[w]] == 1 {
+                let b = self.in_blossom[v];
+                if self.best_edge[b].is_none_or(|e| k_slack < self.slack(e)) {
+                    self.best_edge[b] = Some(k);
+                }
+            } else if self.label[w] == 0
+                && self.best_edge[w].is_none_or(|e| k_slack < self.slack(e))
+            {
+                self.best_edge[w] = Some(k);
+            }
+        }
+        false
+    }
+
+    /**
+    Changes the dual variables by the largest amount that keeps every slack non-negative, which
+    allows an edge or expands a blossom. Returns `false` if the matching is optimal.
+    */
+    fn update_duals(&mut self) -> bool {
+        let n = self.n;
+        // the kind of change: 1 means no further improvement is possible, 2 allows an edge
+        // from a free node, 3 allows an edge between S-blossoms and 4 expands a T-blossom
+        let mut delta_type = 0;
+        let mut delta = 0.0;
+        let mut delta_edge = 0;
+        let mut delta_blossom = 0;
+        if !self.max_cardinality {
+            delta_type = 1;
+            delta = self.dual[..n].iter().cloned().fold(f64::INFINITY, f64::min);
+        }
+        for v in 0..n {
+            if self.label[self.in_blossom[v]] == 0 {
+                if let Some(e) = self.best_edge[v] {
+                    let d = self.slack(e);
+                    if delta_type == 0 || d < delta {
+                        delta = d;
+                        delta_type = 2;
+                        delta_edge = e;
+                    }
+                }
+            }
+        }
+        for b in 0..(2 * n) {
+            if self.blossom_parent[b].is_none() && self.label[b] == 1 {
+                if let Some(e) = self.best_edge[b] {
+                    let d = self.slack(e) / 2.0;
+                    if delta_type == 0 || d < delta {
+                        delta = d;
+                        delta_type = 3;
+                        delta_edge = e;
+                    }
+                }
+            }
+        }
+        for b in n..(2 * n) {
+            if self.blossom_base[b].is_some()
+                && self.blossom_parent[b].is_none()
+                && self.label[b] == 2
+                && (delta_type == 0 || self.dual[b] < delta)
+            {
+                delta = self.dual[b];
+                delta_type = 4;
+                delta_blossom = b;
+            }
+        }
+        if delta_type == 0 {
+            // only possible with max_cardinality: no further growth is possible
+            delta_type = 1;
+            delta = self.dual[..n]
+                .iter()
+                .cloned()
+                .fold(f64::INFINITY, f64::min)
+                .max(0.0);
+        }
+        for v in 0..n {
+            match self.label[self.in_blossom[v]] {
+                1 => self.dual[v] -= delta,
+                2 => self.dual[v] += delta,
+                _ => {}
+            }
+        }
+        for b in n..(2 * n) {
+            if self.blossom_base[b].is_some() && self.blossom_parent[b].is_none() {
+                match self.label[b] {
+                    1 => self.dual[b] += delta,
+                    2 => self.dual[b] -= delta,
+                    _ => {}
+                }
+            }
+        }
+        match delta_type {
+            1 => return false,
+            2 => {
+                self.allowed[delta_edge] = true;
+                let (u, v, _) = self.edges[delta_edge];
+                match self.label[self.in_blossom[u]] == 0 {
+                    true => self.queue.push(v),
+                    false => self.queue.push(u),
+                }
+            }
+            3 => {
+                self.allowed[delta_edge] = true;
+                let (u, _, _) = self.edges[delta_edge];
+                self.queue.push(u);
+            }
+            _ => self.expand_blossom(delta_blossom, false),
+        }
+        true
+    }
+
+    /// Labels node `w`, and its top-level blossom, with `t`, reached through endpoint `p`.
+    fn assign_label(&mut self, w: usize, t: u8, p: Option<usize>) {
+        let (mut w, mut t, mut p) = (w, t, p);
+        loop {
+            let b = self.in_blossom[w];
+            self.label[w] = t;
+            self.label[b] = t;
+            self.label_end[w] = p;
+            self.label_end[b] = p;
+            self.best_edge[w] = None;
+            self.best_edge[b] = None;
+            if t == 1 {
+                let leaves = self.blossom_leaves(b);
+                self.queue.extend(leaves);
+                return;
+            }
+            // the mate of a T-blossom's base becomes an S-node
+            let base_mate = self.mate[self.blossom_base[b].unwrap()].unwrap();
+            w = self.endpoint[base_mate];
+            t = 1;
+            p = Some(base_mate ^ 1);
+        }
+    }
+
+    /**
+    Traces back from S-nodes `v` and `w` towards the roots of their alternating trees. Returns
+    the base of the new blossom if the paths meet, or `None` if they reach different roots,
+    in which case there's an augmenting path.
+    */
+    fn scan_blossom(&mut self, v: usize, w: usize) -> Option<usize> {
+        let mut path = vec![];
+        let mut base = None;
+        let (mut v, mut w) = (Some(v), Some(w));
+        while let Some(node) = v {
+            let b = self.in_blossom[node];
+            if self.label[b] & 4 != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            v = self.label_end[b].map(|p| {
+                let t = self.in_blossom[self.endpoint[p]];
+                self.endpoint[self.label_end[t].unwrap()]
+            });
+            if w.is_some() {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    /// Makes a new blossom with `base` from the cycle closed by edge `k`.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused_blossoms.pop().unwrap();
+        self.blossom_base[b] = Some(base);
+        self.blossom_parent[b] = None;
+        self.blossom_parent[bb] = Some(b);
+        let mut path = vec![];
+        let mut endpoints = vec![];
+        while bv != bb {
+            self.blossom_parent[bv] = Some(b);
+            path.push(bv);
+            let p = self.label_end[bv].unwrap();
+            endpoints.push(p);
+            bv = self.in_blossom[self.endpoint[p]];
+        }
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.blossom_parent[bw] = Some(b);
+            path.push(bw);
+            let p = self.label_end[bw].unwrap();
+            endpoints.push(p ^ 1);
+            bw = self.in_blossom[self.endpoint[p]];
+        }
+        self.label[b] = 1;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = 0.0;
+        self.blossom_children[b] = path.clone();
+        self.blossom_endpoints[b] = endpoints;
+        for v in self.blossom_leaves(b) {
+            if self.label[self.in_blossom[v]] == 2 {
+                // T-nodes inside the blossom become S-nodes
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+        // the least-slack edges from the new blossom to each other S-blossom
+        let mut best_edge_to: Vec<Option<usize>> = vec![None; 2 * self.n];
+        for bv in path {
+            let edge_lists: Vec<Vec<usize>> = match self.blossom_best_edges[bv].take() {
+                Some(edges) => vec![edges],
+                None => self
+                    .blossom_leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbor_endpoints[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for k in edge_lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[k];
+                let j = match self.in_blossom[j] == b {
+                    true => i,
+                    false => j,
+                };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && best_edge_to[bj].is_none_or(|e| self.slack(k) < self.slack(e))
+                {
+                    best_edge_to[bj] = Some(k);
+                }
+            }
+            self.best_edge[bv] = None;
+        }
+        let best_edges: Vec<usize> = best_edge_to.into_iter().flatten().collect();
+        self.best_edge[b] = None;
+        for k in best_edges.iter() {
+            if self.best_edge[b].is_none_or(|e| self.slack(*k) < self.slack(e)) {
+                self.best_edge[b] = Some(*k);
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+    }
+
+    /// Turns blossom `b` back into its sub-blossoms.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for s in self.blossom_children[b].clone() {
+            self.blossom_parent[s] = None;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == 0.0 {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.blossom_leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+        if !end_stage && self.label[b] == 2 {
+            // relabel the sub-blossoms on the even-length path through the expanded
+            // T-blossom, from where it was entered to its base
+            let children = self.blossom_children[b].clone();
+            let endpoints = self.blossom_endpoints[b].clone();
+            let len = children.len() as isize;
+            let at = |j: isize| j.rem_euclid(len) as usize;
+            let entry_child = self.in_blossom[self.endpoint[self.label_end[b].unwrap() ^ 1]];
+            let mut j = children.iter().position(|c| *c == entry_child).unwrap() as isize;
+            let (j_step, trick) = match j & 1 == 1 {
+                true => {
+                    j -= len;
+                    (1, 0)
+                }
+                false => (-1, 1),
+            };
+            let mut p = self.label_end[b].unwrap();
+            while j != 0 {
+                let q = endpoints[at(j - trick as isize)];
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ trick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, Some(p));
+                self.allowed[q / 2] = true;
+                j += j_step;
+                p = endpoints[at(j - trick as isize)] ^ trick;
+                self.allowed[p / 2] = true;
+                j += j_step;
+            }
+            let bv = children[at(j)];
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.label_end[self.endpoint[p ^ 1]] = Some(p);
+            self.label_end[bv] = Some(p);
+            self.best_edge[bv] = None;
+            j += j_step;
+            // the sub-blossoms on the other path become free unless they were reached
+            while children[at(j)] != entry_child {
+                let bv = children[at(j)];
+                if self.label[bv] != 1 {
+                    let reached = self
+                        .blossom_leaves(bv)
+                        .into_iter()
+                        .find(|v| self.label[*v] != 0);
+                    if let Some(v) = reached {
+                        self.label[v] = 0;
+                        let base_mate = self.mate[self.blossom_base[bv].unwrap()].unwrap();
+                        self.label[self.endpoint[base_mate]] = 0;
+                        self.assign_label(v, 2, self.label_end[v]);
+                    }
+                }
+                j += j_step;
+            }
+        }
+        self.label[b] = 0;
+        self.label_end[b] = None;
+        self.blossom_children[b] = vec![];
+        self.blossom_endpoints[b] = vec![];
+        self.blossom_base[b] = None;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = None;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swaps the matched and unmatched edges on the path through blossom `b` from node `v` to
+    /// the blossom's base, making `v` the new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != Some(b) {
+            t = self.blossom_parent[t].unwrap();
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let children = self.blossom_children[b].clone();
+        let endpoints = self.blossom_endpoints[b].clone();
+        let len = children.len() as isize;
+        let at = |j: isize| j.rem_euclid(len) as usize;
+        let i = children.iter().position(|c| *c == t).unwrap();
+        let mut j = i as isize;
+        let (j_step, trick) = match i & 1 == 1 {
+            true => {
+                j -= len;
+                (1, 0)
+            }
+            false => (-1, 1),
+        };
+        while j != 0 {
+            j += j_step;
+            let t = children[at(j)];
+            let p = endpoints[at(j - trick as isize)] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += j_step;
+            let t = children[at(j)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = Some(p ^ 1);
+            self.mate[self.endpoint[p ^ 1]] = Some(p);
+        }
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    /// Augments the matching along the path through edge `k` between two alternating trees.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (s, p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            let (mut s, mut p) = (s, p);
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = Some(p);
+                let label_end = match self.label_end[bs] {
+                    None => break,
+                    Some(label_end) => label_end,
+                };
+                let bt = self.in_blossom[self.endpoint[label_end]];
+                let bt_end = self.label_end[bt].unwrap();
+                s = self.endpoint[bt_end];
+                let j = self.endpoint[bt_end ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = Some(bt_end);
+                p = bt_end ^ 1;
+            }
+        }
+    }
+}
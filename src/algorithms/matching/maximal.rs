@@ -0,0 +1,161 @@
+use crate::{Error, Graph};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns a maximal matching of an undirected graph: a set of edges, no two of which share a
+node, to which no other edge of the graph can be added. The matching is found greedily, so it
+may have fewer edges than a [maximum cardinality
+matching](./fn.max_cardinality_matching.html), but at least half as many.
+
+# Arguments
+
+* `graph`: an undirected [Graph](../../struct.Graph.html) instance; self-loops are ignored
+
+# Returns
+
+The edges of the matching as `(u, v)` tuples, where `u` is less than `v`.
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, generators};
+let graph = generators::social::karate_club_graph();
+let result = matching::maximal_matching(&graph).unwrap();
+assert!(matching::is_maximal_matching(&graph, &result).unwrap());
+```
+*/
+pub fn maximal_matching<T, A>(graph: &Graph<T, A>) -> Result<HashSet<(T, T)>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_undirected()?;
+    let n = graph.number_of_nodes();
+    let mut matched = vec![false; n];
+    let mut matching = HashSet::new();
+    for u in 0..n {
+        if matched[u] {
+            continue;
+        }
+        let mate = graph
+            .get_successor_nodes_by_index(&u)
+            .iter()
+            .map(|adj| adj.node_index)
+            .find(|v| *v != u && !matched[*v]);
+        if let Some(v) = mate {
+            matched[u] = true;
+            matched[v] = true;
+            let u = graph.get_node_by_index(&u).unwrap().name.clone();
+            let v = graph.get_node_by_index(&v).unwrap().name.clone();
+            match u < v {
+                true => matching.insert((u, v)),
+                false => matching.insert((v, u)),
+            };
+        }
+    }
+    Ok(matching)
+}
+
+/**
+Returns `true` if `matching` is a matching of the graph: a set of edges of the graph, none of
+which is a self-loop, no two of which share a node. An edge `(u, v)` of an undirected graph can
+be given as `(u, v)` or `(v, u)`.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `matching`: the edges to check; a `NodeNotFound` error is returned if one of their nodes
+  isn't in the graph
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, generators};
+let graph = generators::social::karate_club_graph();
+assert!(matching::is_matching(&graph, &vec![(0, 1), (33, 32)].into_iter().collect()).unwrap());
+assert!(!matching::is_matching(&graph, &vec![(0, 1), (0, 2)].into_iter().collect()).unwrap());
+assert!(!matching::is_matching(&graph, &vec![(0, 33)].into_iter().collect()).unwrap());
+```
+*/
+pub fn is_matching<T, A>(graph: &Graph<T, A>, matching: &HashSet<(T, T)>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(get_matched_nodes(graph, matching)?.is_some())
+}
+
+/**
+Returns `true` if `matching` is a maximal matching of the graph: a [matching](./fn.is_matching.html)
+to which no other edge of the graph can be added.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance
+* `matching`: the edges to check; a `NodeNotFound` error is returned if one of their nodes
+  isn't in the graph
+
+# Examples
+
+```
+use graphrs::{algorithms::matching, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3)];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(matching::is_maximal_matching(&graph, &vec![(1, 2)].into_iter().collect()).unwrap());
+assert!(!matching::is_maximal_matching(&graph, &vec![(0, 1)].into_iter().collect()).unwrap());
+```
+*/
+pub fn is_maximal_matching<T, A>(
+    graph: &Graph<T, A>,
+    matching: &HashSet<(T, T)>,
+) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let matched = match get_matched_nodes(graph, matching)? {
+        None => return Ok(false),
+        Some(matched) => matched,
+    };
+    Ok((0..graph.number_of_nodes()).all(|u| {
+        matched[u]
+            || graph
+                .get_successor_nodes_by_index(&u)
+                .iter()
+                .all(|adj| adj.node_index == u || matched[adj.node_index])
+    }))
+}
+
+/**
+Returns which nodes are matched if `matching` is a matching of the graph, or `None` if it isn't.
+*/
+fn get_matched_nodes<T, A>(
+    graph: &Graph<T, A>,
+    matching: &HashSet<(T, T)>,
+) -> Result<Option<Vec<bool>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut matched = vec![false; graph.number_of_nodes()];
+    for (u, v) in matching {
+        let u = graph.get_node_index(u)?;
+        let v = graph.get_node_index(v)?;
+        if u == v || matched[u] || matched[v] {
+            return Ok(None);
+        }
+        let is_edge = graph
+            .get_successor_nodes_by_index(&u)
+            .iter()
+            .any(|adj| adj.node_index == v);
+        if !is_edge {
+            return Ok(None);
+        }
+        matched[u] = true;
+        matched[v] = true;
+    }
+    Ok(Some(matched))
+}
@@ -0,0 +1,4 @@
+mod max_weight;
+pub use max_weight::*;
+mod maximal;
+pub use maximal::*;
@@ -1,21 +1,46 @@
+/// Find and analyze bipartite graphs: two-colorings, matchings, projections, centrality and
+/// clustering.
+pub mod bipartite;
+
 /// Functions to find the boundary of a set of nodes.
 pub mod boundary;
 
 /// Compute the centrality of nodes and edges in the graph.
 pub mod centrality;
 
+/// Find maximal cliques, all cliques and maximum weight cliques.
+pub mod clique;
+
 /// Compute clustering metrics.
 pub mod cluster;
 
+/// Color the nodes of a graph so that adjacent nodes have different colors.
+pub mod coloring;
+
 /// Find community sub-structure.
 pub mod community;
 
 /// Find components of a graph.
 pub mod components;
 
+/// Compute node and edge connectivity, minimum node and edge cuts and disjoint paths.
+pub mod connectivity;
+
+/// Find the k-cores, k-shells, k-trusses and onion layers of a graph.
+pub mod core;
+
 /// Functions for finding and evaluating cuts in a graph.
 pub mod cuts;
 
+/// Find cycles and cycle bases of a graph.
+pub mod cycles;
+
+/// Compute maximum flows, minimum cuts and minimum cost flows in flow networks.
+pub mod flow;
+
+/// Find maximum weight, maximum cardinality and maximal matchings.
+pub mod matching;
+
 /// Compute resiliency measures of a graph.
 pub mod resiliency;
 
@@ -24,3 +49,6 @@ pub mod structural_holes;
 
 /// Compute the shortest paths and path lengths between nodes in the graph.
 pub mod shortest_path;
+
+/// Find spanning trees and work with trees and forests.
+pub mod tree;
@@ -30,8 +30,9 @@ where
     T: Hash + Eq + Clone + Ord + Debug + Display + Send + Sync,
     A: Clone + Send + Sync,
 {
-    let centralities =
-        algorithms::centrality::betweenness::betweenness_centrality(graph, weighted, false)?;
+    let centralities = algorithms::centrality::betweenness::betweenness_centrality(
+        graph, weighted, false, None, None,
+    )?;
     Ok(centralities.iter().map(|(_, v)| v).sum::<f64>() / graph.number_of_nodes() as f64)
 }
 
@@ -0,0 +1,478 @@
+use crate::{Edge, Error, ErrorKind, Graph};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/**
+Returns a minimum spanning arborescence of a directed graph: a spanning tree whose edges all
+point away from a root node, and whose sum of edge weights is as small as possible. Every node
+other than the root has exactly one incoming edge.
+
+The arborescence is found with Edmonds' (Chu-Liu) algorithm, which repeatedly picks the
+lightest incoming edge of each node and contracts the cycles that form; O(nm).
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance. An unweighted edge (one with
+  a weight of `f64::NAN`) has a weight of 1 and self-loops are ignored.
+* `root`: the node the arborescence must be rooted at; if `None` the root is chosen to
+  minimize the weight
+
+# Returns
+
+A graph with the same nodes and specs as `graph`, containing the edges of the arborescence.
+An `ErrorKind::Infeasible` error is returned if no spanning arborescence exists, because
+no node (or not `root`) can reach every other node.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("a", "b", 2.0),
+    Edge::with_weight("a", "c", 5.0),
+    Edge::with_weight("b", "c", 1.0),
+    Edge::with_weight("c", "b", 1.0),
+    Edge::with_weight("c", "a", 9.0),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let arborescence = tree::minimum_spanning_arborescence(&graph, None).unwrap();
+let weight: f64 = arborescence.get_all_edges().iter().map(|e| e.weight).sum();
+assert_eq!(weight, 3.0);
+let arborescence = tree::minimum_spanning_arborescence(&graph, Some("c")).unwrap();
+let weight: f64 = arborescence.get_all_edges().iter().map(|e| e.weight).sum();
+assert_eq!(weight, 10.0);
+```
+
+# References
+
+1. J. Edmonds: Optimum branchings. Journal of Research of the National Bureau of Standards
+   71B(4):233–240, 1967.
+2. Y. J. Chu and T. H. Liu: On the shortest arborescence of a directed graph. Scientia Sinica
+   14:1396–1400, 1965.
+*/
+pub fn minimum_spanning_arborescence<T, A>(
+    graph: &Graph<T, A>,
+    root: Option<T>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    spanning_arborescence(graph, root, false)
+}
+
+/**
+Returns a maximum spanning arborescence of a directed graph: a spanning tree whose edges all
+point away from a root node, and whose sum of edge weights is as large as possible.
+See [minimum_spanning_arborescence](./fn.minimum_spanning_arborescence.html).
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance. An unweighted edge (one with
+  a weight of `f64::NAN`) has a weight of 1 and self-loops are ignored.
+* `root`: the node the arborescence must be rooted at; if `None` the root is chosen to
+  maximize the weight
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("a", "b", 2.0),
+    Edge::with_weight("a", "c", 5.0),
+    Edge::with_weight("b", "c", 1.0),
+    Edge::with_weight("c", "b", 1.0),
+    Edge::with_weight("c", "a", 9.0),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let arborescence = tree::maximum_spanning_arborescence(&graph, None).unwrap();
+let weight: f64 = arborescence.get_all_edges().iter().map(|e| e.weight).sum();
+assert_eq!(weight, 11.0);
+```
+*/
+pub fn maximum_spanning_arborescence<T, A>(
+    graph: &Graph<T, A>,
+    root: Option<T>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    spanning_arborescence(graph, root, true)
+}
+
+/**
+Returns a minimum branching of a directed graph: a set of edges in which every node has at
+most one incoming edge and there are no cycles, and whose sum of edge weights is as small as
+possible. A minimum branching only contains edges with negative weights, so it is empty if
+all weights are positive.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance. An unweighted edge (one with
+  a weight of `f64::NAN`) has a weight of 1 and self-loops are ignored.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("a", "b", -2.0),
+    Edge::with_weight("b", "a", -3.0),
+    Edge::with_weight("b", "c", 4.0),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let branching = tree::minimum_branching(&graph).unwrap();
+assert_eq!(branching.number_of_edges(), 1);
+assert_eq!(branching.get_all_edges()[0].weight, -3.0);
+```
+*/
+pub fn minimum_branching<T, A>(graph: &Graph<T, A>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    branching(graph, false)
+}
+
+/**
+Returns a maximum branching of a directed graph: a set of edges in which every node has at
+most one incoming edge and there are no cycles, and whose sum of edge weights is as large as
+possible. A maximum branching only contains edges with positive weights.
+
+# Arguments
+
+* `graph`: a directed [Graph](../../struct.Graph.html) instance. An unweighted edge (one with
+  a weight of `f64::NAN`) has a weight of 1 and self-loops are ignored.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![
+    Edge::with_weight("a", "b", 2.0),
+    Edge::with_weight("b", "a", 3.0),
+    Edge::with_weight("b", "c", -4.0),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let branching = tree::maximum_branching(&graph).unwrap();
+assert_eq!(branching.number_of_edges(), 1);
+assert_eq!(branching.get_all_edges()[0].weight, 3.0);
+```
+*/
+pub fn maximum_branching<T, A>(graph: &Graph<T, A>) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    branching(graph, true)
+}
+
+/// An edge of the graph as `(u, v, cost)`; the algorithm finds the smallest total cost.
+type CostEdge = (usize, usize, f64);
+
+fn spanning_arborescence<T, A>(
+    graph: &Graph<T, A>,
+    root: Option<T>,
+    maximum: bool,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let n = graph.number_of_nodes();
+    let (all_edges, mut cost_edges) = get_cost_edges(graph, |weight| match maximum {
+        true => Some(-weight),
+        false => Some(weight),
+    });
+    let tree_edges = match root {
+        Some(root) => {
+            let r = graph.get_node_index(&root)?;
+            let reachable = get_reachable(graph, r, true);
+            if let Some(v) = reachable.iter().position(|reached| !reached) {
+                return Err(Error {
+                    kind: ErrorKind::Infeasible,
+                    message: format!(
+                        "No spanning arborescence rooted at {} exists: node {} can't be reached from it.",
+                        root,
+                        graph.get_node_by_index(&v).unwrap().name
+                    ),
+                });
+            }
+            edmonds(n, r, &cost_edges)
+        }
+        None if n == 0 => vec![],
+        None => {
+            let roots = get_possible_roots(graph).ok_or_else(|| Error {
+                kind: ErrorKind::Infeasible,
+                message: "No spanning arborescence exists: no node can reach every other node."
+                    .to_string(),
+            })?;
+            // a virtual root with an edge to each possible root; the edges are so expensive
+            // that exactly one of them is in the minimum arborescence
+            let total: f64 = cost_edges.iter().map(|(_, _, cost)| cost.abs()).sum();
+            let virtual_cost = 2.0 * total + 1.0;
+            let m = cost_edges.len();
+            cost_edges.extend(roots.into_iter().map(|v| (n, v, virtual_cost)));
+            let mut tree_edges = edmonds(n + 1, n, &cost_edges);
+            tree_edges.retain(|e| *e < m);
+            tree_edges
+        }
+    };
+    to_graph(graph, &all_edges, tree_edges)
+}
+
+fn branching<T, A>(graph: &Graph<T, A>, maximum: bool) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    let n = graph.number_of_nodes();
+    // only edges that lower the cost can be in an optimum branching
+    let (all_edges, mut cost_edges) = get_cost_edges(graph, |weight| match maximum {
+        true if weight > 0.0 => Some(-weight),
+        false if weight < 0.0 => Some(weight),
+        _ => None,
+    });
+    // a virtual root with a free edge to every node; the roots of the branching's trees are
+    // the nodes whose incoming edge is a virtual edge
+    let m = cost_edges.len();
+    cost_edges.extend((0..n).map(|v| (n, v, 0.0)));
+    let mut tree_edges = edmonds(n + 1, n, &cost_edges);
+    tree_edges.retain(|e| *e < m);
+    to_graph(graph, &all_edges, tree_edges)
+}
+
+/**
+Returns the graph's edges, without self-loops, along with their costs. `get_cost` maps an
+edge weight to a cost, or to `None` to leave the edge out.
+*/
+#[allow(clippy::type_complexity)]
+fn get_cost_edges<T, A>(
+    graph: &Graph<T, A>,
+    get_cost: impl Fn(f64) -> Option<f64>,
+) -> (Vec<&Arc<Edge<T, A>>>, Vec<CostEdge>)
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph
+        .get_all_edges()
+        .into_iter()
+        .filter(|e| e.u != e.v)
+        .filter_map(|e| {
+            let weight = match e.weight.is_nan() {
+                true => 1.0,
+                false => e.weight,
+            };
+            let u = graph.get_node_index(&e.u).unwrap();
+            let v = graph.get_node_index(&e.v).unwrap();
+            get_cost(weight).map(|cost| (e, (u, v, cost)))
+        })
+        .unzip()
+}
+
+fn to_graph<T, A>(
+    graph: &Graph<T, A>,
+    all_edges: &[&Arc<Edge<T, A>>],
+    tree_edges: Vec<usize>,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let edges = tree_edges
+        .into_iter()
+        .map(|i| all_edges[i].clone())
+        .collect();
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
+
+/// Returns which nodes can be reached from `source`, following edges forwards or backwards.
+fn get_reachable<T, A>(graph: &Graph<T, A>, source: usize, forwards: bool) -> Vec<bool>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let mut reachable = vec![false; graph.number_of_nodes()];
+    reachable[source] = true;
+    let mut queue = VecDeque::from([source]);
+    while let Some(u) = queue.pop_front() {
+        let adjacent = match forwards {
+            true => graph.get_successor_nodes_by_index(&u),
+            false => graph.get_predecessor_nodes_by_index(&u),
+        };
+        for adj in adjacent {
+            if !reachable[adj.node_index] {
+                reachable[adj.node_index] = true;
+                queue.push_back(adj.node_index);
+            }
+        }
+    }
+    reachable
+}
+
+/**
+Returns the nodes that can reach every other node, or `None` if there are none. Only the node
+that starts the last tree of a depth-first search of the whole graph can be such a node; if it
+is, the nodes that can reach it are the others.
+*/
+fn get_possible_roots<T, A>(graph: &Graph<T, A>) -> Option<Vec<usize>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    let mut visited = vec![false; n];
+    let mut last_start = 0;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        last_start = start;
+        visited[start] = true;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for adj in graph.get_successor_nodes_by_index(&u) {
+                if !visited[adj.node_index] {
+                    visited[adj.node_index] = true;
+                    stack.push(adj.node_index);
+                }
+            }
+        }
+    }
+    if get_reachable(graph, last_start, true).contains(&false) {
+        return None;
+    }
+    let roots = get_reachable(graph, last_start, false);
+    Some((0..n).filter(|v| roots[*v]).collect())
+}
+
+/// A graph in which some cycles of cheapest incoming edges were contracted to single nodes.
+struct Contraction {
+    /// The edges before the contraction.
+    edges: Vec<CostEdge>,
+    /// The cheapest incoming edge of each node before the contraction.
+    cheapest: Vec<Option<usize>>,
+    /// For each node before the contraction, whether it was on a contracted cycle.
+    on_cycle: Vec<bool>,
+    /// The node that each node before the contraction became.
+    component: Vec<usize>,
+    /// The edge before the contraction that each edge after the contraction came from.
+    original: Vec<usize>,
+}
+
+/**
+Finds a minimum cost arborescence rooted at `root` with Edmonds' algorithm, returning the
+indexes of its edges. Every node must be reachable from `root`.
+
+Each node other than the root picks its cheapest incoming edge. If those edges don't form a
+cycle they are an optimum arborescence; otherwise every cycle is contracted to a single node,
+the cost of each edge entering a cycle is reduced by the cost of the cycle edge it would
+replace, and the algorithm is repeated on the smaller graph. The arborescence of the smaller
+graph is then expanded: the edge entering each cycle replaces one of the cycle's edges.
+*/
+fn edmonds(n: usize, root: usize, edges: &[CostEdge]) -> Vec<usize> {
+    let mut contractions: Vec<Contraction> = vec![];
+    let (mut n, mut root, mut edges) = (n, root, edges.to_vec());
+    let mut tree_edges = loop {
+        let mut cheapest: Vec<Option<usize>> = vec![None; n];
+        for (i, (u, v, cost)) in edges.iter().enumerate() {
+            if u == v || *v == root {
+                continue;
+            }
+            let is_cheaper = match cheapest[*v] {
+                None => true,
+                Some(c) => cost.total_cmp(&edges[c].2).is_lt(),
+            };
+            if is_cheaper {
+                cheapest[*v] = Some(i);
+            }
+        }
+        // follow the cheapest incoming edges backwards from each node to find the cycles
+        let mut component: Vec<Option<usize>> = vec![None; n];
+        let mut on_cycle = vec![false; n];
+        let mut visited_from: Vec<Option<usize>> = vec![None; n];
+        let mut number_of_components = 0;
+        for start in 0..n {
+            let mut v = start;
+            while visited_from[v].is_none() && v != root {
+                visited_from[v] = Some(start);
+                v = edges[cheapest[v].unwrap()].0;
+            }
+            if v != root && visited_from[v] == Some(start) && component[v].is_none() {
+                while component[v].is_none() {
+                    component[v] = Some(number_of_components);
+                    on_cycle[v] = true;
+                    v = edges[cheapest[v].unwrap()].0;
+                }
+                number_of_components += 1;
+            }
+        }
+        if number_of_components == 0 {
+            break cheapest.into_iter().flatten().collect::<Vec<usize>>();
+        }
+        let component: Vec<usize> = component
+            .into_iter()
+            .map(|c| {
+                c.unwrap_or_else(|| {
+                    number_of_components += 1;
+                    number_of_components - 1
+                })
+            })
+            .collect();
+        let mut contracted_edges = vec![];
+        let mut original = vec![];
+        for (i, (u, v, cost)) in edges.iter().enumerate() {
+            if component[*u] == component[*v] {
+                continue;
+            }
+            let cost = match on_cycle[*v] {
+                true => cost - edges[cheapest[*v].unwrap()].2,
+                false => *cost,
+            };
+            contracted_edges.push((component[*u], component[*v], cost));
+            original.push(i);
+        }
+        root = component[root];
+        n = number_of_components;
+        contractions.push(Contraction {
+            edges: std::mem::replace(&mut edges, contracted_edges),
+            cheapest,
+            on_cycle,
+            component,
+            original,
+        });
+    };
+    while let Some(contraction) = contractions.pop() {
+        // the node of each cycle that the arborescence enters the cycle at
+        let mut entered_at = vec![None; n];
+        tree_edges = tree_edges
+            .into_iter()
+            .map(|e| {
+                let i = contraction.original[e];
+                let v = contraction.edges[i].1;
+                if contraction.on_cycle[v] {
+                    entered_at[contraction.component[v]] = Some(v);
+                }
+                i
+            })
+            .collect();
+        for (v, on_cycle) in contraction.on_cycle.iter().enumerate() {
+            if *on_cycle && entered_at[contraction.component[v]] != Some(v) {
+                tree_edges.push(contraction.cheapest[v].unwrap());
+            }
+        }
+        n = contraction.cheapest.len();
+    }
+    tree_edges
+}
@@ -0,0 +1,8 @@
+mod branchings;
+pub use branchings::*;
+/// Find minimum and maximum spanning trees and forests.
+pub mod mst;
+mod recognition;
+pub use recognition::*;
+mod rooted;
+pub use rooted::*;
@@ -0,0 +1,335 @@
+use crate::algorithms::components::UnionFind;
+use crate::{Edge, Error, Graph, ToUndirectedCollapseEdgeWeightsStrategy};
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/**
+The algorithm to use to find a spanning tree.
+
+`Kruskal`: adds edges in order of weight, skipping edges that would make a cycle; O(m log m).
+
+`Prim`: grows a tree from a node, always adding the lightest edge that leaves the tree;
+O(m log m).
+
+`Boruvka`: in each round, adds the lightest edge that leaves each tree of the forest;
+O(m log n). The search for the lightest edges is done in parallel.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpanningTreeAlgorithm {
+    Kruskal,
+    Prim,
+    Boruvka,
+}
+
+/**
+Returns a minimum or maximum spanning tree of a graph. If the graph isn't connected a
+spanning forest is returned: a spanning tree of each connected component.
+
+A minimum spanning tree is a subgraph that contains all the nodes of the graph, is a tree, and
+has the smallest possible sum of edge weights. A maximum spanning tree has the largest
+possible sum.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance. An unweighted edge (one with a
+  weight of `f64::NAN`) has a weight of 1. Self-loops are ignored and for multiple edges
+  between two nodes only the lightest (heaviest if `maximum` is `true`) can be in the tree.
+  A directed graph is first converted to an undirected graph, using
+  [to_undirected](../../../struct.Graph.html#method.to_undirected) to keep the lightest
+  (heaviest) of the edges between two nodes; edge attributes are lost in the conversion.
+* `maximum`: if `true` a maximum spanning tree is returned, otherwise a minimum spanning tree
+* `algorithm`: the [SpanningTreeAlgorithm](./enum.SpanningTreeAlgorithm.html) to use
+
+# Returns
+
+An undirected graph with the same nodes as `graph` and the edges of the spanning tree.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree::mst, generators};
+let graph = generators::social::karate_club_graph();
+let tree = mst::spanning_tree(&graph, false, mst::SpanningTreeAlgorithm::Kruskal).unwrap();
+assert_eq!(tree.number_of_nodes(), 34);
+assert_eq!(tree.number_of_edges(), 33);
+let weight: f64 = tree.get_all_edges().iter().map(|e| e.weight).sum();
+assert_eq!(weight, 68.0);
+```
+
+# References
+
+1. J. B. Kruskal: On the shortest spanning subtree of a graph and the traveling salesman
+   problem. Proceedings of the American Mathematical Society 7(1):48–50, 1956.
+2. R. C. Prim: Shortest connection networks and some generalizations. Bell System Technical
+   Journal 36(6):1389–1401, 1957.
+3. O. Borůvka: O jistém problému minimálním. Práce Moravské Přírodovědecké Společnosti
+   3:37–58, 1926.
+*/
+pub fn spanning_tree<T, A>(
+    graph: &Graph<T, A>,
+    maximum: bool,
+    algorithm: SpanningTreeAlgorithm,
+) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let undirected;
+    let graph = match graph.specs.directed {
+        false => graph,
+        true => {
+            undirected = to_undirected(graph, maximum)?;
+            &undirected
+        }
+    };
+    let edges = get_spanning_edges(graph, maximum, algorithm);
+    let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, graph.specs.clone())
+}
+
+/**
+Returns the edges of a minimum or maximum spanning tree (or forest) of a graph. See
+[spanning_tree](./fn.spanning_tree.html) for how the graph's edges are treated.
+
+For `SpanningTreeAlgorithm::Kruskal` the edges are in the order they were added to the tree:
+lightest first for a minimum spanning tree and heaviest first for a maximum spanning tree.
+
+# Arguments
+
+* `graph`: a [Graph](../../../struct.Graph.html) instance
+* `maximum`: if `true` the edges of a maximum spanning tree are returned, otherwise the
+  edges of a minimum spanning tree
+* `algorithm`: the [SpanningTreeAlgorithm](./enum.SpanningTreeAlgorithm.html) to use
+
+# Examples
+
+```
+use graphrs::{algorithms::tree::mst, generators};
+let graph = generators::social::karate_club_graph();
+let edges = mst::spanning_edges(&graph, true, mst::SpanningTreeAlgorithm::Boruvka).unwrap();
+let weight: f64 = edges.map(|e| e.weight).sum();
+assert_eq!(weight, 120.0);
+```
+*/
+pub fn spanning_edges<T, A>(
+    graph: &Graph<T, A>,
+    maximum: bool,
+    algorithm: SpanningTreeAlgorithm,
+) -> Result<impl Iterator<Item = Arc<Edge<T, A>>>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let edges = match graph.specs.directed {
+        false => get_spanning_edges(graph, maximum, algorithm),
+        true => get_spanning_edges(&to_undirected(graph, maximum)?, maximum, algorithm),
+    };
+    Ok(edges.into_iter())
+}
+
+/**
+An edge of the graph as `(u, v, key)`, where `key` is the weight, negated for a maximum
+spanning tree so that every algorithm looks for the smallest keys.
+*/
+type KeyedEdge = (usize, usize, f64);
+
+fn get_spanning_edges<T, A>(
+    graph: &Graph<T, A>,
+    maximum: bool,
+    algorithm: SpanningTreeAlgorithm,
+) -> Vec<Arc<Edge<T, A>>>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let all_edges: Vec<&Arc<Edge<T, A>>> = graph
+        .get_all_edges()
+        .into_iter()
+        .filter(|e| e.u != e.v)
+        .collect();
+    let keyed_edges: Vec<KeyedEdge> = all_edges
+        .iter()
+        .map(|e| {
+            let weight = match e.weight.is_nan() {
+                true => 1.0,
+                false => e.weight,
+            };
+            let key = match maximum {
+                true => -weight,
+                false => weight,
+            };
+            let u = graph.get_node_index(&e.u).unwrap();
+            let v = graph.get_node_index(&e.v).unwrap();
+            (u, v, key)
+        })
+        .collect();
+    let n = graph.number_of_nodes();
+    let tree_edges = match algorithm {
+        SpanningTreeAlgorithm::Kruskal => kruskal(n, &keyed_edges),
+        SpanningTreeAlgorithm::Prim => prim(n, &keyed_edges),
+        SpanningTreeAlgorithm::Boruvka => boruvka(n, &keyed_edges),
+    };
+    tree_edges
+        .into_iter()
+        .map(|i| all_edges[i].clone())
+        .collect()
+}
+
+fn to_undirected<T, A>(graph: &Graph<T, A>, maximum: bool) -> Result<Graph<T, A>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let strategy = match (graph.edges_have_weight(), maximum) {
+        (false, _) => None,
+        (true, false) => Some(ToUndirectedCollapseEdgeWeightsStrategy::Min),
+        (true, true) => Some(ToUndirectedCollapseEdgeWeightsStrategy::Max),
+    };
+    graph.to_undirected(strategy)
+}
+
+/**
+Compares two edges by key, breaking ties by index so that the order is total and every
+algorithm finds the same tree.
+*/
+#[inline]
+fn compare_edges(edges: &[KeyedEdge], a: usize, b: usize) -> Ordering {
+    edges[a].2.total_cmp(&edges[b].2).then(a.cmp(&b))
+}
+
+fn kruskal(n: usize, edges: &[KeyedEdge]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|a, b| compare_edges(edges, *a, *b));
+    let mut union_find = UnionFind::new(n);
+    order
+        .into_iter()
+        .filter(|i| union_find.union(edges[*i].0, edges[*i].1))
+        .collect()
+}
+
+/// An entry in Prim's priority queue; the edge with the smallest key has the highest priority.
+struct PrimEntry {
+    key: f64,
+    edge: usize,
+    node: usize,
+}
+
+impl Ord for PrimEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .key
+            .total_cmp(&self.key)
+            .then(other.edge.cmp(&self.edge))
+    }
+}
+
+impl PartialOrd for PrimEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PrimEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PrimEntry {}
+
+fn prim(n: usize, edges: &[KeyedEdge]) -> Vec<usize> {
+    let mut incident: Vec<Vec<usize>> = vec![vec![]; n];
+    for (i, (u, v, _)) in edges.iter().enumerate() {
+        incident[*u].push(i);
+        incident[*v].push(i);
+    }
+    let push_incident = |heap: &mut BinaryHeap<PrimEntry>, in_tree: &[bool], node: usize| {
+        for i in incident[node].iter() {
+            let (u, v, key) = edges[*i];
+            let other = if u == node { v } else { u };
+            if !in_tree[other] {
+                heap.push(PrimEntry {
+                    key,
+                    edge: *i,
+                    node: other,
+                });
+            }
+        }
+    };
+    let mut in_tree = vec![false; n];
+    let mut tree_edges = vec![];
+    for start in 0..n {
+        if in_tree[start] {
+            continue;
+        }
+        in_tree[start] = true;
+        let mut heap = BinaryHeap::new();
+        push_incident(&mut heap, &in_tree, start);
+        while let Some(entry) = heap.pop() {
+            if in_tree[entry.node] {
+                continue;
+            }
+            in_tree[entry.node] = true;
+            tree_edges.push(entry.edge);
+            push_incident(&mut heap, &in_tree, entry.node);
+        }
+    }
+    tree_edges
+}
+
+fn boruvka(n: usize, edges: &[KeyedEdge]) -> Vec<usize> {
+    let mut union_find = UnionFind::new(n);
+    let mut tree_edges = vec![];
+    loop {
+        let component: Vec<usize> = (0..n).map(|i| union_find.find(i)).collect();
+        // the lightest edge leaving each component
+        let is_lighter = |a: usize, b: Option<usize>| match b {
+            None => true,
+            Some(b) => compare_edges(edges, a, b) == Ordering::Less,
+        };
+        let lightest = edges
+            .par_iter()
+            .enumerate()
+            .fold(
+                || vec![None; n],
+                |mut lightest: Vec<Option<usize>>, (i, (u, v, _))| {
+                    let (cu, cv) = (component[*u], component[*v]);
+                    if cu != cv {
+                        for c in [cu, cv] {
+                            if is_lighter(i, lightest[c]) {
+                                lightest[c] = Some(i);
+                            }
+                        }
+                    }
+                    lightest
+                },
+            )
+            .reduce(
+                || vec![None; n],
+                |mut a, b| {
+                    for (c, edge) in b.into_iter().enumerate() {
+                        if let Some(edge) = edge {
+                            if is_lighter(edge, a[c]) {
+                                a[c] = Some(edge);
+                            }
+                        }
+                    }
+                    a
+                },
+            );
+        let mut added = false;
+        for i in lightest.into_iter().flatten() {
+            if union_find.union(edges[i].0, edges[i].1) {
+                tree_edges.push(i);
+                added = true;
+            }
+        }
+        if !added {
+            return tree_edges;
+        }
+    }
+}
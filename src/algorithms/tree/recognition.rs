@@ -0,0 +1,154 @@
+use crate::algorithms::components::UnionFind;
+use crate::{Error, ErrorKind, Graph};
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+Returns `true` if the graph is a tree: it's connected and has no cycles. For a directed graph
+the direction of the edges is ignored. Multiple edges between two nodes and self-loops are
+cycles.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must have at least one node
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(1, 3)];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(tree::is_tree(&graph).unwrap());
+```
+*/
+pub fn is_tree<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(get_number_of_trees(graph)? == Some(1))
+}
+
+/**
+Returns `true` if the graph is a forest: it has no cycles, so each of its connected
+components is a tree. For a directed graph the direction of the edges is ignored. Multiple
+edges between two nodes and self-loops are cycles.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must have at least one node
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+let specs = GraphSpecs::undirected_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(tree::is_forest(&graph).unwrap());
+assert!(!tree::is_tree(&graph).unwrap());
+```
+*/
+pub fn is_forest<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    Ok(get_number_of_trees(graph)?.is_some())
+}
+
+/**
+Returns `true` if a directed graph is an arborescence: a tree in which every node has at most
+one incoming edge, so that all edges point away from a single root.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed and have at
+  least one node
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(0, 2), Edge::new(2, 3)];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(tree::is_arborescence(&graph).unwrap());
+let edges = vec![Edge::new(0, 1), Edge::new(2, 1)];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(!tree::is_arborescence(&graph).unwrap());
+```
+*/
+pub fn is_arborescence<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    Ok(is_tree(graph)? && has_max_in_degree_one(graph))
+}
+
+/**
+Returns `true` if a directed graph is a branching: a forest in which every node has at most
+one incoming edge, so that each of its trees is an arborescence.
+
+# Arguments
+
+* `graph`: a [Graph](../../struct.Graph.html) instance that must be directed and have at
+  least one node
+
+# Examples
+
+```
+use graphrs::{algorithms::tree, Edge, Graph, GraphSpecs};
+let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+assert!(tree::is_branching(&graph).unwrap());
+assert!(!tree::is_arborescence(&graph).unwrap());
+```
+*/
+pub fn is_branching<T, A>(graph: &Graph<T, A>) -> Result<bool, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    graph.ensure_directed()?;
+    Ok(is_forest(graph)? && has_max_in_degree_one(graph))
+}
+
+/**
+Returns the number of trees in the graph if it's a forest, or `None` if it has a cycle.
+*/
+fn get_number_of_trees<T, A>(graph: &Graph<T, A>) -> Result<Option<usize>, Error>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    let n = graph.number_of_nodes();
+    if n == 0 {
+        return Err(Error {
+            kind: ErrorKind::InvalidArgument,
+            message: "A graph with no nodes is neither a tree nor a forest.".to_string(),
+        });
+    }
+    let mut union_find = UnionFind::new(n);
+    for edge in graph.get_all_edges() {
+        let u = graph.get_node_index(&edge.u).unwrap();
+        let v = graph.get_node_index(&edge.v).unwrap();
+        if !union_find.union(u, v) {
+            return Ok(None);
+        }
+    }
+    Ok(Some(union_find.number_of_components))
+}
+
+fn has_max_in_degree_one<T, A>(graph: &Graph<T, A>) -> bool
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+    A: Clone + Send + Sync,
+{
+    (0..graph.number_of_nodes()).all(|i| graph.get_predecessor_nodes_by_index(&i).len() <= 1)
+}
@@ -0,0 +1,338 @@
+use super::recognition::{is_arborescence, is_tree};
+use crate::{Error, ErrorKind, Graph};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/**
+A tree with a root, indexed for fast queries: the parent, children, depth and subtree size of
+each node, and the lowest common ancestor of any two nodes.
+
+The lowest common ancestor is found in constant time with a sparse table over an Euler tour of
+the tree; building the index takes O(n log n) time and space.
+
+# Examples
+
+```
+use graphrs::{algorithms::tree::RootedTree, Edge, Graph, GraphSpecs};
+// an org chart: edges point from managers to their reports
+let edges = vec![
+    Edge::new("ceo", "cto"),
+    Edge::new("ceo", "cfo"),
+    Edge::new("cto", "dev1"),
+    Edge::new("cto", "dev2"),
+    Edge::new("cfo", "accountant"),
+];
+let specs = GraphSpecs::directed_create_missing();
+let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+let tree = RootedTree::from_graph(&graph, "ceo").unwrap();
+assert_eq!(tree.lowest_common_ancestor(&"dev1", &"dev2").unwrap(), "cto");
+assert_eq!(tree.lowest_common_ancestor(&"dev1", &"accountant").unwrap(), "ceo");
+assert_eq!(tree.parent(&"dev1").unwrap(), Some("cto"));
+assert_eq!(tree.depth(&"accountant").unwrap(), 2);
+assert_eq!(tree.subtree_size(&"cto").unwrap(), 3);
+```
+
+# References
+
+1. M. A. Bender and M. Farach-Colton: The LCA problem revisited. In: LATIN 2000: Theoretical
+   Informatics, Springer, 2000.
+*/
+#[derive(Clone, Debug)]
+pub struct RootedTree<T> {
+    node_names: Vec<T>,
+    node_indexes: HashMap<T, usize>,
+    root: usize,
+    parents: Vec<Option<usize>>,
+    /// The children of each node, sorted by name.
+    children: Vec<Vec<usize>>,
+    depths: Vec<usize>,
+    subtree_sizes: Vec<usize>,
+    /// The nodes in breadth-first order from the root.
+    order: Vec<usize>,
+    /// The position of each node's first appearance in the Euler tour.
+    first_visits: Vec<usize>,
+    /// `sparse_table[k][i]` is the shallowest node in the Euler tour from position `i` to
+    /// position `i + 2^k - 1`.
+    sparse_table: Vec<Vec<usize>>,
+}
+
+/**
+A tree as a nested structure: a node and the trees rooted at each of its children.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct NestedTree<T> {
+    pub name: T,
+    pub children: Vec<NestedTree<T>>,
+}
+
+impl<T> RootedTree<T>
+where
+    T: Hash + Eq + Clone + Ord + Display + Send + Sync,
+{
+    /**
+    Creates a `RootedTree` from a [Graph](../../struct.Graph.html) that is a tree.
+
+    # Arguments
+
+    * `graph`: an undirected tree, or a directed arborescence whose edges point away from
+      `root`; an `ErrorKind::InvalidArgument` error is returned otherwise
+    * `root`: the node to root the tree at
+    */
+    pub fn from_graph<A>(graph: &Graph<T, A>, root: T) -> Result<RootedTree<T>, Error>
+    where
+        A: Clone + Send + Sync,
+    {
+        let root_index = graph.get_node_index(&root)?;
+        let is_rooted_tree = match graph.specs.directed {
+            true => {
+                is_arborescence(graph)?
+                    && graph.get_predecessor_nodes_by_index(&root_index).is_empty()
+            }
+            false => is_tree(graph)?,
+        };
+        if !is_rooted_tree {
+            let message = match graph.specs.directed {
+                true => format!("The graph is not an arborescence rooted at {}.", root),
+                false => "The graph is not a tree.".to_string(),
+            };
+            return Err(Error {
+                kind: ErrorKind::InvalidArgument,
+                message,
+            });
+        }
+        let n = graph.number_of_nodes();
+        let node_names: Vec<T> = (0..n)
+            .map(|i| graph.get_node_by_index(&i).unwrap().name.clone())
+            .collect();
+        let node_indexes = node_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), i))
+            .collect();
+        let mut parents = vec![None; n];
+        let mut children = vec![vec![]; n];
+        let mut depths = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        order.push(root_index);
+        let mut next = 0;
+        while next < order.len() {
+            let u = order[next];
+            next += 1;
+            let mut node_children: Vec<usize> = graph
+                .get_successor_nodes_by_index(&u)
+                .iter()
+                .map(|adj| adj.node_index)
+                .filter(|v| parents[u] != Some(*v))
+                .collect();
+            node_children.sort_by(|a, b| node_names[*a].cmp(&node_names[*b]));
+            for v in node_children.iter() {
+                parents[*v] = Some(u);
+                depths[*v] = depths[u] + 1;
+                order.push(*v);
+            }
+            children[u] = node_children;
+        }
+        let mut subtree_sizes = vec![1; n];
+        for v in order.iter().rev() {
+            if let Some(p) = parents[*v] {
+                subtree_sizes[p] += subtree_sizes[*v];
+            }
+        }
+        let (first_visits, sparse_table) = build_lca_index(root_index, &children, &depths);
+        Ok(RootedTree {
+            node_names,
+            node_indexes,
+            root: root_index,
+            parents,
+            children,
+            depths,
+            subtree_sizes,
+            order,
+            first_visits,
+            sparse_table,
+        })
+    }
+
+    /**
+    Returns the root of the tree.
+    */
+    pub fn root(&self) -> &T {
+        &self.node_names[self.root]
+    }
+
+    /**
+    Returns the parent of `node`, or `None` if `node` is the root.
+
+    # Arguments
+
+    * `node`: a node in the tree
+    */
+    pub fn parent(&self, node: &T) -> Result<Option<T>, Error> {
+        let index = self.get_node_index(node)?;
+        Ok(self.parents[index].map(|p| self.node_names[p].clone()))
+    }
+
+    /**
+    Returns the parent of every node; the parent of the root is `None`.
+    */
+    pub fn parents(&self) -> HashMap<T, Option<T>> {
+        self.node_names
+            .iter()
+            .zip(self.parents.iter())
+            .map(|(name, p)| (name.clone(), p.map(|p| self.node_names[p].clone())))
+            .collect()
+    }
+
+    /**
+    Returns the children of `node`, sorted.
+
+    # Arguments
+
+    * `node`: a node in the tree
+    */
+    pub fn children(&self, node: &T) -> Result<Vec<T>, Error> {
+        let index = self.get_node_index(node)?;
+        Ok(self.children[index]
+            .iter()
+            .map(|c| self.node_names[*c].clone())
+            .collect())
+    }
+
+    /**
+    Returns the depth of `node`: the number of edges between it and the root.
+
+    # Arguments
+
+    * `node`: a node in the tree
+    */
+    pub fn depth(&self, node: &T) -> Result<usize, Error> {
+        let index = self.get_node_index(node)?;
+        Ok(self.depths[index])
+    }
+
+    /**
+    Returns the number of nodes in the subtree rooted at `node`, including `node`.
+
+    # Arguments
+
+    * `node`: a node in the tree
+    */
+    pub fn subtree_size(&self, node: &T) -> Result<usize, Error> {
+        let index = self.get_node_index(node)?;
+        Ok(self.subtree_sizes[index])
+    }
+
+    /**
+    Returns the lowest common ancestor of `u` and `v`: the deepest node that has both `u` and
+    `v` in its subtree. A node is an ancestor of itself. Runs in constant time.
+
+    # Arguments
+
+    * `u`: a node in the tree
+    * `v`: another node in the tree
+    */
+    pub fn lowest_common_ancestor(&self, u: &T, v: &T) -> Result<T, Error> {
+        let u = self.first_visits[self.get_node_index(u)?];
+        let v = self.first_visits[self.get_node_index(v)?];
+        let (left, right) = (u.min(v), u.max(v));
+        let level = (right - left + 1).ilog2() as usize;
+        let a = self.sparse_table[level][left];
+        let b = self.sparse_table[level][right + 1 - (1 << level)];
+        let lca = match self.depths[a] <= self.depths[b] {
+            true => a,
+            false => b,
+        };
+        Ok(self.node_names[lca].clone())
+    }
+
+    /**
+    Returns the tree as a [NestedTree](./struct.NestedTree.html), with the children of each
+    node sorted.
+    */
+    pub fn to_nested(&self) -> NestedTree<T> {
+        // build the subtrees bottom-up so that deep trees don't need deep recursion
+        let mut subtrees: Vec<Option<NestedTree<T>>> = vec![None; self.node_names.len()];
+        for v in self.order.iter().rev() {
+            let children = self.children[*v]
+                .iter()
+                .map(|c| subtrees[*c].take().unwrap())
+                .collect();
+            subtrees[*v] = Some(NestedTree {
+                name: self.node_names[*v].clone(),
+                children,
+            });
+        }
+        subtrees[self.root].take().unwrap()
+    }
+
+    /**
+    Returns the number of nodes in the tree.
+    */
+    pub fn number_of_nodes(&self) -> usize {
+        self.node_names.len()
+    }
+
+    fn get_node_index(&self, node: &T) -> Result<usize, Error> {
+        match self.node_indexes.get(node) {
+            Some(index) => Ok(*index),
+            None => Err(Error {
+                kind: ErrorKind::NodeNotFound,
+                message: format!("Node '{}' not found.", node),
+            }),
+        }
+    }
+}
+
+/**
+Builds an Euler tour of the tree, which lists each node when the tour enters it and again
+after returning from each of its children, and a sparse table over the tour. The lowest common
+ancestor of two nodes is the shallowest node in the tour between their first visits.
+*/
+fn build_lca_index(
+    root: usize,
+    children: &[Vec<usize>],
+    depths: &[usize],
+) -> (Vec<usize>, Vec<Vec<usize>>) {
+    let n = children.len();
+    let mut tour = Vec::with_capacity(2 * n - 1);
+    let mut first_visits = vec![0; n];
+    // each entry is a node and the number of its children that have been toured
+    let mut stack = vec![(root, 0)];
+    first_visits[root] = 0;
+    tour.push(root);
+    while let Some((u, visited)) = stack.last_mut() {
+        let u = *u;
+        match children[u].get(*visited) {
+            Some(child) => {
+                *visited += 1;
+                first_visits[*child] = tour.len();
+                tour.push(*child);
+                stack.push((*child, 0));
+            }
+            None => {
+                stack.pop();
+                if let Some((parent, _)) = stack.last() {
+                    tour.push(*parent);
+                }
+            }
+        }
+    }
+    let mut sparse_table = vec![tour];
+    let mut width = 1;
+    while 2 * width <= sparse_table[0].len() {
+        let previous = sparse_table.last().unwrap();
+        let level = (0..(previous.len() - width))
+            .map(|i| {
+                let (a, b) = (previous[i], previous[i + width]);
+                match depths[a] <= depths[b] {
+                    true => a,
+                    false => b,
+                }
+            })
+            .collect();
+        sparse_table.push(level);
+        width *= 2;
+    }
+    (first_visits, sparse_table)
+}
@@ -26,6 +26,9 @@ pub enum ErrorKind {
     /// A duplicate `Edge` was added to a [Graph](./struct.Graph.html) that doesn't
     /// support multi `Edge`s.
     DuplicateEdge,
+    /// No solution satisfies the constraints of an optimization problem, such as the node
+    /// demands of a minimum cost flow.
+    Infeasible,
     /// An argument to a function was not a valid value.
     InvalidArgument,
     /// An error occurred while using the Lapack library.
@@ -52,6 +55,12 @@ pub enum ErrorKind {
     /// An [Edge](./struct.Edge.html) where `u` and `v` were the same was added to a
     /// [Graph](./struct.Graph.html) that doesn't allow self-loops.
     SelfLoopsFound,
+    /// An iterative solver of a system of linear equations failed to converge to the
+    /// required tolerance within the maximum number of iterations.
+    SolverFailedConvergence,
+    /// The solution of an optimization problem, such as a maximum flow or a minimum cost
+    /// flow, is unbounded.
+    Unbounded,
     /// A method was invoked on a [Graph](./struct.Graph.html) whose
     /// [GraphSpecs](./struct.GraphSpecs.html) are not supported by the method.
     WrongMethod,
@@ -64,6 +73,7 @@ impl Display for ErrorKind {
             ErrorKind::DuplicateEdge => write!(f, "duplicate edge detected"),
             ErrorKind::EdgeNotFound => write!(f, "edge not found"),
             ErrorKind::EdgeWeightNotSpecified => write!(f, "edge weight not found"),
+            ErrorKind::Infeasible => write!(f, "no feasible solution exists"),
             ErrorKind::InvalidArgument => write!(f, "invalid argument"),
             ErrorKind::LaPackError => write!(f, "error using Lapack library"),
             ErrorKind::NodeNotFound => write!(f, "node not found"),
@@ -72,6 +82,8 @@ impl Display for ErrorKind {
             ErrorKind::PowerIterationFailedConvergence => write!(f, "failed to converge to the specified tolerance within the specified number of iterations"),
             ErrorKind::ReadError => write!(f, "error reading graph from file"),
             ErrorKind::SelfLoopsFound => write!(f, "self loops found"),
+            ErrorKind::SolverFailedConvergence => write!(f, "linear solver failed to converge"),
+            ErrorKind::Unbounded => write!(f, "the solution is unbounded"),
             ErrorKind::WrongMethod => write!(f, "wrong method was used"),
         }
     }
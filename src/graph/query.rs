@@ -933,6 +933,18 @@ where
         &self.predecessors_vec[*node_index]
     }
 
+    /// Returns the predecessors of a node if the `graph` is directed, and its neighbors if the
+    /// `graph` is undirected.
+    pub(crate) fn get_predecessors_or_neighbors_by_index(
+        &self,
+        node_index: &usize,
+    ) -> &Vec<AdjacentNode> {
+        match self.specs.directed {
+            true => &self.predecessors_vec[*node_index],
+            false => &self.successors_vec[*node_index],
+        }
+    }
+
     pub(crate) fn get_neighbors_nodes_by_index(&self, node_index: &usize) -> IntSet<usize> {
         self.successors_vec[*node_index]
             .iter()
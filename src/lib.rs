@@ -33,14 +33,23 @@ Python bindings are available in the [graphrs-python](<https://pypi.org/project/
 
 ## Modules
 
+- `algorithms::bipartite`
 - `algorithms::boundary`
 - `algorithms::cuts`
 - `algorithms::centrality`
+- `algorithms::clique`
 - `algorithms::cluster`
+- `algorithms::coloring`
 - `algorithms::community`
 - `algorithms::components`
+- `algorithms::connectivity`
+- `algorithms::core`
+- `algorithms::cycles`
+- `algorithms::flow`
+- `algorithms::matching`
 - `algorithms::structural_holes`
 - `algorithms::shortest_path`
+- `algorithms::tree`
 - `generators`
 - `readwrite`
 
@@ -131,11 +140,11 @@ assert_eq!(shortest_paths.unwrap().get("n3").unwrap().distance, 2.1);
 ### Compute the betweenness, closeness and eigenvector centrality for all nodes
 
 ```rust
-use graphrs::{algorithms::centrality, generators};
+use graphrs::{algorithms::centrality::{self, EdgeDirection}, generators};
 let graph = generators::social::karate_club_graph();
-let centralities = centrality::betweenness::betweenness_centrality(&graph, false, true);
+let centralities = centrality::betweenness::betweenness_centrality(&graph, false, true, None, None);
 let closeness = centrality::closeness::closeness_centrality(&graph, false, true);
-let centralities = centrality::eigenvector::eigenvector_centrality(&graph, false, None, None);
+let centralities = centrality::eigenvector::eigenvector_centrality(&graph, false, EdgeDirection::Incoming, None, None);
 ```
 
 ### Detect communities within a graph
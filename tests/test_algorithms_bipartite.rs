@@ -0,0 +1,403 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{bipartite, matching},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sets_1() {
+        for (graph, top_count, bottom_count) in
+            [(get_graph(), 5, 106), (get_formula_graph(), 15, 125)]
+        {
+            assert!(bipartite::is_bipartite(&graph));
+            let (top, bottom) = bipartite::sets(&graph, None).unwrap();
+            assert_eq!(top, (0..top_count).collect());
+            assert_eq!(bottom, (100..bottom_count).collect());
+            let colors = bipartite::color(&graph).unwrap();
+            assert!(graph
+                .get_all_edges()
+                .iter()
+                .all(|e| colors[&e.u] != colors[&e.v]));
+        }
+        let graph = generators::social::karate_club_graph();
+        assert!(!bipartite::is_bipartite(&graph));
+        let result = bipartite::color(&graph);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_sets_2() {
+        // a disconnected graph needs the top nodes
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+        let specs = GraphSpecs::undirected_create_missing();
+        let mut graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        graph.add_node(Node::from_name(4));
+        let result = bipartite::sets(&graph, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let top = vec![0, 3].into_iter().collect();
+        let (top, bottom) = bipartite::sets(&graph, Some(&top)).unwrap();
+        assert_eq!(top, vec![0, 3].into_iter().collect());
+        assert_eq!(bottom, vec![1, 2, 4].into_iter().collect());
+        let colors = bipartite::color(&graph).unwrap();
+        assert_eq!(colors[&4], 0);
+        let top = vec![0, 5].into_iter().collect();
+        let result = bipartite::sets(&graph, Some(&top));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_hopcroft_karp_matching_1() {
+        // values from NetworkX
+        let graph = get_graph();
+        let result = bipartite::hopcroft_karp_matching(&graph, None).unwrap();
+        assert_eq!(result.len(), 5);
+        assert!(result.iter().all(|(u, v)| *u < 100 && *v >= 100));
+        assert!(matching::is_matching(&graph, &result).unwrap());
+        let graph = get_formula_graph();
+        let result = bipartite::hopcroft_karp_matching(&graph, None).unwrap();
+        assert_eq!(result.len(), 15);
+        assert!(result.iter().all(|(u, v)| *u < 100 && *v >= 100));
+        assert!(matching::is_matching(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_hopcroft_karp_matching_2() {
+        // compare with the blossom algorithm on random bipartite graphs
+        for seed in 0..20 {
+            let graph = get_random_graph(12, 17, 0.15, seed);
+            let top = (0..12).collect();
+            let result = bipartite::hopcroft_karp_matching(&graph, Some(&top)).unwrap();
+            assert!(matching::is_matching(&graph, &result).unwrap());
+            let expected = matching::max_cardinality_matching(&graph).unwrap();
+            assert_eq!(result.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn test_minimum_weight_full_matching_1() {
+        // the lightest full matching is 0-101, 1-104, 2-100, 3-105, 4-102
+        let graph = get_graph();
+        let result = bipartite::minimum_weight_full_matching(&graph, None, true).unwrap();
+        assert_eq!(
+            result,
+            vec![(0, 101), (1, 104), (2, 100), (3, 105), (4, 102)]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(total_weight(&graph, &result), 7.0);
+        let result = bipartite::minimum_weight_full_matching(&graph, None, false).unwrap();
+        assert_eq!(result.len(), 5);
+        // values from NetworkX
+        let graph = get_formula_graph();
+        let result = bipartite::minimum_weight_full_matching(&graph, None, true).unwrap();
+        assert_eq!(result.len(), 15);
+        assert!(matching::is_matching(&graph, &result).unwrap());
+        assert_eq!(total_weight(&graph, &result), 48.0);
+        let result = bipartite::minimum_weight_full_matching(&graph, None, false).unwrap();
+        assert_eq!(result.len(), 15);
+    }
+
+    #[test]
+    fn test_minimum_weight_full_matching_2() {
+        // compare with an exhaustive search on small graphs
+        for seed in 0..40 {
+            let graph = get_random_graph(5, 7, 0.6, seed);
+            let top: HashSet<i32> = (0..5).collect();
+            let result = bipartite::minimum_weight_full_matching(&graph, Some(&top), true);
+            let expected = get_best_by_search(&graph, 0, &mut vec![false; 7]);
+            match expected {
+                None => assert!(matches!(result.err().unwrap().kind, ErrorKind::Infeasible)),
+                Some(expected) => {
+                    let result = result.unwrap();
+                    assert_eq!(result.len(), 5);
+                    assert!(matching::is_matching(&graph, &result).unwrap());
+                    assert!((total_weight(&graph, &result) - expected).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimum_weight_full_matching_3() {
+        // the smaller side is matched even if it is the bottom side
+        let edges = vec![
+            Edge::with_weight(0, 10, -1.0),
+            Edge::with_weight(1, 10, 2.0),
+            Edge::with_weight(2, 10, 3.0),
+            Edge::with_weight(2, 11, 5.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let top = vec![0, 1, 2].into_iter().collect();
+        let result = bipartite::minimum_weight_full_matching(&graph, Some(&top), true).unwrap();
+        assert_eq!(result, vec![(0, 10), (2, 11)].into_iter().collect());
+    }
+
+    #[test]
+    fn test_projected_graph_1() {
+        // values from NetworkX
+        let graph = get_graph();
+        let top: HashSet<i32> = (0..5).collect();
+        let result = bipartite::projected_graph(&graph, &top).unwrap();
+        assert_eq!(result.number_of_nodes(), 5);
+        assert_eq!(result.number_of_edges(), 8);
+        assert!(result.get_edge(3, 4).is_ok());
+        assert!(result.get_edge(0, 3).is_err());
+        let bottom: HashSet<i32> = (100..106).collect();
+        let result = bipartite::projected_graph(&graph, &bottom).unwrap();
+        assert_eq!(result.number_of_nodes(), 6);
+        assert_eq!(result.number_of_edges(), 12);
+        let result = bipartite::weighted_projected_graph(&graph, &top, false).unwrap();
+        assert_eq!(result.number_of_edges(), 8);
+        assert_eq!(result.get_edge(0, 2).unwrap().weight, 2.0);
+        assert_eq!(result.get_edge(1, 3).unwrap().weight, 1.0);
+        assert_eq!(total_edge_weight(&result), 10.0);
+        let result = bipartite::weighted_projected_graph(&graph, &top, true).unwrap();
+        assert!((result.get_edge(0, 2).unwrap().weight - 1.0 / 3.0).abs() < 1e-9);
+        assert!((total_edge_weight(&result) - 10.0 / 6.0).abs() < 1e-9);
+        // with every node in `nodes` there are no other nodes to divide the weights by
+        let all: HashSet<i32> = (0..5).chain(100..106).collect();
+        let result = bipartite::weighted_projected_graph(&graph, &all, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = get_formula_graph();
+        let top: HashSet<i32> = (0..15).collect();
+        let result = bipartite::projected_graph(&graph, &top).unwrap();
+        assert_eq!(result.number_of_nodes(), 15);
+        assert_eq!(result.number_of_edges(), 61);
+        let bottom: HashSet<i32> = (100..125).collect();
+        let result = bipartite::projected_graph(&graph, &bottom).unwrap();
+        assert_eq!(result.number_of_nodes(), 25);
+        assert_eq!(result.number_of_edges(), 155);
+        let result = bipartite::weighted_projected_graph(&graph, &top, false).unwrap();
+        assert_eq!(result.number_of_edges(), 61);
+        assert_eq!(result.get_edge(0, 5).unwrap().weight, 5.0);
+        assert_eq!(total_edge_weight(&result), 157.0);
+        let result = bipartite::weighted_projected_graph(&graph, &top, true).unwrap();
+        assert!((total_edge_weight(&result) - 6.28).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_projected_graph_2() {
+        // values from NetworkX
+        let edges = vec![
+            Edge::new(0, 10),
+            Edge::new(10, 1),
+            Edge::new(10, 2),
+            Edge::new(1, 11),
+            Edge::new(11, 0),
+            Edge::new(3, 12),
+            Edge::new(12, 3),
+            Edge::new(2, 10),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let mut graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        graph.add_node(Node::from_name(4));
+        let nodes: HashSet<i32> = vec![0, 1, 2, 3, 4].into_iter().collect();
+        let result = bipartite::weighted_projected_graph(&graph, &nodes, false).unwrap();
+        assert!(result.specs.directed);
+        assert_eq!(result.number_of_nodes(), 5);
+        let mut edges: Vec<(i32, i32, f64)> = result
+            .get_all_edges()
+            .iter()
+            .map(|e| (e.u, e.v, e.weight))
+            .collect();
+        edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            edges,
+            vec![(0, 1, 1.0), (0, 2, 1.0), (1, 0, 1.0), (2, 1, 1.0)]
+        );
+        let nodes: HashSet<i32> = vec![0, 5].into_iter().collect();
+        let result = bipartite::projected_graph(&graph, &nodes);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_degree_centrality_1() {
+        // values from NetworkX
+        let graph = get_graph();
+        let top: HashSet<i32> = (0..5).collect();
+        let result = bipartite::degree_centrality(&graph, &top).unwrap();
+        assert_eq!(result.len(), 11);
+        assert!((result[&0] - 0.5).abs() < 1e-9);
+        assert!((result[&3] - 1.0 / 3.0).abs() < 1e-9);
+        assert!((result[&100] - 0.4).abs() < 1e-9);
+        assert!((result[&104] - 0.6).abs() < 1e-9);
+        let all: HashSet<i32> = (0..5).chain(100..106).collect();
+        let result = bipartite::degree_centrality(&graph, &all);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = get_formula_graph();
+        let top: HashSet<i32> = (0..15).collect();
+        let result = bipartite::degree_centrality(&graph, &top).unwrap();
+        assert_eq!(result.len(), 40);
+        assert!((result[&0] - 0.28).abs() < 1e-9);
+        assert!((result[&3] - 0.24).abs() < 1e-9);
+        assert!((result[&100] - 0.26666666666666666).abs() < 1e-9);
+        assert!((result[&110] - 0.3333333333333333).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clustering_1() {
+        // values from NetworkX
+        let parameters = [
+            (
+                bipartite::ClusteringMode::Dot,
+                (0.2833333333333334, 0.3958333333333333, 0.2777777777777778),
+                (0.25209358542691873, 0.3249084249084249, 0.26910859716415275),
+            ),
+            (
+                bipartite::ClusteringMode::Min,
+                (0.5, 0.625, 0.5166666666666666),
+                (0.3915343915343915, 0.48717948717948717, 0.40567821067821064),
+            ),
+            (
+                bipartite::ClusteringMode::Max,
+                (0.38888888888888884, 0.5, 0.375),
+                (
+                    0.37103174603174593,
+                    0.44999999999999996,
+                    0.37320887445887446,
+                ),
+            ),
+        ];
+        for (mode, small_expected, formula_expected) in parameters {
+            for (graph, top_count, (expected_0, expected_100, expected_average)) in [
+                (get_graph(), 5, small_expected),
+                (get_formula_graph(), 15, formula_expected),
+            ] {
+                let top: Vec<i32> = (0..top_count).collect();
+                let result = bipartite::clustering(&graph, None, mode).unwrap();
+                assert_eq!(result.len(), graph.number_of_nodes());
+                assert!((result[&0] - expected_0).abs() < 1e-9);
+                assert!((result[&100] - expected_100).abs() < 1e-9);
+                let result = bipartite::average_clustering(&graph, Some(&top), mode).unwrap();
+                assert!((result - expected_average).abs() < 1e-9);
+            }
+        }
+        let graph = get_graph();
+        let result =
+            bipartite::average_clustering(&graph, Some(&[]), bipartite::ClusteringMode::Dot);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::social::karate_club_graph();
+        let result = bipartite::clustering(&graph, None, bipartite::ClusteringMode::Dot);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    /// A weighted bipartite graph with top nodes `0..5` and bottom nodes `100..106`.
+    fn get_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::with_weight(0, 100, 3.0),
+            Edge::with_weight(0, 101, 1.0),
+            Edge::with_weight(0, 102, 4.0),
+            Edge::with_weight(1, 101, 2.0),
+            Edge::with_weight(1, 103, 5.0),
+            Edge::with_weight(1, 104, 1.0),
+            Edge::with_weight(2, 100, 2.0),
+            Edge::with_weight(2, 102, 6.0),
+            Edge::with_weight(2, 103, 3.0),
+            Edge::with_weight(2, 104, 2.0),
+            Edge::with_weight(3, 104, 4.0),
+            Edge::with_weight(3, 105, 2.0),
+            Edge::with_weight(4, 102, 1.0),
+            Edge::with_weight(4, 105, 3.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    /// A random bipartite graph with top nodes `0..top` and bottom nodes `100..100 + bottom`.
+    fn get_random_graph(top: i32, bottom: i32, p: f64, seed: u64) -> Graph<i32, ()> {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let nodes = (0..top)
+            .chain(100..100 + bottom)
+            .map(Node::from_name)
+            .collect();
+        let mut edges = vec![];
+        for u in 0..top {
+            for v in 100..100 + bottom {
+                if rng.gen::<f64>() < p {
+                    edges.push(Edge::with_weight(u, v, rng.gen_range(-5..15) as f64));
+                }
+            }
+        }
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    /// The smallest total weight of assigning top nodes `u..` to different unused bottom nodes.
+    fn get_best_by_search(graph: &Graph<i32, ()>, u: i32, used: &mut Vec<bool>) -> Option<f64> {
+        if u == 5 {
+            return Some(0.0);
+        }
+        let mut best: Option<f64> = None;
+        for v in 0..used.len() {
+            if used[v] {
+                continue;
+            }
+            if let Ok(edge) = graph.get_edge(u, 100 + v as i32) {
+                used[v] = true;
+                if let Some(rest) = get_best_by_search(graph, u + 1, used) {
+                    let total = edge.weight + rest;
+                    best = Some(best.map_or(total, |b| b.min(total)));
+                }
+                used[v] = false;
+            }
+        }
+        best
+    }
+
+    fn total_weight(graph: &Graph<i32, ()>, matching: &HashSet<(i32, i32)>) -> f64 {
+        matching
+            .iter()
+            .map(|(u, v)| graph.get_edge(*u, *v).unwrap().weight)
+            .sum()
+    }
+
+    fn total_edge_weight(graph: &Graph<i32, ()>) -> f64 {
+        graph.get_all_edges().iter().map(|e| e.weight).sum()
+    }
+
+    /// A weighted bipartite graph with top nodes `0..15` and bottom nodes `100..125`.
+    fn get_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for i in 0..15 {
+            for j in 0..25 {
+                if (i * 7 + j * 3) % 5 == 0 || (i + j) % 11 == 0 {
+                    let weight = ((i * 13 + j * 7) % 17 + 1) as f64;
+                    edges.push(Edge::with_weight(i, 100 + j, weight));
+                }
+            }
+        }
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+}
@@ -3,14 +3,18 @@ mod utility;
 #[cfg(test)]
 mod tests {
 
-    use super::utility::round;
-    use graphrs::{algorithms::centrality::betweenness, generators, Edge, Graph, GraphSpecs};
+    use super::utility::{get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::betweenness, generators, Edge, ErrorKind, Graph, GraphSpecs,
+        MissingNodeStrategy,
+    };
+    use std::collections::HashMap;
 
     #[test]
     fn test_betweenness_centrality_1() {
         // directed, weighted, not normalized
         let graph = get_graph_1(true);
-        let result = betweenness::betweenness_centrality(&graph, true, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, true, false, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &0.0);
         assert_eq!(result.get("n2").unwrap(), &0.0);
         assert_eq!(result.get("n3").unwrap(), &3.0);
@@ -22,7 +26,7 @@ mod tests {
     fn test_betweenness_centrality_2() {
         // directed, weighted, normalized
         let graph = get_graph_1(true);
-        let result = betweenness::betweenness_centrality(&graph, true, true).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, true, true, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &0.0);
         assert_eq!(result.get("n2").unwrap(), &0.0);
         assert_eq!(result.get("n3").unwrap(), &0.25);
@@ -34,7 +38,7 @@ mod tests {
     fn test_betweenness_centrality_3() {
         // directed, unweighted, not normalized
         let graph = get_graph_1(true);
-        let result = betweenness::betweenness_centrality(&graph, false, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &0.0);
         assert_eq!(result.get("n2").unwrap(), &0.5);
         assert_eq!(result.get("n3").unwrap(), &2.0);
@@ -46,7 +50,7 @@ mod tests {
     fn test_betweenness_centrality_4() {
         // directed, unweighted, normalized
         let graph = get_graph_1(true);
-        let result = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, true, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &0.0);
         assert_eq!(result.get("n2").unwrap(), &(1.0 / 24.0));
         assert_eq!(result.get("n3").unwrap(), &(1.0 / 6.0));
@@ -58,7 +62,7 @@ mod tests {
     fn test_betweenness_centrality_5() {
         // undirected, weighted, not normalized
         let graph = get_graph_1(false);
-        let result = betweenness::betweenness_centrality(&graph, true, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, true, false, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &1.0);
         assert_eq!(result.get("n2").unwrap(), &0.0);
         assert_eq!(result.get("n3").unwrap(), &3.0);
@@ -70,7 +74,7 @@ mod tests {
     fn test_betweenness_centrality_6() {
         // undirected, weighted, normalized
         let graph = get_graph_1(false);
-        let result = betweenness::betweenness_centrality(&graph, true, true).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, true, true, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &(1.0 / 6.0));
         assert_eq!(result.get("n2").unwrap(), &0.0);
         assert_eq!(result.get("n3").unwrap(), &0.5);
@@ -82,7 +86,7 @@ mod tests {
     fn test_betweenness_centrality_7() {
         // undirected, unweighted, not normalized
         let graph = get_graph_1(false);
-        let result = betweenness::betweenness_centrality(&graph, false, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &1.5);
         assert_eq!(result.get("n2").unwrap(), &(1.0 / 3.0));
         assert_eq!(result.get("n3").unwrap(), &1.5);
@@ -94,7 +98,7 @@ mod tests {
     fn test_betweenness_centrality_8() {
         // undirected, unweighted, normalized
         let graph = get_graph_1(false);
-        let result = betweenness::betweenness_centrality(&graph, false, true).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, true, None, None).unwrap();
         assert_eq!(result.get("n1").unwrap(), &0.25);
         assert_eq!(result.get("n2").unwrap(), &(1.0 / 18.0));
         assert_eq!(result.get("n3").unwrap(), &0.25);
@@ -106,7 +110,7 @@ mod tests {
     fn test_betweenness_centrality_9() {
         // karate club, unweighted, not normalized
         let graph = generators::social::karate_club_graph();
-        let result = betweenness::betweenness_centrality(&graph, false, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
         assert_eq!(round(result.get(&0).unwrap(), 2), 231.07);
         assert_eq!(round(result.get(&1).unwrap(), 2), 28.48);
         assert_eq!(round(result.get(&2).unwrap(), 2), 75.85);
@@ -147,10 +151,605 @@ mod tests {
     fn test_betweenness_centrality_10() {
         // testing a larger graph tests the parallel implementation
         let graph = generators::random::fast_gnp_random_graph(100, 0.5, true, Some(1)).unwrap();
-        let result = betweenness::betweenness_centrality(&graph, false, false).unwrap();
+        let result = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
         assert_eq!(round(result.get(&0).unwrap(), 2), 50.43);
     }
 
+    #[test]
+    fn test_betweenness_centrality_sampled_1() {
+        // sampling every node as a source gives the exact values
+        let graph = generators::social::karate_club_graph();
+        for (weighted, normalized) in [(false, true), (true, false)] {
+            let exact =
+                betweenness::betweenness_centrality(&graph, weighted, normalized, None, None)
+                    .unwrap();
+            let sampled =
+                betweenness::betweenness_centrality(&graph, weighted, normalized, Some(34), None)
+                    .unwrap();
+            for (node, value) in exact {
+                assert_eq!(round(&sampled[&node], 8), round(&value, 8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_betweenness_centrality_sampled_2() {
+        // the same seed gives the same sample
+        let graph = generators::random::fast_gnp_random_graph(100, 0.1, true, Some(1)).unwrap();
+        let result1 =
+            betweenness::betweenness_centrality(&graph, false, true, Some(30), Some(1)).unwrap();
+        let result2 =
+            betweenness::betweenness_centrality(&graph, false, true, Some(30), Some(1)).unwrap();
+        assert_eq!(result1, result2);
+        let exact = betweenness::betweenness_centrality(&graph, false, true, None, None).unwrap();
+        let total: f64 = exact.values().sum();
+        let sampled_total: f64 = result1.values().sum();
+        assert!((total - sampled_total).abs() / total < 0.1);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_sampled_3() {
+        let graph = generators::social::karate_club_graph();
+        for k in [0, 35] {
+            let result = betweenness::betweenness_centrality(&graph, false, true, Some(k), None);
+            assert!(matches!(
+                result.err().unwrap().kind,
+                ErrorKind::InvalidArgument
+            ));
+        }
+        // a single sample gives finite estimates, with zero for the sampled node
+        let result =
+            betweenness::betweenness_centrality(&graph, false, true, Some(1), Some(1)).unwrap();
+        assert!(result.values().all(|value| value.is_finite()));
+        assert!(result.values().any(|value| *value == 0.0));
+    }
+
+    #[test]
+    fn test_approximate_betweenness_centrality_1() {
+        // the estimates are within epsilon of the fraction of pairs of nodes
+        let graph = generators::social::karate_club_graph();
+        let epsilon = 0.02;
+        for weighted in [false, true] {
+            let exact =
+                betweenness::betweenness_centrality(&graph, weighted, true, None, None).unwrap();
+            let result = betweenness::approximate_betweenness_centrality(
+                &graph,
+                weighted,
+                true,
+                epsilon,
+                0.1,
+                Some(1),
+            )
+            .unwrap();
+            assert_eq!(result.len(), 34);
+            for (node, value) in exact {
+                assert!((result[&node] - value).abs() * 32.0 / 34.0 <= epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn test_approximate_betweenness_centrality_2() {
+        // directed, not normalized, with unreachable pairs
+        let graph = get_directed_graph();
+        let exact = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
+        let result1 = betweenness::approximate_betweenness_centrality(
+            &graph,
+            false,
+            false,
+            0.02,
+            0.1,
+            Some(2),
+        )
+        .unwrap();
+        let result2 = betweenness::approximate_betweenness_centrality(
+            &graph,
+            false,
+            false,
+            0.02,
+            0.1,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(result1, result2);
+        for (node, value) in exact {
+            assert!((result1[&node] - value).abs() / (10.0 * 9.0) <= 0.02);
+        }
+    }
+
+    #[test]
+    fn test_approximate_betweenness_centrality_3() {
+        let graph = generators::social::karate_club_graph();
+        for (epsilon, delta) in [(0.0, 0.1), (0.1, 1.0)] {
+            let result = betweenness::approximate_betweenness_centrality(
+                &graph, false, true, epsilon, delta, None,
+            );
+            assert!(matches!(
+                result.err().unwrap().kind,
+                ErrorKind::InvalidArgument
+            ));
+        }
+    }
+
+    #[test]
+    fn test_approximate_betweenness_centrality_4() {
+        // a weighted multigraph; the sampled paths take the lightest parallel edge, as the
+        // exact ones do, so none of them go through c
+        let edges = vec![
+            Edge::with_weight("a", "b", 5.0),
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("a", "c", 2.0),
+            Edge::with_weight("c", "b", 2.0),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_undirected()
+            },
+        )
+        .unwrap();
+        let exact = betweenness::betweenness_centrality(&graph, true, false, None, None).unwrap();
+        let result =
+            betweenness::approximate_betweenness_centrality(&graph, true, false, 0.1, 0.1, Some(1))
+                .unwrap();
+        assert_eq!(result, exact);
+        assert_eq!(result[&"c"], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_subset_1() {
+        // karate club, unweighted, not normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            betweenness::betweenness_centrality_subset(&graph, &[0, 1], &[32, 33], false, false)
+                .unwrap();
+        let expected = vec![
+            (2, 0.4166666667),
+            (8, 0.2916666667),
+            (13, 0.2916666667),
+            (19, 0.2916666667),
+            (30, 0.4166666667),
+            (31, 0.2916666667),
+        ];
+        assert_nonzero_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_subset_2() {
+        // karate club, weighted, normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = betweenness::betweenness_centrality_subset(
+            &graph,
+            &[0, 5, 16],
+            &[25, 26, 29],
+            true,
+            true,
+        )
+        .unwrap();
+        let expected = vec![
+            (0, 0.0056818182),
+            (5, 0.0014204545),
+            (6, 0.0014204545),
+            (19, 0.0056818182),
+            (24, 0.0028409091),
+            (31, 0.0028409091),
+            (33, 0.0056818182),
+        ];
+        assert_nonzero_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_subset_3() {
+        // directed, unweighted, not normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let result = betweenness::betweenness_centrality_subset(
+            &graph,
+            &[1, 3, 6],
+            &[2, 4, 8],
+            false,
+            false,
+        )
+        .unwrap();
+        let expected = vec![
+            (1, 3.0),
+            (2, 2.0),
+            (3, 1.0),
+            (4, 1.5),
+            (5, 0.5),
+            (6, 2.0),
+            (7, 3.0),
+        ];
+        assert_nonzero_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_subset_4() {
+        // all sources and all targets give the betweenness centrality
+        let graph = generators::random::fast_gnp_random_graph(60, 0.1, false, Some(1)).unwrap();
+        let nodes: Vec<i32> = (0..60).collect();
+        let result =
+            betweenness::betweenness_centrality_subset(&graph, &nodes, &nodes, true, true).unwrap();
+        let expected = betweenness::betweenness_centrality(&graph, true, true, None, None).unwrap();
+        for (node, value) in expected {
+            assert_eq!(round(&result[&node], 8), round(&value, 8));
+        }
+        let result = betweenness::betweenness_centrality_subset(&graph, &[99], &[1], true, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_betweenness_centrality_source_1() {
+        // karate club, unweighted, normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            betweenness::betweenness_centrality_source(&graph, Some(&[0, 33]), false, true)
+                .unwrap();
+        let expected = vec![
+            (0, 0.0077922078),
+            (1, 0.0014880952),
+            (2, 0.004111051),
+            (3, 0.0002570346),
+            (5, 0.0009469697),
+            (6, 0.0009469697),
+            (8, 0.0055067039),
+            (9, 0.000225469),
+            (13, 0.0056585197),
+            (19, 0.0042290464),
+            (23, 0.0004734848),
+            (25, 0.0001052189),
+            (27, 0.0008041727),
+            (28, 0.000225469),
+            (30, 0.00065386),
+            (31, 0.0082273629),
+            (32, 0.0029761905),
+            (33, 0.0046145984),
+        ];
+        assert_nonzero_values(&result, &expected);
+        let result = betweenness::betweenness_centrality_source(&graph, None, false, true).unwrap();
+        let expected =
+            betweenness::betweenness_centrality(&graph, false, true, None, None).unwrap();
+        for (node, value) in expected {
+            assert_eq!(round(&result[&node], 8), round(&value, 8));
+        }
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_subset_1() {
+        // karate club, unweighted; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            betweenness::edge_betweenness_centrality_subset(&graph, &[0], &[33], false, false)
+                .unwrap();
+        assert_eq!(result.len(), 78);
+        for edge in [
+            (0, 8),
+            (0, 13),
+            (0, 19),
+            (0, 31),
+            (8, 33),
+            (13, 33),
+            (19, 33),
+            (31, 33),
+        ] {
+            assert_eq!(result[&edge], 0.125);
+        }
+        assert_eq!(result.values().filter(|v| **v != 0.0).count(), 8);
+        let result = betweenness::edge_betweenness_centrality_subset(
+            &graph,
+            &[0, 1, 2],
+            &[24, 25, 33],
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(round(&result[&(0, 1)], 8), 0.0013369);
+        assert_eq!(round(&result[&(0, 31)], 8), 0.00349079);
+        assert_eq!(round(&result[&(2, 27)], 8), 0.00178253);
+        assert_eq!(round(&result[&(25, 31)], 8), 0.00222816);
+        assert_eq!(round(&result[&(32, 33)], 8), 0.00014854);
+        assert_eq!(result.values().filter(|v| **v != 0.0).count(), 34);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_subset_2() {
+        // directed, weighted, not normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let result = betweenness::edge_betweenness_centrality_subset(
+            &graph,
+            &[1, 3, 6],
+            &[2, 4, 8],
+            true,
+            false,
+        )
+        .unwrap();
+        let expected = vec![
+            ((1, 2), 3.0),
+            ((1, 3), 3.0),
+            ((3, 4), 4.0),
+            ((3, 5), 2.0),
+            ((4, 1), 1.0),
+            ((5, 6), 2.0),
+            ((6, 1), 2.0),
+            ((6, 7), 3.0),
+            ((7, 8), 3.0),
+        ];
+        for (edge, value) in expected.iter() {
+            assert_eq!(round(&result[edge], 8), *value);
+        }
+        assert_eq!(result.values().filter(|v| **v != 0.0).count(), 9);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_subset_3() {
+        // the dependency of `e`, which isn't a target, is split equally between `c` and `d`,
+        // although two shortest paths pass through `c` and one through `d`; values from NetworkX
+        let edges = vec![
+            Edge::new("s", "a"),
+            Edge::new("s", "b"),
+            Edge::new("a", "c"),
+            Edge::new("b", "c"),
+            Edge::new("s", "f"),
+            Edge::new("f", "d"),
+            Edge::new("c", "e"),
+            Edge::new("d", "e"),
+            Edge::new("e", "t"),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result =
+            betweenness::edge_betweenness_centrality_subset(&graph, &["s"], &["t"], false, false)
+                .unwrap();
+        let expected = vec![
+            (("s", "a"), 0.25),
+            (("s", "b"), 0.25),
+            (("s", "f"), 0.5),
+            (("a", "c"), 0.25),
+            (("b", "c"), 0.25),
+            (("c", "e"), 0.5),
+            (("f", "d"), 0.5),
+            (("d", "e"), 0.5),
+            (("e", "t"), 1.0),
+        ];
+        assert_eq!(result.len(), expected.len());
+        for (edge, value) in expected.iter() {
+            assert_eq!(result[edge], *value);
+        }
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_1() {
+        // karate club, unweighted, normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+        assert_eq!(result.len(), 78);
+        assert_eq!(round(&result[&(0, 1)], 8), 0.02525253);
+        assert_eq!(round(&result[&(0, 31)], 8), 0.12725999);
+        assert_eq!(round(&result[&(2, 32)], 8), 0.06898679);
+        assert_eq!(round(&result[&(32, 33)], 8), 0.00822511);
+        assert_eq!(round(&result[&(13, 33)], 8), 0.0678239);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_2() {
+        // karate club, weighted, not normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = betweenness::edge_betweenness_centrality(&graph, true, false).unwrap();
+        assert_eq!(round(&result[&(0, 1)], 8), 0.0);
+        assert_eq!(round(&result[&(0, 31)], 8), 62.53333333);
+        assert_eq!(round(&result[&(2, 32)], 8), 22.53333333);
+        assert_eq!(round(&result[&(32, 33)], 8), 0.0);
+        assert_eq!(round(&result[&(13, 33)], 8), 12.7);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_3() {
+        // directed, unweighted, not normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let result = betweenness::edge_betweenness_centrality(&graph, false, false).unwrap();
+        assert_eq!(result.len(), 21);
+        let expected = vec![
+            ((0, 1), 2.3333333333),
+            ((0, 2), 6.5),
+            ((0, 3), 5.0),
+            ((1, 2), 16.0),
+            ((1, 3), 4.5),
+            ((2, 0), 8.3333333333),
+            ((2, 4), 4.3333333333),
+            ((2, 6), 9.8333333333),
+            ((3, 4), 9.5),
+            ((3, 5), 4.5),
+            ((4, 1), 6.8333333333),
+            ((4, 5), 2.5),
+            ((4, 6), 4.5),
+            ((5, 3), 4.5),
+            ((5, 6), 12.0),
+            ((6, 1), 11.3333333333),
+            ((6, 7), 15.0),
+            ((7, 5), 9.5),
+            ((7, 8), 9.0),
+            ((9, 0), 5.5),
+            ((9, 7), 3.5),
+        ];
+        for (edge, value) in expected {
+            assert_eq!(round(&result[&edge], 8), round(&value, 8));
+        }
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_4() {
+        // directed, weighted, normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let result = betweenness::edge_betweenness_centrality(&graph, true, true).unwrap();
+        let expected = vec![
+            ((0, 1), 0.05),
+            ((0, 2), 0.0740740741),
+            ((0, 3), 0.0277777778),
+            ((1, 2), 0.1333333333),
+            ((1, 3), 0.1611111111),
+            ((2, 0), 0.0777777778),
+            ((2, 4), 0.0185185185),
+            ((2, 6), 0.1111111111),
+            ((3, 4), 0.1037037037),
+            ((3, 5), 0.1296296296),
+            ((4, 1), 0.0777777778),
+            ((4, 5), 0.0277777778),
+            ((4, 6), 0.0166666667),
+            ((5, 3), 0.0444444444),
+            ((5, 6), 0.1944444444),
+            ((6, 1), 0.1666666667),
+            ((6, 7), 0.1555555556),
+            ((7, 5), 0.0814814815),
+            ((7, 8), 0.1),
+            ((9, 0), 0.0740740741),
+            ((9, 7), 0.0259259259),
+        ];
+        for (edge, value) in expected {
+            assert_eq!(round(&result[&edge], 8), round(&value, 8));
+        }
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_5() {
+        // undirected; keys are ordered and parallel edges share a key
+        let edges = vec![
+            Edge::new("n2", "n1"),
+            Edge::new("n1", "n2"),
+            Edge::new("n3", "n2"),
+            Edge::new("n3", "n4"),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_undirected()
+            },
+        )
+        .unwrap();
+        let result = betweenness::edge_betweenness_centrality(&graph, false, false).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[&("n1", "n2")], 3.0);
+        assert_eq!(result[&("n2", "n3")], 4.0);
+        assert_eq!(result[&("n3", "n4")], 3.0);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_6() {
+        // testing a larger graph tests the parallel implementation
+        let graph = generators::random::fast_gnp_random_graph(100, 0.1, false, Some(1)).unwrap();
+        let parallel = betweenness::edge_betweenness_centrality(&graph, false, false).unwrap();
+        assert_eq!(parallel.len(), graph.number_of_edges());
+        // each node's edge betweenness sums to its node betweenness plus n - 1
+        let nodes = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
+        for (node, value) in nodes {
+            let edges_sum: f64 = parallel
+                .iter()
+                .filter(|((u, v), _)| *u == node || *v == node)
+                .map(|(_, c)| c)
+                .sum();
+            assert_eq!(round(&(2.0 * value + 99.0), 6), round(&edges_sum, 6));
+        }
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_multigraph_1() {
+        // parallel edges split their pair's value; only the lightest are on weighted paths
+        let edges = vec![
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("a", "b", 3.0),
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("b", "c", 1.0),
+            Edge::with_weight("a", "c", 5.0),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_directed()
+            },
+        )
+        .unwrap();
+        let result =
+            betweenness::edge_betweenness_centrality_multigraph(&graph, false, false).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(round(&result[&("a", "b", 0)], 8), round(&(1.0 / 3.0), 8));
+        assert_eq!(round(&result[&("a", "b", 1)], 8), round(&(1.0 / 3.0), 8));
+        assert_eq!(round(&result[&("a", "b", 2)], 8), round(&(1.0 / 3.0), 8));
+        assert_eq!(result[&("b", "c", 0)], 1.0);
+        assert_eq!(result[&("a", "c", 0)], 1.0);
+        let result =
+            betweenness::edge_betweenness_centrality_multigraph(&graph, true, false).unwrap();
+        assert_eq!(result[&("a", "b", 0)], 1.0);
+        assert_eq!(result[&("a", "b", 1)], 0.0);
+        assert_eq!(result[&("a", "b", 2)], 1.0);
+        assert_eq!(result[&("b", "c", 0)], 2.0);
+        assert_eq!(result[&("a", "c", 0)], 0.0);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_multigraph_2() {
+        // undirected; the values of parallel edges add up to the value of their pair
+        let edges = vec![
+            Edge::new("n2", "n1"),
+            Edge::new("n1", "n2"),
+            Edge::new("n3", "n2"),
+            Edge::new("n3", "n4"),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_undirected()
+            },
+        )
+        .unwrap();
+        let result =
+            betweenness::edge_betweenness_centrality_multigraph(&graph, false, true).unwrap();
+        assert_eq!(result.len(), 4);
+        let pairs = betweenness::edge_betweenness_centrality(&graph, false, true).unwrap();
+        assert_eq!(result[&("n1", "n2", 0)], pairs[&("n1", "n2")] / 2.0);
+        assert_eq!(result[&("n1", "n2", 1)], pairs[&("n1", "n2")] / 2.0);
+        assert_eq!(result[&("n2", "n3", 0)], pairs[&("n2", "n3")]);
+        assert_eq!(result[&("n3", "n4", 0)], pairs[&("n3", "n4")]);
+    }
+
+    #[test]
+    fn test_edge_betweenness_centrality_multigraph_3() {
+        // the shortest paths take the lightest parallel edge, even if it was added later;
+        // values from NetworkX
+        let edges = vec![
+            Edge::with_weight("a", "b", 5.0),
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("a", "c", 2.0),
+            Edge::with_weight("c", "b", 2.0),
+        ];
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_undirected()
+            },
+        )
+        .unwrap();
+        let result =
+            betweenness::edge_betweenness_centrality_multigraph(&graph, true, false).unwrap();
+        assert_eq!(result[&("a", "b", 0)], 0.0);
+        assert_eq!(result[&("a", "b", 1)], 1.0);
+        assert_eq!(result[&("a", "c", 0)], 1.0);
+        assert_eq!(result[&("b", "c", 0)], 1.0);
+        let result = betweenness::betweenness_centrality(&graph, true, false, None, None).unwrap();
+        assert_eq!(result[&"c"], 0.0);
+    }
+
     fn get_graph_1<'a>(directed: bool) -> Graph<&'a str, ()> {
         let edges = vec![
             Edge::with_weight("n1", "n2", 1.0),
@@ -171,4 +770,14 @@ mod tests {
         .unwrap();
         graph
     }
+
+    fn assert_nonzero_values(result: &HashMap<i32, f64>, expected: &[(i32, f64)]) {
+        assert_eq!(
+            result.values().filter(|v| **v != 0.0).count(),
+            expected.len()
+        );
+        for (node, value) in expected {
+            assert_eq!(round(&result[node], 8), round(value, 8));
+        }
+    }
 }
@@ -4,7 +4,9 @@ mod utility;
 mod tests {
 
     use super::utility::round;
-    use graphrs::{algorithms::centrality::closeness, generators, Edge, Graph, GraphSpecs};
+    use graphrs::{
+        algorithms::centrality::closeness, generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
 
     #[test]
     fn test_closeness_centrality_1() {
@@ -246,6 +248,63 @@ mod tests {
         assert_eq!(round(result.get(&0).unwrap(), 2), 0.69);
     }
 
+    #[test]
+    fn test_incremental_closeness_centrality_1() {
+        // adding edges to a disconnected random graph one at a time
+        let mut graph =
+            generators::random::fast_gnp_random_graph(60, 0.03, false, Some(1)).unwrap();
+        let mut closeness = closeness::closeness_centrality(&graph, false, true).unwrap();
+        for (u, v) in [(0, 59), (3, 40), (7, 21), (12, 50), (0, 30)] {
+            let result =
+                closeness::incremental_closeness_centrality(&graph, u, v, Some(&closeness), true)
+                    .unwrap();
+            graph.add_edge(Edge::new(u, v)).unwrap();
+            let expected = closeness::closeness_centrality(&graph, false, true).unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (node, value) in expected {
+                assert_eq!(round(&result[&node], 8), round(&value, 8));
+            }
+            closeness = result;
+        }
+    }
+
+    #[test]
+    fn test_incremental_closeness_centrality_2() {
+        // no previous closeness centrality, not wf_improved
+        let graph = get_graph_1(false);
+        let result =
+            closeness::incremental_closeness_centrality(&graph, "n2", "n5", None, false).unwrap();
+        let mut graph = graph;
+        graph.add_edge(Edge::new("n2", "n5")).unwrap();
+        let expected = closeness::closeness_centrality(&graph, false, false).unwrap();
+        for (node, value) in expected {
+            assert_eq!(round(&result[&node], 8), round(&value, 8));
+        }
+    }
+
+    #[test]
+    fn test_incremental_closeness_centrality_3() {
+        let graph = get_graph_1(false);
+        let prev = closeness::closeness_centrality(&graph, false, true).unwrap();
+        let result = closeness::incremental_closeness_centrality(&graph, "n1", "n9", None, true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let mut partial = prev.clone();
+        partial.remove("n1");
+        let result =
+            closeness::incremental_closeness_centrality(&graph, "n2", "n5", Some(&partial), true);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = get_graph_1(true);
+        let result =
+            closeness::incremental_closeness_centrality(&graph, "n2", "n5", Some(&prev), true);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
     fn get_graph_1<'a>(directed: bool) -> Graph<&'a str, ()> {
         let edges = vec![
             Edge::with_weight("n1", "n2", 1.0),
@@ -0,0 +1,252 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_parallel_edges_graph, round};
+    use graphrs::{
+        algorithms::centrality::{current_flow_betweenness, LaplacianSolver},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    const CG: LaplacianSolver = LaplacianSolver::ConjugateGradient;
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_1() {
+        // K4; values from NetworkX
+        let graph = generators::classic::complete_graph(4, false);
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        assert_values(&result, &[0.25, 0.25, 0.25, 0.25]);
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, false, CG)
+                .unwrap();
+        assert_values(&result, &[0.75, 0.75, 0.75, 0.75]);
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_2() {
+        // weighted K4; values from NetworkX
+        let mut edges = vec![Edge::with_weight(0, 1, 0.5)];
+        for (u, v) in [(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+            edges.push(Edge::with_weight(u, v, 1.0));
+        }
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, true, true, CG)
+                .unwrap();
+        assert_values(
+            &result,
+            &[0.2222222222, 0.2222222222, 0.3055555556, 0.3055555556],
+        );
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        assert_values(&result, &[0.25, 0.25, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_3() {
+        // path and star graphs; values from NetworkX
+        let graph = get_path_graph(4);
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, false, CG)
+                .unwrap();
+        assert_values(&result, &[0.0, 2.0, 2.0, 0.0]);
+        let edges = vec![
+            Edge::new("a", "b"),
+            Edge::new("a", "c"),
+            Edge::new("a", "d"),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        assert_eq!(round(&result["a"], 8), 1.0);
+        assert_eq!(round(&result["b"], 8), 0.0);
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_4() {
+        // karate club, normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        let expected = vec![
+            0.4863872084,
+            0.1837643138,
+            0.2527384611,
+            0.0998773522,
+            0.0468999203,
+            0.0717869484,
+            0.0717869484,
+            0.0535617822,
+            0.1035359279,
+            0.0291263286,
+            0.0468999203,
+            0.0,
+            0.021792873,
+            0.095216132,
+            0.0098781603,
+            0.0098781603,
+            0.0157496013,
+            0.0206109525,
+            0.0098781603,
+            0.0678812589,
+            0.0098781603,
+            0.0206109525,
+            0.0098781603,
+            0.0925487018,
+            0.0417710688,
+            0.0468428439,
+            0.0158944027,
+            0.0811857423,
+            0.0475059951,
+            0.0585462564,
+            0.075595337,
+            0.1552170137,
+            0.247681619,
+            0.3903685196,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_5() {
+        // karate club, weighted, not normalized; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, true, false, CG)
+                .unwrap();
+        assert_eq!(round(&result[&0], 6), 248.481108);
+        assert_eq!(round(&result[&1], 6), 100.286068);
+        assert_eq!(round(&result[&2], 6), 151.815456);
+        assert_eq!(round(&result[&16], 6), 6.506676);
+        assert_eq!(round(&result[&33], 6), 204.772425);
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_6() {
+        let graph = generators::classic::complete_graph(4, true);
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let nodes = vec![Node::from_name(0), Node::from_name(1), Node::from_name(2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, vec![], GraphSpecs::undirected()).unwrap();
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = get_path_graph(2);
+        let result =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        assert_values(&result, &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_approximate_current_flow_betweenness_centrality_1() {
+        // the estimates are within epsilon of the exact values
+        let graph = generators::social::karate_club_graph();
+        let exact =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, false, true, CG)
+                .unwrap();
+        let result = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+            &graph,
+            false,
+            true,
+            CG,
+            0.1,
+            Some(1000000),
+            Some(1),
+        )
+        .unwrap();
+        for (node, value) in exact {
+            assert!((result[&node] - value).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_approximate_current_flow_betweenness_centrality_2() {
+        // K4, not normalized, with the same seed twice
+        let graph = generators::classic::complete_graph(4, false);
+        let result1 = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+            &graph,
+            false,
+            false,
+            CG,
+            0.05,
+            None,
+            Some(1),
+        )
+        .unwrap();
+        let result2 = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+            &graph,
+            false,
+            false,
+            CG,
+            0.05,
+            None,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(result1, result2);
+        for value in result1.values() {
+            assert!((value - 0.75).abs() < 0.05 * 16.0);
+        }
+    }
+
+    #[test]
+    fn test_approximate_current_flow_betweenness_centrality_3() {
+        let graph = generators::social::karate_club_graph();
+        let result = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+            &graph, false, true, CG, 0.01, None, None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = current_flow_betweenness::approximate_current_flow_betweenness_centrality(
+            &graph, false, true, CG, 0.0, None, None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    fn get_path_graph(n: i32) -> Graph<i32, ()> {
+        let edges = (1..n).map(|v| Edge::new(v - 1, v)).collect();
+        let nodes = (0..n).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    #[test]
+    fn test_current_flow_betweenness_centrality_7() {
+        // the conductances of parallel edges add up
+        let multigraph = get_parallel_edges_graph(true);
+        let graph = get_parallel_edges_graph(false);
+        let result = current_flow_betweenness::current_flow_betweenness_centrality(
+            &multigraph,
+            true,
+            false,
+            CG,
+        )
+        .unwrap();
+        let expected =
+            current_flow_betweenness::current_flow_betweenness_centrality(&graph, true, false, CG)
+                .unwrap();
+        for v in 0..4 {
+            assert_eq!(round(&result[&v], 8), round(&expected[&v], 8));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{get_parallel_edges_graph, round};
+    use graphrs::{
+        algorithms::centrality::{current_flow_closeness, LaplacianSolver},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    const CG: LaplacianSolver = LaplacianSolver::ConjugateGradient;
+
+    #[test]
+    fn test_current_flow_closeness_centrality_1() {
+        // K4, a path and a star; values from NetworkX
+        let graph = generators::classic::complete_graph(4, false);
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        for v in 0..4 {
+            assert_eq!(round(&result[&v], 8), round(&(2.0 / 3.0), 8));
+        }
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), round(&(1.0 / 6.0), 8));
+        assert_eq!(round(&result[&1], 8), 0.25);
+        let edges = vec![
+            Edge::new("a", "b"),
+            Edge::new("a", "c"),
+            Edge::new("a", "d"),
+        ];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        assert_eq!(round(&result["a"], 8), round(&(1.0 / 3.0), 8));
+        assert_eq!(round(&result["b"], 8), 0.2);
+    }
+
+    #[test]
+    fn test_current_flow_closeness_centrality_2() {
+        // karate club; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        let expected = vec![
+            0.058567106,
+            0.0525250094,
+            0.055765415,
+            0.0468268076,
+            0.032735244,
+            0.0341063598,
+            0.0341063598,
+            0.042032715,
+            0.0471063833,
+            0.0316297332,
+            0.032735244,
+            0.0203772104,
+            0.0302215036,
+            0.0467578202,
+            0.0309455014,
+            0.0309455014,
+            0.0239572043,
+            0.0308494947,
+            0.0309455014,
+            0.0390959048,
+            0.0309455014,
+            0.0308494947,
+            0.0309455014,
+            0.0436224788,
+            0.0335867779,
+            0.0337634812,
+            0.0292021363,
+            0.0412689998,
+            0.0381702064,
+            0.0396756829,
+            0.0430432716,
+            0.0475826006,
+            0.0550945202,
+            0.0591829069,
+        ];
+        assert_eq!(result.len(), 34);
+        for (node, value) in expected.iter().enumerate() {
+            assert_eq!(round(&result[&(node as i32)], 7), round(value, 7));
+        }
+    }
+
+    #[test]
+    fn test_current_flow_closeness_centrality_3() {
+        // karate club, weighted; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, true, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), 0.14431627);
+        assert_eq!(round(&result[&1], 8), 0.13551282);
+        assert_eq!(round(&result[&2], 8), 0.14296389);
+        assert_eq!(round(&result[&16], 8), 0.06519353);
+        assert_eq!(round(&result[&33], 8), 0.14708017);
+    }
+
+    #[test]
+    fn test_current_flow_closeness_centrality_4() {
+        let nodes = vec![Node::from_name(1), Node::from_name(2), Node::from_name(3)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, vec![], GraphSpecs::undirected()).unwrap();
+        let result = current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::complete_graph(4, true);
+        let result = current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_current_flow_closeness_centrality_5() {
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        assert!(result.is_empty());
+        let nodes = vec![Node::from_name(1)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, vec![], GraphSpecs::undirected()).unwrap();
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, false, CG).unwrap();
+        assert_eq!(result[&1], 0.0);
+    }
+
+    #[test]
+    fn test_current_flow_closeness_centrality_6() {
+        // the conductances of parallel edges add up
+        let multigraph = get_parallel_edges_graph(true);
+        let graph = get_parallel_edges_graph(false);
+        let result =
+            current_flow_closeness::current_flow_closeness_centrality(&multigraph, true, CG)
+                .unwrap();
+        let expected =
+            current_flow_closeness::current_flow_closeness_centrality(&graph, true, CG).unwrap();
+        for v in 0..4 {
+            assert_eq!(round(&result[&v], 8), round(&expected[&v], 8));
+        }
+    }
+}
@@ -3,9 +3,10 @@ mod utility;
 #[cfg(test)]
 mod tests {
 
-    use super::utility::round;
+    use super::utility::{assert_values, get_directed_graph, round};
     use graphrs::{
-        algorithms::centrality::eigenvector, generators, Edge, ErrorKind, Graph, GraphSpecs,
+        algorithms::centrality::{eigenvector, EdgeDirection},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
     };
 
     #[test]
@@ -21,7 +22,8 @@ mod tests {
         let graph: Graph<&str, ()> =
             Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
                 .unwrap();
-        let result = eigenvector::eigenvector_centrality(&graph, true, None, None);
+        let result =
+            eigenvector::eigenvector_centrality(&graph, true, EdgeDirection::Incoming, None, None);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(
@@ -44,7 +46,9 @@ mod tests {
         let graph: Graph<&str, ()> =
             Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
                 .unwrap();
-        let result = eigenvector::eigenvector_centrality(&graph, true, None, None).unwrap();
+        let result =
+            eigenvector::eigenvector_centrality(&graph, true, EdgeDirection::Incoming, None, None)
+                .unwrap();
         assert_eq!(round(result.get("n1").unwrap(), 2), 0.28);
         assert_eq!(round(result.get("n2").unwrap(), 2), 0.09);
         assert_eq!(round(result.get("n3").unwrap(), 2), 0.31);
@@ -56,7 +60,9 @@ mod tests {
     fn test_eigenvector_centrality_3() {
         // karate club, unweighted
         let graph = generators::social::karate_club_graph();
-        let result = eigenvector::eigenvector_centrality(&graph, false, None, None).unwrap();
+        let result =
+            eigenvector::eigenvector_centrality(&graph, false, EdgeDirection::Incoming, None, None)
+                .unwrap();
         assert_eq!(round(result.get(&0).unwrap(), 2), 0.36);
         assert_eq!(round(result.get(&1).unwrap(), 2), 0.27);
         assert_eq!(round(result.get(&2).unwrap(), 2), 0.32);
@@ -92,4 +98,99 @@ mod tests {
         assert_eq!(round(result.get(&32).unwrap(), 2), 0.31);
         assert_eq!(round(result.get(&33).unwrap(), 2), 0.37);
     }
+
+    #[test]
+    fn test_eigenvector_centrality_4() {
+        // directed graph, incoming and outgoing edges; values from NetworkX
+        let graph = get_directed_graph();
+        let result = eigenvector::eigenvector_centrality(
+            &graph,
+            false,
+            EdgeDirection::Incoming,
+            Some(10000),
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            0.1098800787,
+            0.407983674,
+            0.2385433082,
+            0.4447597313,
+            0.3147495199,
+            0.4476839512,
+            0.4610794077,
+            0.2123867652,
+            0.097831604,
+            0.0,
+        ];
+        assert_values(&result, &expected);
+        let result = eigenvector::eigenvector_centrality(
+            &graph,
+            false,
+            EdgeDirection::Outgoing,
+            Some(10000),
+            Some(1e-12),
+        )
+        .unwrap();
+        assert_eq!(round(&result[&2], 6), 0.493866);
+        assert_eq!(round(&result[&3], 6), 0.263681);
+        assert_eq!(round(&result[&7], 6), 0.099807);
+        assert_eq!(round(&result[&8], 6), 0.0);
+        let result = eigenvector::eigenvector_centrality(
+            &graph,
+            true,
+            EdgeDirection::Incoming,
+            Some(10000),
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            0.1304641272,
+            0.3698014814,
+            0.2843926608,
+            0.3709900376,
+            0.4311186219,
+            0.4281069335,
+            0.4600895487,
+            0.2110644531,
+            0.0484125356,
+            0.0,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_eigenvector_centrality_5() {
+        // too few iterations, a multigraph and an empty graph
+        let graph = get_directed_graph();
+        let result = eigenvector::eigenvector_centrality(
+            &graph,
+            false,
+            EdgeDirection::Incoming,
+            Some(3),
+            None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+        let edges = vec![Edge::new(0, 1), Edge::new(0, 1), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_directed()
+            },
+        )
+        .unwrap();
+        let result =
+            eigenvector::eigenvector_centrality(&graph, false, EdgeDirection::Incoming, None, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let result =
+            eigenvector::eigenvector_centrality(&graph, false, EdgeDirection::Incoming, None, None)
+                .unwrap();
+        assert!(result.is_empty());
+    }
 }
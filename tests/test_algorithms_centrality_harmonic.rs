@@ -0,0 +1,170 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::harmonic, generators, Edge, ErrorKind, Graph, GraphSpecs,
+        MissingNodeStrategy,
+    };
+
+    #[test]
+    fn test_harmonic_centrality_1() {
+        // karate club, unweighted; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = harmonic::harmonic_centrality(&graph, false, None, None).unwrap();
+        let expected = vec![
+            23.1666666667,
+            19.1666666667,
+            21.0,
+            17.6666666667,
+            14.6666666667,
+            15.1666666667,
+            15.1666666667,
+            16.4166666667,
+            18.5,
+            15.5833333333,
+            14.6666666667,
+            13.5,
+            14.0,
+            18.5,
+            14.2,
+            14.2,
+            11.1,
+            14.1666666667,
+            14.2,
+            17.5,
+            14.2,
+            14.1666666667,
+            14.2,
+            16.0333333333,
+            13.9166666667,
+            13.9166666667,
+            13.95,
+            16.9166666667,
+            16.4166666667,
+            15.3666666667,
+            16.9166666667,
+            19.3333333333,
+            20.9166666667,
+            23.25,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_harmonic_centrality_2() {
+        // karate club, weighted; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = harmonic::harmonic_centrality(&graph, true, None, None).unwrap();
+        assert_eq!(result.len(), 34);
+        assert_eq!(round(&result[&0], 8), 10.44523810);
+        assert_eq!(round(&result[&1], 8), 8.24365079);
+        assert_eq!(round(&result[&16], 8), 4.07312132);
+        assert_eq!(round(&result[&33], 8), 10.91944444);
+    }
+
+    #[test]
+    fn test_harmonic_centrality_3() {
+        // karate club, subsets of nodes and sources; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            harmonic::harmonic_centrality(&graph, false, Some(&[0, 1, 2]), Some(&[5, 6, 16]))
+                .unwrap();
+        assert_values(&result, &[2.5, 1.3333333333, 1.3333333333]);
+    }
+
+    #[test]
+    fn test_harmonic_centrality_4() {
+        // directed, not strongly connected, unweighted; values from NetworkX
+        let graph = get_directed_graph();
+        let result = harmonic::harmonic_centrality(&graph, false, None, None).unwrap();
+        let expected = vec![
+            3.8666666667,
+            5.3333333333,
+            4.4166666667,
+            5.5,
+            4.5,
+            5.5,
+            5.3333333333,
+            4.5,
+            3.75,
+            0.0,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_harmonic_centrality_5() {
+        // directed, not strongly connected, weighted; values from NetworkX
+        let graph = get_directed_graph();
+        let nodes: Vec<i32> = (0..6).collect();
+        let result = harmonic::harmonic_centrality(&graph, true, Some(&nodes), None).unwrap();
+        let expected = vec![
+            2.3504329004,
+            3.5,
+            2.7277777778,
+            3.3666666667,
+            2.0428571429,
+            3.2333333333,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_harmonic_centrality_6() {
+        // the sum over all sources equals the sum over subsets of sources that partition them
+        let graph = generators::random::fast_gnp_random_graph(50, 0.1, true, Some(1)).unwrap();
+        let all = harmonic::harmonic_centrality(&graph, false, None, None).unwrap();
+        let evens: Vec<i32> = (0..50).filter(|n| n % 2 == 0).collect();
+        let odds: Vec<i32> = (0..50).filter(|n| n % 2 == 1).collect();
+        let from_evens = harmonic::harmonic_centrality(&graph, false, None, Some(&evens)).unwrap();
+        let from_odds = harmonic::harmonic_centrality(&graph, false, None, Some(&odds)).unwrap();
+        for (node, value) in all {
+            assert_eq!(
+                round(&value, 8),
+                round(&(from_evens[&node] + from_odds[&node]), 8)
+            );
+        }
+    }
+
+    #[test]
+    fn test_harmonic_centrality_7() {
+        let graph = generators::social::karate_club_graph();
+        let result = harmonic::harmonic_centrality(&graph, false, Some(&[99]), None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = harmonic::harmonic_centrality(&graph, false, None, None).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_harmonic_centrality_8() {
+        // in multigraphs the lightest of the parallel edges is used
+        let edges = vec![
+            Edge::with_weight(0, 1, 5.0),
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, 2.0),
+            Edge::with_weight(0, 2, 4.0),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges.clone(), specs).unwrap();
+        let result = harmonic::harmonic_centrality(&graph, true, None, None).unwrap();
+        assert_values(&result, &[1.3333333333, 1.5, 0.8333333333]);
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_directed()
+        };
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = harmonic::harmonic_centrality(&graph, true, None, None).unwrap();
+        assert_values(&result, &[0.0, 1.0, 0.8333333333]);
+    }
+}
@@ -0,0 +1,142 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::hits, generators, Edge, ErrorKind, Graph, GraphSpecs,
+        MissingNodeStrategy,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_hits_1() {
+        // values from NetworkX
+        let graph = get_directed_graph();
+        let (hubs, authorities) =
+            hits::hits(&graph, false, true, None, Some(1000), Some(1e-12)).unwrap();
+        let expected_hubs = vec![
+            0.1741533463,
+            0.1053200666,
+            0.1258211858,
+            0.0817184229,
+            0.1864608551,
+            0.1302363905,
+            0.0898103242,
+            0.0590221872,
+            0.0,
+            0.0474572213,
+        ];
+        let expected_authorities = vec![
+            0.0696894872,
+            0.1811527168,
+            0.112399226,
+            0.1647779812,
+            0.0834687319,
+            0.131594598,
+            0.1779730266,
+            0.0552065605,
+            0.0237376718,
+            0.0,
+        ];
+        assert_values(&hubs, &expected_hubs);
+        assert_values(&authorities, &expected_authorities);
+        assert_eq!(round(&hubs.values().sum(), 8), 1.0);
+        assert_eq!(round(&authorities.values().sum(), 8), 1.0);
+    }
+
+    #[test]
+    fn test_hits_2() {
+        // weighted and not normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let (hubs, authorities) =
+            hits::hits(&graph, true, true, None, Some(1000), Some(1e-12)).unwrap();
+        let expected_hubs = vec![
+            0.0926389118,
+            0.0411544912,
+            0.3114552711,
+            0.1423939458,
+            0.1739948989,
+            0.0675400565,
+            0.0461340755,
+            0.087629599,
+            0.0,
+            0.0370587503,
+        ];
+        let expected_authorities = vec![
+            0.1264153832,
+            0.0864914674,
+            0.0413938439,
+            0.0869914953,
+            0.2931841261,
+            0.1442873752,
+            0.1725803554,
+            0.0318707342,
+            0.0167852194,
+            0.0,
+        ];
+        assert_values(&hubs, &expected_hubs);
+        assert_values(&authorities, &expected_authorities);
+        let (hubs, authorities) =
+            hits::hits(&graph, true, false, None, Some(1000), Some(1e-12)).unwrap();
+        assert_eq!(round(&hubs[&0], 8), 0.2974389);
+        assert_eq!(round(&hubs[&9], 8), 0.11898579);
+        assert_eq!(round(&authorities[&0], 8), 0.43118086);
+        assert_eq!(round(&authorities[&5], 8), 0.49213911);
+        assert_eq!(hubs.values().cloned().fold(0.0, f64::max), 1.0);
+        assert_eq!(authorities.values().cloned().fold(0.0, f64::max), 1.0);
+    }
+
+    #[test]
+    fn test_hits_3() {
+        // a starting vector and an undirected graph; values from NetworkX
+        let graph = get_directed_graph();
+        let nstart: HashMap<i32, f64> = (0..10).map(|i| (i, (i % 3 + 1) as f64)).collect();
+        let (hubs, authorities) =
+            hits::hits(&graph, false, true, Some(&nstart), Some(1000), Some(1e-12)).unwrap();
+        assert_eq!(round(&hubs[&0], 8), 0.17415335);
+        assert_eq!(round(&authorities[&5], 8), 0.1315946);
+        let graph = generators::social::karate_club_graph();
+        let (hubs, authorities) =
+            hits::hits(&graph, false, true, None, Some(1000), Some(1e-12)).unwrap();
+        assert_eq!(round(&hubs[&0], 8), 0.07141273);
+        assert_eq!(round(&hubs[&33], 8), 0.07500294);
+        assert_eq!(round(&authorities[&1], 8), 0.05342723);
+        for node in 0..34 {
+            assert_eq!(round(&hubs[&node], 8), round(&authorities[&node], 8));
+        }
+    }
+
+    #[test]
+    fn test_hits_4() {
+        // errors and edge cases
+        let graph = get_directed_graph();
+        let result = hits::hits(&graph, false, true, None, Some(2), None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+        let nstart: HashMap<i32, f64> = vec![(99, 1.0)].into_iter().collect();
+        let result = hits::hits(&graph, false, true, Some(&nstart), None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let edges = vec![Edge::new(0, 1), Edge::new(0, 1), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_directed()
+            },
+        )
+        .unwrap();
+        let result = hits::hits(&graph, false, true, None, None, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let (hubs, authorities) = hits::hits(&graph, false, true, None, None, None).unwrap();
+        assert!(hubs.is_empty() && authorities.is_empty());
+    }
+}
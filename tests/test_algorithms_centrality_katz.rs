@@ -0,0 +1,192 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::{katz, EdgeDirection},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_katz_centrality_1() {
+        // values from NetworkX
+        let graph = get_directed_graph();
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            Some(0.05),
+            None,
+            EdgeDirection::Incoming,
+            true,
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            0.3124086216,
+            0.3304368005,
+            0.3146890966,
+            0.3312145834,
+            0.3148420095,
+            0.3305097357,
+            0.3305488676,
+            0.3132016102,
+            0.298206906,
+            0.2825468255,
+        ];
+        assert_values(&result, &expected);
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            Some(0.05),
+            None,
+            EdgeDirection::Outgoing,
+            true,
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            0.33067828,
+            0.3149316952,
+            0.3313458517,
+            0.3148154849,
+            0.3297730718,
+            0.3140640583,
+            0.3139931138,
+            0.3124580127,
+            0.2826236284,
+            0.314780443,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_katz_centrality_2() {
+        // weighted, per-node beta and not normalized; values from NetworkX
+        let graph = get_directed_graph();
+        let result = katz::katz_centrality(
+            &graph,
+            true,
+            Some(0.01),
+            None,
+            EdgeDirection::Incoming,
+            true,
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            0.3128309161,
+            0.3193456376,
+            0.3161812439,
+            0.3225034891,
+            0.3225698851,
+            0.3226269813,
+            0.3195377444,
+            0.3159327718,
+            0.3066318933,
+            0.3034725656,
+        ];
+        assert_values(&result, &expected);
+        let beta: HashMap<i32, f64> = (0..10).map(|i| (i, (i % 4 + 1) as f64)).collect();
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            Some(0.05),
+            Some(&beta),
+            EdgeDirection::Incoming,
+            false,
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+        let expected = vec![
+            1.258895412,
+            2.299269373,
+            3.1779082392,
+            4.3027693045,
+            1.3740338772,
+            2.4972213045,
+            3.352458171,
+            4.2676229086,
+            1.2133811454,
+            2.0,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_katz_centrality_3() {
+        // karate club; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            None,
+            None,
+            EdgeDirection::Incoming,
+            true,
+            None,
+            Some(1e-12),
+        )
+        .unwrap();
+        assert_eq!(round(&result[&0], 8), 0.32132462);
+        assert_eq!(round(&result[&1], 8), 0.23548427);
+        assert_eq!(round(&result[&33], 8), 0.33140643);
+        // alpha is larger than the reciprocal of the largest eigenvalue
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            Some(0.5),
+            None,
+            EdgeDirection::Incoming,
+            true,
+            None,
+            None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+        let beta: HashMap<i32, f64> = (0..33).map(|i| (i, 1.0)).collect();
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            None,
+            Some(&beta),
+            EdgeDirection::Incoming,
+            true,
+            None,
+            None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let edges = vec![Edge::new(0, 1), Edge::new(0, 1), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(
+            vec![],
+            edges,
+            GraphSpecs {
+                missing_node_strategy: MissingNodeStrategy::Create,
+                ..GraphSpecs::multi_directed()
+            },
+        )
+        .unwrap();
+        let result = katz::katz_centrality(
+            &graph,
+            false,
+            None,
+            None,
+            EdgeDirection::Incoming,
+            true,
+            None,
+            None,
+        );
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+}
@@ -0,0 +1,134 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::{betweenness, load},
+        generators, Edge, Graph, GraphSpecs, Node,
+    };
+
+    #[test]
+    fn test_load_centrality_1() {
+        // a path: the load is twice the unnormalized betweenness centrality
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 3)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = load::load_centrality(&graph, false, false).unwrap();
+        assert_values(&result, &[0.0, 4.0, 4.0, 0.0]);
+        let result = load::load_centrality(&graph, false, true).unwrap();
+        assert_values(&result, &[0.0, 2.0 / 3.0, 2.0 / 3.0, 0.0]);
+    }
+
+    #[test]
+    fn test_load_centrality_2() {
+        // the ladder graph with three rungs, the smallest graph on which load and betweenness
+        // centrality differ; values from NetworkX
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(0, 2),
+            Edge::new(1, 3),
+            Edge::new(2, 3),
+            Edge::new(2, 4),
+            Edge::new(4, 5),
+            Edge::new(3, 5),
+        ];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = load::load_centrality(&graph, false, false).unwrap();
+        assert_values(&result, &[1.75, 1.75, 6.5, 6.5, 1.75, 1.75]);
+        let bc = betweenness::betweenness_centrality(&graph, false, false, None, None).unwrap();
+        assert_ne!(round(&(2.0 * bc[&0]), 8), 1.75);
+    }
+
+    #[test]
+    fn test_load_centrality_3() {
+        // karate club; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = load::load_centrality(&graph, false, true).unwrap();
+        let expected = vec![
+            0.4346834228,
+            0.0549360795,
+            0.148342803,
+            0.0142341383,
+            0.0006313131,
+            0.0299873737,
+            0.0299873737,
+            0.0,
+            0.0515309343,
+            0.0007891414,
+            0.0006313131,
+            0.0,
+            0.0,
+            0.0478877315,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0339199285,
+            0.0,
+            0.0,
+            0.0,
+            0.0176767677,
+            0.0023279672,
+            0.0049558081,
+            0.0,
+            0.0239267677,
+            0.0017624158,
+            0.003649779,
+            0.0142071759,
+            0.1340751263,
+            0.1476352062,
+            0.2984335543,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_load_centrality_4() {
+        // karate club, weighted
+        let graph = generators::social::karate_club_graph();
+        let result = load::load_centrality(&graph, true, false).unwrap();
+        assert_eq!(round(&result[&0], 6), 499.666667);
+        assert_eq!(round(&result[&1], 6), 67.041667);
+        assert_eq!(round(&result[&2], 6), 73.583333);
+        assert_eq!(round(&result[&16], 6), 0.0);
+        assert_eq!(round(&result[&31], 6), 132.666667);
+        assert_eq!(round(&result[&33], 6), 418.708333);
+    }
+
+    #[test]
+    fn test_load_centrality_5() {
+        // directed; values from NetworkX
+        let graph = get_directed_graph();
+        let result = load::load_centrality(&graph, false, true).unwrap();
+        let expected = vec![
+            0.08101852, 0.17361111, 0.20138889, 0.08333333, 0.08101852, 0.11805556, 0.25462963,
+            0.14583333, 0.0, 0.0,
+        ];
+        assert_values(&result, &expected);
+        // NetworkX stops at the source when going through the predecessors of a node, so it
+        // misses the paths to 1 and 5 that tie with an edge from the source
+        let result = load::load_centrality(&graph, true, false).unwrap();
+        let expected = vec![5.5, 18.25, 11.0, 12.5, 3.0, 13.5, 21.0, 8.5, 0.0, 0.0];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_load_centrality_6() {
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = load::load_centrality(&graph, false, true).unwrap();
+        assert!(result.is_empty());
+        let nodes = vec![Node::from_name(1), Node::from_name(2)];
+        let edges = vec![Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap();
+        let result = load::load_centrality(&graph, false, true).unwrap();
+        assert_eq!(result[&1], 0.0);
+        assert_eq!(result[&2], 0.0);
+    }
+}
@@ -0,0 +1,211 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::pagerank, generators, Edge, ErrorKind, Graph, GraphSpecs,
+        MissingNodeStrategy,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_pagerank_1() {
+        // karate club; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result =
+            pagerank::pagerank(&graph, false, None, None, None, Some(1000), Some(1e-10)).unwrap();
+        let expected = vec![
+            0.0969972864,
+            0.0528769243,
+            0.0570785095,
+            0.035859858,
+            0.0219779528,
+            0.0291111553,
+            0.0291111553,
+            0.0244904972,
+            0.029766056,
+            0.0143093971,
+            0.0219779528,
+            0.0095647456,
+            0.0146448921,
+            0.0295364562,
+            0.0145359939,
+            0.0145359939,
+            0.0167840058,
+            0.0145586773,
+            0.0145359939,
+            0.0196046364,
+            0.0145359939,
+            0.0145586773,
+            0.0145359939,
+            0.0315225144,
+            0.0210760334,
+            0.0210061972,
+            0.0150440379,
+            0.0256397673,
+            0.0195734593,
+            0.0262885374,
+            0.0245901551,
+            0.0371580868,
+            0.0716932253,
+            0.1009191814,
+        ];
+        assert_values(&result, &expected);
+        let result =
+            pagerank::pagerank(&graph, true, None, None, None, Some(1000), Some(1e-10)).unwrap();
+        assert_eq!(round(&result[&0], 6), 0.088500);
+        assert_eq!(round(&result[&9], 6), 0.009463);
+        assert_eq!(round(&result[&23], 6), 0.041150);
+        assert_eq!(round(&result[&33], 6), 0.096989);
+        assert_eq!(round(&result.values().sum(), 6), 1.0);
+    }
+
+    #[test]
+    fn test_pagerank_2() {
+        // directed graph with dangling nodes; values from NetworkX
+        let graph = get_directed_graph();
+        let result =
+            pagerank::pagerank(&graph, false, None, None, None, Some(1000), Some(1e-10)).unwrap();
+        let expected = vec![
+            0.0536153109,
+            0.1271226344,
+            0.0891226174,
+            0.1550155286,
+            0.111037501,
+            0.1550421439,
+            0.1425094379,
+            0.0889304138,
+            0.0576999189,
+            0.0199044931,
+        ];
+        assert_values(&result, &expected);
+        let result =
+            pagerank::pagerank(&graph, true, Some(0.7), None, None, Some(1000), Some(1e-10))
+                .unwrap();
+        assert_eq!(round(&result[&0], 6), 0.060778);
+        assert_eq!(round(&result[&4], 6), 0.139135);
+        assert_eq!(round(&result[&8], 6), 0.049834);
+        assert_eq!(round(&result[&9], 6), 0.033488);
+    }
+
+    #[test]
+    fn test_pagerank_3() {
+        // personalization and dangling weights; values from NetworkX
+        let graph = get_directed_graph();
+        let personalization: HashMap<i32, f64> =
+            vec![(0, 1.0), (5, 2.0), (7, 1.0)].into_iter().collect();
+        let dangling: HashMap<i32, f64> = vec![(1, 1.0), (2, 3.0)].into_iter().collect();
+        let result = pagerank::pagerank(
+            &graph,
+            true,
+            None,
+            Some(&personalization),
+            Some(&dangling),
+            Some(1000),
+            Some(1e-10),
+        )
+        .unwrap();
+        assert_eq!(round(&result[&0], 6), 0.057178);
+        assert_eq!(round(&result[&1], 6), 0.094706);
+        assert_eq!(round(&result[&5], 6), 0.22037);
+        assert_eq!(round(&result[&8], 6), 0.019689);
+        assert_eq!(round(&result[&9], 6), 0.0);
+        let result = pagerank::pagerank(
+            &graph,
+            false,
+            None,
+            Some(&personalization),
+            None,
+            Some(1000),
+            Some(1e-10),
+        )
+        .unwrap();
+        assert_eq!(round(&result[&0], 6), 0.064663);
+        assert_eq!(round(&result[&5], 6), 0.232218);
+        assert_eq!(round(&result[&8], 6), 0.045416);
+        assert_eq!(round(&result[&9], 6), 0.0);
+    }
+
+    #[test]
+    fn test_pagerank_4() {
+        // errors and edge cases
+        let graph = get_directed_graph();
+        let result = pagerank::pagerank(&graph, false, None, None, None, Some(3), None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::PowerIterationFailedConvergence
+        ));
+        let result = pagerank::pagerank(&graph, false, Some(1.5), None, None, None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let personalization: HashMap<i32, f64> = vec![(0, 0.0)].into_iter().collect();
+        let result = pagerank::pagerank(
+            &graph,
+            false,
+            None,
+            Some(&personalization),
+            None,
+            None,
+            None,
+        );
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let dangling: HashMap<i32, f64> = vec![(99, 1.0)].into_iter().collect();
+        let result = pagerank::pagerank(&graph, false, None, None, Some(&dangling), None, None);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let result = pagerank::pagerank(&graph, false, None, None, None, None, None).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_pagerank_5() {
+        // multigraphs; the walks follow each of the parallel edges; values from NetworkX
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_directed()
+        };
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(0, 1),
+            Edge::new(0, 2),
+            Edge::new(1, 2),
+            Edge::new(2, 0),
+            Edge::new(3, 0),
+            Edge::new(3, 2),
+        ];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = pagerank::pagerank(&graph, false, None, None, None, None, None).unwrap();
+        let expected = vec![0.36012398, 0.24156985, 0.36080617, 0.0375];
+        for (node, value) in expected.iter().enumerate() {
+            assert_eq!(round(&result[&(node as i32)], 6), round(value, 6));
+        }
+        // weighted; the weights of parallel edges are added up
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        };
+        let edges = vec![
+            Edge::with_weight(0, 1, 2.0),
+            Edge::with_weight(0, 1, 3.0),
+            Edge::with_weight(1, 2, 1.0),
+            Edge::with_weight(2, 3, 4.0),
+            Edge::with_weight(0, 3, 1.0),
+        ];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = pagerank::pagerank(&graph, true, None, None, None, None, None).unwrap();
+        let expected = vec![0.2653422, 0.2653422, 0.2346578, 0.2346578];
+        for (node, value) in expected.iter().enumerate() {
+            assert_eq!(round(&result[&(node as i32)], 6), round(value, 6));
+        }
+    }
+}
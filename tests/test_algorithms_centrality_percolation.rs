@@ -0,0 +1,183 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{assert_values, get_directed_graph, round};
+    use graphrs::{
+        algorithms::centrality::{betweenness, percolation},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_percolation_centrality_1() {
+        // the examples from Piraveenan et al.; values from NetworkX
+        let graph = get_tree();
+        let states = HashMap::from([
+            (1, 0.1),
+            (2, 0.2),
+            (3, 0.2),
+            (4, 0.2),
+            (5, 0.3),
+            (6, 0.2),
+            (7, 0.5),
+            (8, 0.5),
+        ]);
+        let result =
+            percolation::percolation_centrality(&graph, false, true, Some(&states)).unwrap();
+        assert_eq!(round(&result[&4], 3), 0.625);
+        assert_eq!(round(&result[&6], 3), 0.667);
+        let states = HashMap::from([
+            (1, 0.3),
+            (2, 0.5),
+            (3, 0.5),
+            (4, 0.2),
+            (5, 0.3),
+            (6, 0.2),
+            (7, 0.1),
+            (8, 0.1),
+        ]);
+        let result =
+            percolation::percolation_centrality(&graph, false, true, Some(&states)).unwrap();
+        assert_eq!(round(&result[&4], 8), 0.825);
+        assert_eq!(round(&result[&5], 8), 0.53508772);
+        assert_eq!(round(&result[&6], 8), 0.4);
+        assert_eq!(result[&1], 0.0);
+        let result =
+            percolation::percolation_centrality(&graph, false, false, Some(&states)).unwrap();
+        assert_eq!(round(&result[&4], 8), 4.95);
+        assert_eq!(round(&result[&6], 8), 2.4);
+    }
+
+    #[test]
+    fn test_percolation_centrality_2() {
+        // with every node in the same state, the percolation centrality is the normalized
+        // betweenness centrality
+        let graph = generators::social::karate_club_graph();
+        for weighted in [false, true] {
+            let result = percolation::percolation_centrality(&graph, weighted, true, None).unwrap();
+            let expected =
+                betweenness::betweenness_centrality(&graph, weighted, true, None, None).unwrap();
+            for v in 0..34 {
+                assert_eq!(round(&result[&v], 8), round(&expected[&v], 8));
+            }
+            // without normalization the values aren't divided by n - 2
+            let result =
+                percolation::percolation_centrality(&graph, weighted, false, None).unwrap();
+            for v in 0..34 {
+                assert_eq!(round(&result[&v], 8), round(&(expected[&v] * 32.0), 8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_percolation_centrality_3() {
+        // karate club; values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let states: HashMap<i32, f64> = (0..34).map(|v| (v, ((v % 4) + 1) as f64 / 10.0)).collect();
+        let result =
+            percolation::percolation_centrality(&graph, false, true, Some(&states)).unwrap();
+        let expected = vec![
+            0.4212567751,
+            0.0577160494,
+            0.1464152406,
+            0.0094676135,
+            0.0007621951,
+            0.0219907407,
+            0.02109375,
+            0.0,
+            0.0525893462,
+            0.000997575,
+            0.000390625,
+            0.0,
+            0.0,
+            0.0482335758,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0322835041,
+            0.0,
+            0.0,
+            0.0,
+            0.0167721519,
+            0.0029852642,
+            0.0045374657,
+            0.0,
+            0.0213463181,
+            0.0021922184,
+            0.0032756467,
+            0.0133984375,
+            0.1161404963,
+            0.1563104917,
+            0.3209059009,
+        ];
+        assert_values(&result, &expected);
+        let result =
+            percolation::percolation_centrality(&graph, true, true, Some(&states)).unwrap();
+        assert_eq!(round(&result[&0], 8), 0.4578125);
+        assert_eq!(round(&result[&1], 8), 0.06630015);
+        assert_eq!(round(&result[&2], 8), 0.08032552);
+        assert_eq!(round(&result[&16], 8), 0.0);
+        assert_eq!(round(&result[&31], 8), 0.09971651);
+        assert_eq!(round(&result[&33], 8), 0.40972222);
+    }
+
+    #[test]
+    fn test_percolation_centrality_4() {
+        // directed; values from NetworkX
+        let graph = get_directed_graph();
+        let states: HashMap<i32, f64> = [0.2, 0.5, 1.0, 0.0, 0.4, 0.9, 0.1, 0.6, 0.3, 0.8]
+            .into_iter()
+            .enumerate()
+            .map(|(v, state)| (v as i32, state))
+            .collect();
+        let result =
+            percolation::percolation_centrality(&graph, false, true, Some(&states)).unwrap();
+        let expected = vec![
+            0.13405797, 0.12790698, 0.19078947, 0.078125, 0.03787879, 0.13461538, 0.28812057,
+            0.1547619, 0.0, 0.0,
+        ];
+        assert_values(&result, &expected);
+        let result =
+            percolation::percolation_centrality(&graph, true, true, Some(&states)).unwrap();
+        let expected = vec![
+            0.12318841, 0.22965116, 0.13925439, 0.171875, 0.0, 0.18269231, 0.35904255, 0.12400794,
+            0.0, 0.0,
+        ];
+        assert_values(&result, &expected);
+    }
+
+    #[test]
+    fn test_percolation_centrality_5() {
+        let graph = get_tree();
+        let states = HashMap::from([(1, 0.5), (2, 0.5)]);
+        let result = percolation::percolation_centrality(&graph, false, true, Some(&states));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let states: HashMap<i32, f64> = (1..9).map(|v| (v, v as f64)).collect();
+        let result = percolation::percolation_centrality(&graph, false, true, Some(&states));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    fn get_tree() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::new(1, 4),
+            Edge::new(2, 4),
+            Edge::new(3, 4),
+            Edge::new(4, 5),
+            Edge::new(5, 6),
+            Edge::new(6, 7),
+            Edge::new(6, 8),
+        ];
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+            .unwrap()
+    }
+}
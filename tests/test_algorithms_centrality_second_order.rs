@@ -0,0 +1,187 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::{get_parallel_edges_graph, round};
+    use graphrs::{
+        algorithms::centrality::{second_order, LaplacianSolver},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    const CG: LaplacianSolver = LaplacianSolver::ConjugateGradient;
+
+    #[test]
+    fn test_second_order_centrality_1() {
+        // P3, K3 and C5; values from NetworkX
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), round(&14.0_f64.sqrt(), 8));
+        assert_eq!(round(&result[&1], 8), round(&2.0_f64.sqrt(), 8));
+        assert_eq!(round(&result[&2], 8), round(&14.0_f64.sqrt(), 8));
+        let graph = generators::classic::complete_graph(3, false);
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        for v in 0..3 {
+            assert_eq!(round(&result[&v], 8), round(&2.0_f64.sqrt(), 8));
+        }
+        let edges = (0..5).map(|v| Edge::new(v, (v + 1) % 5)).collect();
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        for v in 0..5 {
+            assert_eq!(round(&result[&v], 8), round(&20.0_f64.sqrt(), 8));
+        }
+        // normalized, the values are divided by the mean return time, n
+        let result = second_order::second_order_centrality(&graph, false, true, CG).unwrap();
+        for v in 0..5 {
+            assert_eq!(round(&result[&v], 8), round(&(20.0_f64.sqrt() / 5.0), 8));
+        }
+    }
+
+    #[test]
+    fn test_second_order_centrality_2() {
+        // karate club; values from the mean return times of the random walk
+        let graph = generators::social::karate_club_graph();
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        let expected = vec![
+            51.25352406,
+            69.98174838,
+            60.15460019,
+            87.03789729,
+            134.91665085,
+            129.54865323,
+            129.54865323,
+            101.93757311,
+            86.19211275,
+            139.41582211,
+            134.91665085,
+            199.0450294,
+            145.3952931,
+            87.24688432,
+            142.2844926,
+            142.2844926,
+            176.46980024,
+            142.69244013,
+            142.2844926,
+            111.61180926,
+            142.2844926,
+            142.69244013,
+            142.2844926,
+            96.89675208,
+            131.55678521,
+            130.87038396,
+            149.91673247,
+            104.40412864,
+            114.77927756,
+            109.65909088,
+            98.71968539,
+            84.75539221,
+            62.21739885,
+            49.20923173,
+        ];
+        assert_eq!(result.len(), 34);
+        for (node, value) in expected.iter().enumerate() {
+            assert_eq!(round(&result[&(node as i32)], 6), round(value, 6));
+        }
+        let result = second_order::second_order_centrality(&graph, true, false, CG).unwrap();
+        assert_eq!(round(&result[&0], 6), 55.602414);
+        assert_eq!(round(&result[&1], 6), 67.534571);
+        assert_eq!(round(&result[&2], 6), 57.494145);
+        assert_eq!(round(&result[&16], 6), 174.759703);
+        assert_eq!(round(&result[&33], 5), 51.63929);
+    }
+
+    #[test]
+    fn test_second_order_centrality_3() {
+        // weighted, with and without a self-loop
+        let edges = vec![Edge::with_weight(0, 1, 1.0), Edge::with_weight(1, 2, 3.5)];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = second_order::second_order_centrality(&graph, true, false, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), 5.70713839);
+        assert_eq!(round(&result[&1], 8), 2.36038738);
+        assert_eq!(round(&result[&2], 8), 3.64495738);
+        graph.add_edge(Edge::with_weight(2, 2, 2.0)).unwrap();
+        let result = second_order::second_order_centrality(&graph, true, false, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), 6.41426981);
+        assert_eq!(round(&result[&1], 8), 2.85356919);
+        assert_eq!(round(&result[&2], 8), 4.19182879);
+    }
+
+    #[test]
+    fn test_second_order_centrality_4() {
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        assert!(result.is_empty());
+        let nodes = vec![Node::from_name(1)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, vec![], GraphSpecs::undirected()).unwrap();
+        let result = second_order::second_order_centrality(&graph, false, false, CG).unwrap();
+        assert_eq!(result[&1], 0.0);
+        let result = second_order::second_order_centrality(&graph, false, true, CG).unwrap();
+        assert_eq!(result[&1], 0.0);
+    }
+
+    #[test]
+    fn test_second_order_centrality_5() {
+        let nodes = vec![Node::from_name(1), Node::from_name(2), Node::from_name(3)];
+        let edges = vec![Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap();
+        let result = second_order::second_order_centrality(&graph, false, false, CG);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let edges = vec![Edge::with_weight(1, 2, -1.0)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = second_order::second_order_centrality(&graph, true, false, CG);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph = generators::classic::complete_graph(4, true);
+        let result = second_order::second_order_centrality(&graph, false, false, CG);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_second_order_centrality_6() {
+        // normalized, on the path 0 - 1 - 2, computed by hand: 0 and 2 get self-loops so that
+        // every node has a degree of 2, and every step of the walk takes one of the two edges
+        // of its node with a probability of 1/2. The mean times to reach 1 are 2 from 0 and
+        // 2, and 3 from 1 itself, so `sqrt(2 * (2 + 3 + 2) - 3 * 4) / 3 = sqrt(2) / 3`. The
+        // mean times to reach 0 are 4 from 1, 6 from 2 and 3 from 0 itself, so
+        // `sqrt(2 * (3 + 4 + 6) - 3 * 4) / 3 = sqrt(14) / 3`.
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result = second_order::second_order_centrality(&graph, false, true, CG).unwrap();
+        assert_eq!(round(&result[&0], 8), round(&(14.0_f64.sqrt() / 3.0), 8));
+        assert_eq!(round(&result[&1], 8), round(&(2.0_f64.sqrt() / 3.0), 8));
+        assert_eq!(round(&result[&2], 8), round(&(14.0_f64.sqrt() / 3.0), 8));
+    }
+
+    #[test]
+    fn test_second_order_centrality_7() {
+        // the weights of parallel edges add up
+        let multigraph = get_parallel_edges_graph(true);
+        let graph = get_parallel_edges_graph(false);
+        let result = second_order::second_order_centrality(&multigraph, true, false, CG).unwrap();
+        let expected = second_order::second_order_centrality(&graph, true, false, CG).unwrap();
+        for v in 0..4 {
+            assert_eq!(round(&result[&v], 8), round(&expected[&v], 8));
+        }
+    }
+}
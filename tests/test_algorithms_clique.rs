@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::clique, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_find_cliques_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let cliques: Vec<Vec<i32>> = clique::find_cliques(&graph).unwrap().collect();
+        assert_eq!(cliques.len(), 36);
+        assert_eq!(cliques.iter().map(|c| c.len()).sum::<usize>(), 103);
+        assert_eq!(cliques.iter().map(|c| c.len()).max(), Some(5));
+        for c in cliques.iter() {
+            assert!(is_clique(&graph, c));
+        }
+        let graph = get_graph();
+        let cliques: HashSet<Vec<i32>> =
+            clique::find_cliques(&graph).unwrap().map(sorted).collect();
+        let expected: HashSet<Vec<i32>> = vec![
+            vec![0, 1, 2, 3],
+            vec![2, 3, 4, 5],
+            vec![5, 6, 7],
+            vec![7, 8],
+            vec![9],
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(cliques, expected);
+        let graph = get_formula_graph();
+        let cliques: Vec<Vec<i32>> = clique::find_cliques(&graph).unwrap().collect();
+        assert_eq!(cliques.len(), 168);
+        assert_eq!(cliques.iter().map(|c| c.len()).sum::<usize>(), 1134);
+        assert_eq!(cliques.iter().map(|c| c.len()).max(), Some(9));
+        let distinct: HashSet<Vec<i32>> = cliques.into_iter().map(sorted).collect();
+        assert_eq!(distinct.len(), 168);
+    }
+
+    #[test]
+    fn test_find_cliques_2() {
+        // isolated nodes and self-loops
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 1)];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let mut graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        graph.add_node(Node::from_name(2));
+        let mut cliques: Vec<Vec<i32>> = clique::find_cliques(&graph)
+            .unwrap()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        cliques.sort();
+        assert_eq!(cliques, vec![vec![0, 1], vec![2]]);
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        assert_eq!(clique::find_cliques(&graph).unwrap().count(), 0);
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let result = clique::find_cliques(&graph);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_enumerate_all_cliques_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let cliques: Vec<Vec<i32>> = clique::enumerate_all_cliques(&graph).unwrap().collect();
+        let counts: Vec<usize> = (1..=5)
+            .map(|k| cliques.iter().filter(|c| c.len() == k).count())
+            .collect();
+        assert_eq!(counts, vec![34, 78, 45, 11, 2]);
+        assert!(cliques.windows(2).all(|w| w[0].len() <= w[1].len()));
+        assert!(cliques.iter().all(|c| is_clique(&graph, c)));
+        let graph = get_graph();
+        let cliques: Vec<Vec<i32>> = clique::enumerate_all_cliques(&graph).unwrap().collect();
+        let counts: Vec<usize> = (1..=4)
+            .map(|k| cliques.iter().filter(|c| c.len() == k).count())
+            .collect();
+        assert_eq!(counts, vec![10, 15, 9, 2]);
+        let graph = get_formula_graph();
+        let cliques: Vec<Vec<i32>> = clique::enumerate_all_cliques(&graph).unwrap().collect();
+        assert_eq!(cliques.len(), 14073);
+        assert_eq!(cliques.last().unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_node_clique_number_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = clique::node_clique_number(&graph, None).unwrap();
+        let expected = vec![
+            5, 5, 5, 5, 3, 3, 3, 5, 4, 2, 3, 2, 3, 5, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 3,
+            4, 4, 3, 4, 4,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        let result = clique::number_of_cliques(&graph, None).unwrap();
+        let expected = vec![
+            13, 6, 7, 3, 2, 3, 3, 1, 3, 2, 2, 1, 1, 2, 1, 1, 1, 1, 1, 2, 1, 1, 1, 3, 2, 2, 1, 3, 2,
+            2, 2, 4, 9, 14,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        let graph = get_graph();
+        let result = clique::node_clique_number(&graph, None).unwrap();
+        assert_eq!(to_vec(&result), vec![4, 4, 4, 4, 4, 4, 3, 3, 2, 1]);
+        let result = clique::number_of_cliques(&graph, None).unwrap();
+        assert_eq!(to_vec(&result), vec![1, 1, 2, 2, 1, 2, 1, 2, 1, 1]);
+        let result = clique::number_of_cliques(&graph, Some(&[0, 7])).unwrap();
+        assert_eq!(result, vec![(0, 1), (7, 2)].into_iter().collect());
+        let result = clique::number_of_cliques(&graph, Some(&[0, 70]));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph = get_formula_graph();
+        let result = clique::node_clique_number(&graph, None).unwrap();
+        assert_eq!(result.values().sum::<usize>(), 387);
+        let result = clique::number_of_cliques(&graph, Some(&[0, 7])).unwrap();
+        assert_eq!(result, vec![(0, 56), (7, 50)].into_iter().collect());
+    }
+
+    #[test]
+    fn test_max_weight_clique_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let weights: HashMap<i32, f64> = (0..34).map(|v| (v, ((v * 7) % 5 + 1) as f64)).collect();
+        let (nodes, weight) = clique::max_weight_clique(&graph, Some(&weights)).unwrap();
+        assert_eq!(sorted(nodes), vec![0, 1, 2, 3, 7]);
+        assert_eq!(weight, 16.0);
+        let graph = get_graph();
+        let weights: HashMap<i32, f64> = vec![2.0, 1.0, 3.0, 2.0, 1.0, 4.0, 5.0, 3.0, 6.0, 2.0]
+            .into_iter()
+            .enumerate()
+            .map(|(v, w)| (v as i32, w))
+            .collect();
+        let (nodes, weight) = clique::max_weight_clique(&graph, Some(&weights)).unwrap();
+        assert_eq!(sorted(nodes), vec![5, 6, 7]);
+        assert_eq!(weight, 12.0);
+        let (nodes, weight) = clique::max_weight_clique(&graph, None).unwrap();
+        assert!(is_clique(&graph, &nodes));
+        assert_eq!(weight, 4.0);
+        let graph = get_formula_graph();
+        let weights: HashMap<i32, f64> = (0..50).map(|v| (v, ((v * 13) % 11 + 1) as f64)).collect();
+        let (nodes, weight) = clique::max_weight_clique(&graph, Some(&weights)).unwrap();
+        assert_eq!(sorted(nodes), vec![3, 5, 7, 14, 21, 28, 35, 42, 49]);
+        assert_eq!(weight, 65.0);
+        let (nodes, weight) = clique::max_weight_clique(&graph, None).unwrap();
+        assert!(is_clique(&graph, &nodes));
+        assert_eq!(weight, 9.0);
+    }
+
+    #[test]
+    fn test_max_weight_clique_2() {
+        // compare with the maximal cliques
+        for seed in 0..20 {
+            let graph =
+                generators::random::fast_gnp_random_graph(30, 0.4, false, Some(seed)).unwrap();
+            let weights: HashMap<i32, f64> = (0..30)
+                .map(|v| (v, ((v * 17 + seed as i32) % 9) as f64 * 0.5))
+                .collect();
+            let (nodes, weight) = clique::max_weight_clique(&graph, Some(&weights)).unwrap();
+            assert!(is_clique(&graph, &nodes));
+            assert_eq!(nodes.iter().map(|v| weights[v]).sum::<f64>(), weight);
+            let expected = clique::find_cliques(&graph)
+                .unwrap()
+                .map(|c| c.iter().map(|v| weights[v]).sum::<f64>())
+                .fold(0.0, f64::max);
+            assert_eq!(weight, expected);
+        }
+        let graph = generators::social::karate_club_graph();
+        let weights: HashMap<i32, f64> = (0..33).map(|v| (v, 1.0)).collect();
+        let result = clique::max_weight_clique(&graph, Some(&weights));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    fn is_clique(graph: &Graph<i32, ()>, nodes: &[i32]) -> bool {
+        nodes.iter().enumerate().all(|(i, u)| {
+            nodes[(i + 1)..]
+                .iter()
+                .all(|v| graph.get_edge(*u, *v).is_ok())
+        })
+    }
+
+    fn sorted(mut nodes: Vec<i32>) -> Vec<i32> {
+        nodes.sort();
+        nodes
+    }
+
+    fn to_vec(values: &HashMap<i32, usize>) -> Vec<usize> {
+        (0..values.len() as i32).map(|n| values[&n]).collect()
+    }
+
+    /// Two 4-cliques sharing the edge `2-3`, a triangle and an edge hanging off them,
+    /// and the isolated node `9`.
+    fn get_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+            (3, 4),
+            (3, 5),
+            (4, 5),
+            (5, 6),
+            (5, 7),
+            (6, 7),
+            (7, 8),
+        ];
+        let edges = edges.into_iter().map(|(u, v)| Edge::new(u, v)).collect();
+        let nodes = (0..10).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    /// An undirected graph on `0..50` with many overlapping cliques.
+    fn get_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..50 {
+            for v in (u + 1)..50 {
+                if (u * 3 + v * 5 + u * v) % 7 < 3 {
+                    edges.push(Edge::new(u, v));
+                }
+            }
+        }
+        let nodes = (0..50).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+}
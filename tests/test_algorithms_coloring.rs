@@ -0,0 +1,226 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::coloring::{self, ColoringStrategy},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+    use std::collections::HashMap;
+
+    const STRATEGIES: [ColoringStrategy; 7] = [
+        ColoringStrategy::LargestFirst,
+        ColoringStrategy::SmallestLast,
+        ColoringStrategy::RandomSequential(Some(1)),
+        ColoringStrategy::ConnectedSequentialBfs,
+        ColoringStrategy::ConnectedSequentialDfs,
+        ColoringStrategy::Dsatur,
+        ColoringStrategy::IndependentSet,
+    ];
+
+    #[test]
+    fn test_greedy_color_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = coloring::greedy_color(&graph, ColoringStrategy::LargestFirst);
+        let expected = vec![
+            0, 1, 2, 3, 1, 1, 2, 4, 3, 1, 2, 1, 1, 4, 2, 2, 0, 2, 2, 2, 2, 2, 2, 2, 0, 1, 1, 1, 1,
+            3, 2, 2, 1, 0,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        for strategy in STRATEGIES {
+            let result = coloring::greedy_color(&graph, strategy);
+            assert_eq!(result.len(), 34);
+            assert!(coloring::is_coloring(&graph, &result).unwrap());
+            let number_of_colors = result.values().max().unwrap() + 1;
+            assert!((5..=6).contains(&number_of_colors));
+        }
+    }
+
+    #[test]
+    fn test_greedy_color_2() {
+        // strategies that color some graphs optimally
+        let graph = get_grid_graph(6, 9);
+        for strategy in [
+            ColoringStrategy::ConnectedSequentialBfs,
+            ColoringStrategy::ConnectedSequentialDfs,
+            ColoringStrategy::Dsatur,
+        ] {
+            let result = coloring::greedy_color(&graph, strategy);
+            assert!(coloring::is_coloring(&graph, &result).unwrap());
+            assert_eq!(result.values().max(), Some(&1));
+        }
+        let graph = get_cycle_graph(9);
+        let result = coloring::greedy_color(&graph, ColoringStrategy::Dsatur);
+        assert_eq!(result.values().max(), Some(&2));
+        let graph = generators::classic::complete_graph(7, false);
+        for strategy in STRATEGIES {
+            let result = coloring::greedy_color(&graph, strategy);
+            let mut colors: Vec<usize> = result.values().cloned().collect();
+            colors.sort();
+            assert_eq!(colors, (0..7).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn test_greedy_color_3() {
+        // smallest-last uses at most one more color than the degeneracy, which is three for a
+        // triangulated grid
+        let graph = get_triangulated_grid_graph(8, 8);
+        let result = coloring::greedy_color(&graph, ColoringStrategy::SmallestLast);
+        assert!(coloring::is_coloring(&graph, &result).unwrap());
+        assert!(*result.values().max().unwrap() <= 3);
+        let result = coloring::greedy_color(&graph, ColoringStrategy::IndependentSet);
+        assert!(coloring::is_coloring(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_greedy_color_4() {
+        // random orders are deterministic with a seed, and edge directions and self-loops are
+        // ignored
+        let graph = generators::random::fast_gnp_random_graph(50, 0.2, true, Some(3)).unwrap();
+        let result1 = coloring::greedy_color(&graph, ColoringStrategy::RandomSequential(Some(7)));
+        let result2 = coloring::greedy_color(&graph, ColoringStrategy::RandomSequential(Some(7)));
+        assert_eq!(result1, result2);
+        assert!(coloring::is_coloring(&graph, &result1).unwrap());
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 1), Edge::new(2, 1)];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = coloring::greedy_color(&graph, ColoringStrategy::LargestFirst);
+        assert_eq!(to_vec(&result), vec![1, 0, 1]);
+        assert!(coloring::is_coloring(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_equitable_color_1() {
+        let graph = generators::social::karate_club_graph();
+        for num_colors in [18, 19, 25, 34, 40] {
+            let result = coloring::equitable_color(&graph, num_colors).unwrap();
+            assert!(coloring::is_equitable(&graph, &result, Some(num_colors)).unwrap());
+        }
+        let result = coloring::equitable_color(&graph, 17);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_equitable_color_2() {
+        // graphs whose largest degree is close to the number of colors
+        for seed in 0..200 {
+            let graph = get_bounded_degree_graph(16, 4, seed);
+            let result = coloring::equitable_color(&graph, 5).unwrap();
+            assert_eq!(result.len(), 16);
+            assert!(coloring::is_equitable(&graph, &result, Some(5)).unwrap());
+            let mut sizes = get_class_sizes(&result);
+            sizes.sort();
+            assert_eq!(sizes, vec![3, 3, 3, 3, 4]);
+        }
+        for seed in 0..20 {
+            let graph =
+                generators::random::fast_gnp_random_graph(60, 0.1, false, Some(seed)).unwrap();
+            let max_degree = graph
+                .get_all_node_names()
+                .into_iter()
+                .map(|n| graph.get_node_degree(*n).unwrap())
+                .max()
+                .unwrap();
+            let result = coloring::equitable_color(&graph, max_degree + 1).unwrap();
+            assert!(coloring::is_equitable(&graph, &result, Some(max_degree + 1)).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_is_equitable_1() {
+        let graph = get_cycle_graph(6);
+        let coloring = vec![(0, 0), (1, 1), (2, 0), (3, 1), (4, 0), (5, 1)]
+            .into_iter()
+            .collect();
+        assert!(coloring::is_equitable(&graph, &coloring, None).unwrap());
+        assert!(coloring::is_equitable(&graph, &coloring, Some(2)).unwrap());
+        assert!(!coloring::is_equitable(&graph, &coloring, Some(3)).unwrap());
+        let coloring = vec![(0, 0), (1, 1), (2, 0), (3, 1), (4, 0), (5, 2)]
+            .into_iter()
+            .collect();
+        assert!(!coloring::is_equitable(&graph, &coloring, None).unwrap());
+        let coloring = vec![(0, 0), (1, 1), (2, 0), (3, 1), (4, 2)]
+            .into_iter()
+            .collect();
+        assert!(!coloring::is_coloring(&graph, &coloring).unwrap());
+        let coloring = vec![(0, 0), (9, 1)].into_iter().collect();
+        let result = coloring::is_coloring(&graph, &coloring);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    fn to_vec(coloring: &HashMap<i32, usize>) -> Vec<usize> {
+        (0..coloring.len() as i32).map(|n| coloring[&n]).collect()
+    }
+
+    /// The number of nodes of each color, by color.
+    fn get_class_sizes(coloring: &HashMap<i32, usize>) -> Vec<usize> {
+        let mut sizes = vec![0; coloring.values().max().unwrap() + 1];
+        for c in coloring.values() {
+            sizes[*c] += 1;
+        }
+        sizes
+    }
+
+    fn get_cycle_graph(n: i32) -> Graph<i32, ()> {
+        let edges = (0..n).map(|i| Edge::new(i, (i + 1) % n)).collect();
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn get_grid_graph(rows: i32, columns: i32) -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for r in 0..rows {
+            for c in 0..columns {
+                let u = r * columns + c;
+                if c + 1 < columns {
+                    edges.push(Edge::new(u, u + 1));
+                }
+                if r + 1 < rows {
+                    edges.push(Edge::new(u, u + columns));
+                }
+            }
+        }
+        let nodes = (0..rows * columns).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    fn get_triangulated_grid_graph(rows: i32, columns: i32) -> Graph<i32, ()> {
+        let mut graph = get_grid_graph(rows, columns);
+        for r in 0..(rows - 1) {
+            for c in 0..(columns - 1) {
+                let u = r * columns + c;
+                graph.add_edge(Edge::new(u, u + columns + 1)).unwrap();
+            }
+        }
+        graph
+    }
+
+    /// A graph whose nodes have at most `max_degree` neighbors, made by adding edges between
+    /// pseudo-random pairs of nodes.
+    fn get_bounded_degree_graph(n: i32, max_degree: usize, seed: u64) -> Graph<i32, ()> {
+        let candidates =
+            generators::random::fast_gnp_random_graph(n, 0.5, false, Some(seed)).unwrap();
+        let mut degrees = vec![0; n as usize];
+        let mut edges = vec![];
+        for edge in candidates.get_all_edges() {
+            let (u, v) = (edge.u as usize, edge.v as usize);
+            if degrees[u] < max_degree && degrees[v] < max_degree {
+                degrees[u] += 1;
+                degrees[v] += 1;
+                edges.push(Edge::new(edge.u, edge.v));
+            }
+        }
+        let nodes = (0..n).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+}
@@ -0,0 +1,206 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::components, generators, Edge, Graph, GraphSpecs};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_biconnected_components_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::biconnected_components(&graph).unwrap();
+        let mut result: Vec<Vec<i32>> = result.into_iter().map(sorted).collect();
+        result.sort();
+        let expected = vec![
+            vec![
+                0, 1, 2, 3, 7, 8, 9, 12, 13, 14, 15, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+                28, 29, 30, 31, 32, 33,
+            ],
+            vec![0, 4, 5, 6, 10, 16],
+            vec![0, 11],
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_biconnected_components_2() {
+        let graph = get_barbell_graph();
+        let result = components::biconnected_components(&graph).unwrap();
+        let mut result: Vec<Vec<i32>> = result.into_iter().map(sorted).collect();
+        result.sort();
+        let expected = vec![
+            vec![0, 1, 2, 3],
+            vec![3, 4],
+            vec![4, 5],
+            vec![5, 6],
+            vec![6, 7, 8, 9],
+        ];
+        assert_eq!(result, expected);
+        let graph = generators::classic::complete_graph(4, true);
+        assert!(components::biconnected_components(&graph).is_err());
+    }
+
+    #[test]
+    fn test_biconnected_component_edges_1() {
+        let graph = get_barbell_graph();
+        let result = components::biconnected_component_edges(&graph).unwrap();
+        assert_eq!(result.len(), 5);
+        let total: usize = result.iter().map(|c| c.len()).sum();
+        assert_eq!(total, graph.number_of_edges());
+        let all: HashSet<(i32, i32)> = result
+            .into_iter()
+            .flatten()
+            .map(|(u, v)| (u.min(v), u.max(v)))
+            .collect();
+        assert_eq!(all.len(), graph.number_of_edges());
+    }
+
+    #[test]
+    fn test_articulation_points_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::articulation_points(&graph).unwrap();
+        assert_eq!(result, vec![0].into_iter().collect());
+        let graph = get_barbell_graph();
+        let result = components::articulation_points(&graph).unwrap();
+        assert_eq!(result, vec![3, 4, 5, 6].into_iter().collect());
+    }
+
+    #[test]
+    fn test_articulation_points_2() {
+        // a deep path shouldn't overflow the stack
+        let edges: Vec<_> = (0..100_000).map(|i| Edge::new(i, i + 1)).collect();
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = components::articulation_points(&graph).unwrap();
+        assert_eq!(result.len(), 99_999);
+    }
+
+    #[test]
+    fn test_bridges_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::bridges(&graph, None).unwrap();
+        assert_eq!(result, vec![(0, 11)]);
+        let graph = get_barbell_graph();
+        let result: HashSet<(i32, i32)> = components::bridges(&graph, None)
+            .unwrap()
+            .into_iter()
+            .map(|(u, v)| (u.min(v), u.max(v)))
+            .collect();
+        assert_eq!(result, vec![(3, 4), (4, 5), (5, 6)].into_iter().collect());
+    }
+
+    #[test]
+    fn test_bridges_2() {
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(1, 2),
+            Edge::new(3, 4),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: graphrs::MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = components::bridges(&graph, None).unwrap();
+        assert_eq!(result, vec![(0, 1), (3, 4)]);
+        let result = components::bridges(&graph, Some(4)).unwrap();
+        assert_eq!(result, vec![(4, 3)]);
+        assert!(components::bridges(&graph, Some(5)).is_err());
+    }
+
+    #[test]
+    fn test_local_bridges_1() {
+        let graph = generators::social::karate_club_graph();
+        let result = components::local_bridges(&graph, true, false).unwrap();
+        let expected = vec![
+            (0, 11, Some(f64::INFINITY)),
+            (0, 31, Some(3.0)),
+            (1, 30, Some(3.0)),
+            (2, 9, Some(3.0)),
+            (2, 27, Some(3.0)),
+            (2, 28, Some(3.0)),
+            (9, 33, Some(3.0)),
+            (13, 33, Some(3.0)),
+            (19, 33, Some(3.0)),
+            (23, 25, Some(3.0)),
+            (24, 27, Some(3.0)),
+        ];
+        assert_eq!(sorted_bridges(result), expected);
+        let result = components::local_bridges(&graph, false, false).unwrap();
+        assert_eq!(result.len(), 11);
+        assert!(result.iter().all(|(_, _, span)| span.is_none()));
+    }
+
+    #[test]
+    fn test_local_bridges_2() {
+        let edges = vec![
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, 2.0),
+            Edge::with_weight(2, 3, 3.0),
+            Edge::with_weight(3, 0, 4.0),
+            Edge::with_weight(3, 4, 1.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = components::local_bridges(&graph, true, true).unwrap();
+        let expected = vec![
+            (0, 1, Some(9.0)),
+            (0, 3, Some(6.0)),
+            (1, 2, Some(8.0)),
+            (2, 3, Some(7.0)),
+            (3, 4, Some(f64::INFINITY)),
+        ];
+        assert_eq!(sorted_bridges(result), expected);
+        let result = components::local_bridges(&graph, true, false).unwrap();
+        assert!(result
+            .iter()
+            .all(|(_, _, span)| *span == Some(3.0) || *span == Some(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_is_biconnected_1() {
+        let graph = generators::social::karate_club_graph();
+        assert!(!components::is_biconnected(&graph).unwrap());
+        let graph = generators::classic::complete_graph(5, false);
+        assert!(components::is_biconnected(&graph).unwrap());
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        assert!(!components::is_biconnected(&graph).unwrap());
+        let edges = vec![Edge::new(0, 1)];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert!(components::is_biconnected(&graph).unwrap());
+    }
+
+    fn get_barbell_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for offset in [0, 6] {
+            for u in 0..4 {
+                for v in (u + 1)..4 {
+                    edges.push(Edge::new(u + offset, v + offset));
+                }
+            }
+        }
+        edges.push(Edge::new(3, 4));
+        edges.push(Edge::new(4, 5));
+        edges.push(Edge::new(5, 6));
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn sorted(component: HashSet<i32>) -> Vec<i32> {
+        let mut v: Vec<i32> = component.into_iter().collect();
+        v.sort();
+        v
+    }
+
+    fn sorted_bridges(bridges: Vec<(i32, i32, Option<f64>)>) -> Vec<(i32, i32, Option<f64>)> {
+        let mut bridges: Vec<(i32, i32, Option<f64>)> = bridges
+            .into_iter()
+            .map(|(u, v, span)| (u.min(v), u.max(v), span))
+            .collect();
+        bridges.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        bridges
+    }
+}
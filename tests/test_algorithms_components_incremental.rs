@@ -0,0 +1,74 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::components, generators, Edge, ErrorKind, Graph, GraphSpecs};
+
+    #[test]
+    fn test_incremental_components_1() {
+        let graph = generators::random::fast_gnp_random_graph(200, 0.005, false, Some(1)).unwrap();
+        let mut incremental = components::IncrementalComponents::from_graph(&graph);
+        let expected = components::number_of_connected_components(&graph).unwrap();
+        assert_eq!(incremental.number_of_components(), expected);
+        assert_eq!(incremental.number_of_nodes(), 200);
+        let mut result: Vec<Vec<i32>> = incremental
+            .components()
+            .into_iter()
+            .map(|c| {
+                let mut v: Vec<i32> = c.into_iter().collect();
+                v.sort();
+                v
+            })
+            .collect();
+        result.sort();
+        let mut expected: Vec<Vec<i32>> = components::connected_components(&graph)
+            .unwrap()
+            .into_iter()
+            .map(|c| {
+                let mut v: Vec<i32> = c.into_iter().collect();
+                v.sort();
+                v
+            })
+            .collect();
+        expected.sort();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_incremental_components_2() {
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 3), Edge::new(4, 5)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let mut incremental = components::IncrementalComponents::from_graph(&graph);
+        assert_eq!(incremental.number_of_components(), 3);
+        assert!(incremental.same_component(&1, &0).unwrap());
+        assert!(!incremental.same_component(&1, &2).unwrap());
+        assert!(incremental.add_edge(1, 2));
+        assert!(!incremental.add_edge(3, 0));
+        assert_eq!(incremental.number_of_components(), 2);
+        assert_eq!(incremental.component_size(&3).unwrap(), 4);
+        assert_eq!(incremental.component_size(&5).unwrap(), 2);
+        assert!(incremental.add_edge(6, 7));
+        assert_eq!(incremental.number_of_components(), 3);
+        assert!(incremental.add_node(8));
+        assert!(!incremental.add_node(8));
+        assert_eq!(incremental.number_of_components(), 4);
+        assert_eq!(incremental.number_of_nodes(), 9);
+    }
+
+    #[test]
+    fn test_incremental_components_3() {
+        let mut incremental = components::IncrementalComponents::<i32>::new();
+        assert_eq!(incremental.number_of_components(), 0);
+        let result = incremental.same_component(&1, &2);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let result = incremental.component_size(&1);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        for i in 0..999 {
+            incremental.add_edge(i, i + 1);
+        }
+        assert_eq!(incremental.number_of_components(), 1);
+        assert_eq!(incremental.component_size(&0).unwrap(), 1000);
+    }
+}
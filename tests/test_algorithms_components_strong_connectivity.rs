@@ -3,7 +3,7 @@ mod utility;
 #[cfg(test)]
 mod tests {
 
-    use graphrs::{algorithms::components, Edge, Graph, GraphSpecs};
+    use graphrs::{algorithms::components, generators, Edge, Graph, GraphSpecs};
     use std::sync::Arc;
 
     #[test]
@@ -119,4 +119,109 @@ mod tests {
         let result = components::node_connected_component(&graph, &"p3").unwrap();
         assert_eq!(result.len(), 3);
     }
+
+    #[test]
+    fn test_condensation_1() {
+        let graph = get_graph_1();
+        let (condensed, mapping) = components::condensation(&graph).unwrap();
+        assert_eq!(condensed.number_of_nodes(), 4);
+        assert_eq!(condensed.number_of_edges(), 2);
+        assert_eq!(mapping.len(), 8);
+        assert_eq!(mapping[&1], mapping[&2]);
+        assert_eq!(mapping[&1], mapping[&3]);
+        assert_eq!(mapping[&4], mapping[&5]);
+        assert_eq!(mapping[&6], mapping[&7]);
+        assert_ne!(mapping[&1], mapping[&4]);
+        assert!(condensed.get_edge(mapping[&3], mapping[&4]).is_ok());
+        assert!(condensed.get_edge(mapping[&6], mapping[&5]).is_ok());
+        // components are numbered in topological order
+        for edge in condensed.get_all_edges() {
+            assert!(edge.u < edge.v);
+        }
+    }
+
+    #[test]
+    fn test_condensation_2() {
+        let edges = vec![
+            Edge::with_weight("n1", "n2", 1.5),
+            Edge::with_weight("n2", "n1", 1.0),
+            Edge::with_weight("n1", "n3", 2.0),
+            Edge::with_weight("n2", "n3", 3.0),
+            Edge::with_weight("n3", "n4", 0.5),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let (condensed, mapping) = components::condensation(&graph).unwrap();
+        assert_eq!(condensed.number_of_nodes(), 3);
+        let edge = condensed.get_edge(mapping["n1"], mapping["n3"]).unwrap();
+        assert_eq!(edge.weight, 5.0);
+        let edge = condensed.get_edge(mapping["n3"], mapping["n4"]).unwrap();
+        assert_eq!(edge.weight, 0.5);
+    }
+
+    #[test]
+    fn test_condensation_3() {
+        let graph = generators::random::fast_gnp_random_graph(250, 0.02, true, Some(1)).unwrap();
+        let (condensed, mapping) = components::condensation(&graph).unwrap();
+        assert_eq!(condensed.number_of_nodes(), 6);
+        assert_eq!(mapping.len(), 250);
+        let edges = vec![Edge::new("n1", "n2")];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert!(components::condensation(&graph).is_err());
+    }
+
+    #[test]
+    fn test_attracting_components_1() {
+        let graph = get_graph_1();
+        let result = components::attracting_components(&graph).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.contains(&vec![4, 5].into_iter().collect()));
+        assert!(result.contains(&vec![8].into_iter().collect()));
+    }
+
+    #[test]
+    fn test_is_semiconnected_1() {
+        let graph = get_graph_1();
+        assert!(!components::is_semiconnected(&graph).unwrap());
+        let edges = vec![
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 1),
+            Edge::new(3, 4),
+            Edge::new(4, 5),
+            Edge::new(5, 4),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert!(components::is_semiconnected(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_semiconnected_2() {
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed_create_missing());
+        assert!(components::is_semiconnected(&graph).is_err());
+        let graph = generators::classic::complete_graph(3, true);
+        assert!(components::is_semiconnected(&graph).unwrap());
+    }
+
+    fn get_graph_1() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 1),
+            Edge::new(3, 4),
+            Edge::new(4, 5),
+            Edge::new(5, 4),
+            Edge::new(6, 5),
+            Edge::new(6, 7),
+            Edge::new(7, 6),
+            Edge::new(8, 8),
+        ];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
 }
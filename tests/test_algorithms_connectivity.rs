@@ -0,0 +1,579 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::connectivity, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_node_connectivity_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            1
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some(0), Some(33)).unwrap(),
+            6
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some(5), Some(25)).unwrap(),
+            1
+        );
+        // the edge between adjacent nodes counts as one path
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some(0), Some(1)).unwrap(),
+            9
+        );
+    }
+
+    #[test]
+    fn test_node_connectivity_2() {
+        // values from NetworkX
+        let graph = get_petersen_graph();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            3
+        );
+        let graph = get_directed_graph();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            2
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some(0), Some(7)).unwrap(),
+            2
+        );
+        let graph = get_formula_graph();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            4
+        );
+        assert_eq!(
+            connectivity::node_connectivity(&graph, Some(0), Some(7)).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_node_connectivity_3() {
+        // a disconnected graph and a complete graph
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            0
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            0
+        );
+        let graph = get_complete_graph(6);
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            5
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            5
+        );
+        assert_eq!(
+            connectivity::minimum_node_cut(&graph, None, None)
+                .unwrap()
+                .len(),
+            5
+        );
+        assert_eq!(
+            connectivity::minimum_edge_cut(&graph, None, None)
+                .unwrap()
+                .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_node_connectivity_4() {
+        // the complete digraph on three nodes, a 2-cycle, and a digraph that node 1 separates
+        let specs = GraphSpecs::directed_create_missing();
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 0),
+            Edge::new(0, 2),
+            Edge::new(2, 0),
+            Edge::new(1, 2),
+            Edge::new(2, 1),
+        ];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs.clone()).unwrap();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            2
+        );
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 0)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs.clone()).unwrap();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            1
+        );
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(2, 0),
+            Edge::new(2, 1),
+        ];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            1
+        );
+        let cut = connectivity::minimum_node_cut(&graph, None, None).unwrap();
+        assert_eq!(cut, vec![1].into_iter().collect());
+    }
+
+    #[test]
+    fn test_node_connectivity_5() {
+        // random digraphs, against the minimum local connectivity of the non-adjacent pairs
+        for seed in 0..40 {
+            let n = 5 + (seed % 4) as i32;
+            let p = [0.3, 0.5, 0.7][(seed % 3) as usize];
+            let graph = generators::random::fast_gnp_random_graph(n, p, true, Some(seed)).unwrap();
+            let mut expected = (n - 1) as usize;
+            for u in 0..n {
+                for v in 0..n {
+                    if u != v && graph.get_edge(u, v).is_err() {
+                        let local =
+                            connectivity::node_connectivity(&graph, Some(u), Some(v)).unwrap();
+                        expected = expected.min(local);
+                    }
+                }
+            }
+            let k = connectivity::node_connectivity(&graph, None, None).unwrap();
+            assert_eq!(k, expected);
+            if k == 0 {
+                continue;
+            }
+            let cut = connectivity::minimum_node_cut(&graph, None, None).unwrap();
+            assert_eq!(cut.len(), k);
+            if k < (n - 1) as usize {
+                assert!(is_disconnected_without_nodes(&graph, &cut));
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_connectivity_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            1
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some(0), Some(33)).unwrap(),
+            10
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some(5), Some(25)).unwrap(),
+            3
+        );
+        let graph = get_petersen_graph();
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            3
+        );
+        let graph = get_directed_graph();
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            2
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some(0), Some(7)).unwrap(),
+            3
+        );
+        let graph = get_formula_graph();
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            7
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some(0), Some(7)).unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_edge_connectivity_2() {
+        // multiple edges add to the connectivity
+        let edges = vec![
+            Edge::new("a", "b"),
+            Edge::new("a", "b"),
+            Edge::new("b", "c"),
+            Edge::new("b", "c"),
+            Edge::new("b", "c"),
+            Edge::new("c", "a"),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: graphrs::MissingNodeStrategy::Create,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, None, None).unwrap(),
+            3
+        );
+        assert_eq!(
+            connectivity::edge_connectivity(&graph, Some("b"), Some("c")).unwrap(),
+            4
+        );
+        let cut = connectivity::minimum_edge_cut(&graph, None, None).unwrap();
+        assert_eq!(cut.len(), 3);
+        let paths = connectivity::edge_disjoint_paths(&graph, "b", "c").unwrap();
+        assert_eq!(paths.len(), 4);
+        assert_eq!(
+            connectivity::node_connectivity(&graph, None, None).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_minimum_node_cut_1() {
+        let graph = generators::social::karate_club_graph();
+        let cut = connectivity::minimum_node_cut(&graph, None, None).unwrap();
+        assert_eq!(cut, vec![0].into_iter().collect());
+        let cut = connectivity::minimum_node_cut(&graph, Some(0), Some(33)).unwrap();
+        assert_eq!(cut.len(), 6);
+        assert!(!cut.contains(&0) && !cut.contains(&33));
+        assert!(!is_connected_without_nodes(&graph, 0, 33, &cut));
+        let result = connectivity::minimum_node_cut(&graph, Some(0), Some(1));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_minimum_node_cut_2() {
+        for graph in [
+            get_petersen_graph(),
+            get_directed_graph(),
+            get_formula_graph(),
+        ] {
+            let k = connectivity::node_connectivity(&graph, None, None).unwrap();
+            let cut = connectivity::minimum_node_cut(&graph, None, None).unwrap();
+            assert_eq!(cut.len(), k);
+            assert!(is_disconnected_without_nodes(&graph, &cut));
+        }
+    }
+
+    #[test]
+    fn test_minimum_edge_cut_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let cut = connectivity::minimum_edge_cut(&graph, None, None).unwrap();
+        assert_eq!(cut, vec![(11, 0)]);
+        let cut = connectivity::minimum_edge_cut(&graph, Some(11), Some(33)).unwrap();
+        assert_eq!(cut, vec![(11, 0)]);
+        let cut = connectivity::minimum_edge_cut(&graph, Some(0), Some(33)).unwrap();
+        assert_eq!(cut.len(), 10);
+        assert!(cut.iter().all(|(u, v)| graph.get_edge(*u, *v).is_ok()));
+    }
+
+    #[test]
+    fn test_minimum_edge_cut_2() {
+        let graph = get_directed_graph();
+        // values from NetworkX
+        let cut = connectivity::minimum_edge_cut(&graph, None, None).unwrap();
+        assert_eq!(cut.len(), 2);
+        assert!(cut.iter().all(|(u, v)| graph.get_edge(*u, *v).is_ok()));
+        let cut = connectivity::minimum_edge_cut(&graph, Some(0), Some(7)).unwrap();
+        assert_eq!(cut.len(), 3);
+        assert!(cut.iter().all(|(u, v)| graph.get_edge(*u, *v).is_ok()));
+        let graph = get_formula_graph();
+        let cut = connectivity::minimum_edge_cut(&graph, None, None).unwrap();
+        assert_eq!(cut.len(), 7);
+        assert!(cut.iter().all(|(u, v)| graph.get_edge(*u, *v).is_ok()));
+        let cut = connectivity::minimum_edge_cut(&graph, Some(0), Some(7)).unwrap();
+        assert_eq!(cut.len(), 7);
+    }
+
+    #[test]
+    fn test_disjoint_paths_1() {
+        let graph = generators::social::karate_club_graph();
+        let paths = connectivity::node_disjoint_paths(&graph, 0, 33).unwrap();
+        assert_eq!(paths.len(), 6);
+        assert_valid_paths(&graph, &paths, 0, 33);
+        let inner: Vec<i32> = paths
+            .iter()
+            .flat_map(|p| p[1..(p.len() - 1)].to_vec())
+            .collect();
+        let unique: HashSet<i32> = inner.iter().cloned().collect();
+        assert_eq!(inner.len(), unique.len());
+
+        let paths = connectivity::edge_disjoint_paths(&graph, 0, 33).unwrap();
+        assert_eq!(paths.len(), 10);
+        assert_valid_paths(&graph, &paths, 0, 33);
+        let edges: Vec<(i32, i32)> = paths
+            .iter()
+            .flat_map(|p| p.windows(2).map(|w| (w[0].min(w[1]), w[0].max(w[1]))))
+            .collect();
+        let unique: HashSet<(i32, i32)> = edges.iter().cloned().collect();
+        assert_eq!(edges.len(), unique.len());
+    }
+
+    #[test]
+    fn test_disjoint_paths_2() {
+        let graph = get_directed_graph();
+        let paths = connectivity::node_disjoint_paths(&graph, 0, 7).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_valid_paths(&graph, &paths, 0, 7);
+        let paths = connectivity::edge_disjoint_paths(&graph, 0, 7).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_valid_paths(&graph, &paths, 0, 7);
+        let graph = get_formula_graph();
+        let paths = connectivity::node_disjoint_paths(&graph, 0, 7).unwrap();
+        assert_eq!(paths.len(), 4);
+        assert_valid_paths(&graph, &paths, 0, 7);
+        let paths = connectivity::edge_disjoint_paths(&graph, 0, 7).unwrap();
+        assert_eq!(paths.len(), 7);
+        assert_valid_paths(&graph, &paths, 0, 7);
+        // no path
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        assert!(connectivity::node_disjoint_paths(&graph, 0, 3)
+            .unwrap()
+            .is_empty());
+        assert!(connectivity::edge_disjoint_paths(&graph, 1, 0)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_k_edge_components_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = connectivity::k_edge_components(&graph, 1).unwrap();
+        assert_eq!(result.len(), 1);
+        let result = connectivity::k_edge_components(&graph, 2).unwrap();
+        assert_eq!(
+            sorted(result),
+            vec![(0..34).filter(|n| *n != 11).collect::<Vec<i32>>(), vec![11]]
+        );
+        let result = connectivity::k_edge_components(&graph, 5).unwrap();
+        let result = sorted(result);
+        assert_eq!(result[0], vec![0, 1, 2, 3, 8, 13, 23, 31, 32, 33]);
+        assert_eq!(result.len(), 25);
+        let result = connectivity::k_edge_components(&graph, 4).unwrap();
+        assert_eq!(
+            sorted(result)[0],
+            vec![0, 1, 2, 3, 5, 6, 7, 8, 13, 23, 27, 29, 30, 31, 32, 33]
+        );
+    }
+
+    #[test]
+    fn test_k_edge_components_2() {
+        // values from NetworkX
+        let graph = get_directed_graph();
+        let result = connectivity::k_edge_components(&graph, 2).unwrap();
+        assert_eq!(result.len(), 1);
+        let result = sorted(connectivity::k_edge_components(&graph, 3).unwrap());
+        assert_eq!(result, vec![vec![0, 1, 2, 3, 4, 5, 6, 7], vec![8]]);
+        let result = sorted(connectivity::k_edge_components(&graph, 4).unwrap());
+        assert_eq!(result.len(), 8);
+        assert!(result.contains(&vec![0, 3]));
+        let graph = get_formula_graph();
+        let result = connectivity::k_edge_components(&graph, 7).unwrap();
+        assert_eq!(result.len(), 1);
+        let result = sorted(connectivity::k_edge_components(&graph, 8).unwrap());
+        assert_eq!(result.len(), 13);
+        assert!(result.contains(&vec![2, 3, 7, 8, 12, 13, 17, 18]));
+        let result = sorted(connectivity::k_edge_components(&graph, 9).unwrap());
+        assert_eq!(result.len(), 17);
+        assert!(result.contains(&vec![2, 7, 12, 17]));
+        // k = 1 gives the strongly connected components
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(2, 0),
+            Edge::new(2, 3),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = sorted(connectivity::k_edge_components(&graph, 1).unwrap());
+        assert_eq!(result, vec![vec![0, 1, 2], vec![3]]);
+        let result = connectivity::k_edge_components(&graph, 0);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_connectivity_errors() {
+        let graph = generators::social::karate_club_graph();
+        let result = connectivity::node_connectivity(&graph, Some(0), None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = connectivity::edge_connectivity(&graph, Some(0), Some(0));
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = connectivity::edge_disjoint_paths(&graph, 0, 34);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 3)];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = connectivity::minimum_edge_cut(&graph, None, None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = connectivity::node_connectivity(&graph, None, None);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    fn sorted(components: Vec<HashSet<i32>>) -> Vec<Vec<i32>> {
+        let mut components: Vec<Vec<i32>> = components
+            .into_iter()
+            .map(|c| {
+                let mut c: Vec<i32> = c.into_iter().collect();
+                c.sort();
+                c
+            })
+            .collect();
+        components.sort_by_key(|c| (std::cmp::Reverse(c.len()), c[0]));
+        components
+    }
+
+    fn assert_valid_paths(graph: &Graph<i32, ()>, paths: &[Vec<i32>], source: i32, target: i32) {
+        for path in paths {
+            assert_eq!(path[0], source);
+            assert_eq!(path[path.len() - 1], target);
+            for w in path.windows(2) {
+                assert!(graph.get_edge(w[0], w[1]).is_ok());
+            }
+        }
+    }
+
+    fn is_disconnected_without_nodes(graph: &Graph<i32, ()>, removed: &HashSet<i32>) -> bool {
+        let remaining: Vec<i32> = graph
+            .get_all_node_names()
+            .into_iter()
+            .filter(|n| !removed.contains(n))
+            .cloned()
+            .collect();
+        remaining.iter().any(|u| {
+            remaining
+                .iter()
+                .any(|v| u != v && !is_connected_without_nodes(graph, *u, *v, removed))
+        })
+    }
+
+    fn is_connected_without_nodes(
+        graph: &Graph<i32, ()>,
+        source: i32,
+        target: i32,
+        removed: &HashSet<i32>,
+    ) -> bool {
+        let mut seen: HashSet<i32> = vec![source].into_iter().collect();
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            if u == target {
+                return true;
+            }
+            let successors: Vec<i32> = match graph.specs.directed {
+                true => graph
+                    .get_successor_node_names(u)
+                    .unwrap()
+                    .into_iter()
+                    .cloned()
+                    .collect(),
+                false => graph
+                    .get_neighbor_nodes(u)
+                    .unwrap()
+                    .into_iter()
+                    .map(|n| n.name)
+                    .collect(),
+            };
+            for v in successors {
+                if !removed.contains(&v) && seen.insert(v) {
+                    stack.push(v);
+                }
+            }
+        }
+        false
+    }
+
+    fn get_petersen_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for i in 0..5 {
+            edges.push(Edge::new(i, (i + 1) % 5));
+            edges.push(Edge::new(i, i + 5));
+            edges.push(Edge::new(i + 5, (i + 2) % 5 + 5));
+        }
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn get_complete_graph(n: i32) -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..n {
+            for v in (u + 1)..n {
+                edges.push(Edge::new(u, v));
+            }
+        }
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    /// Two complete digraphs, on `0..4` and `4..8`, joined by single edges and through `8`.
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for nodes in [0..4, 4..8] {
+            for u in nodes.clone() {
+                for v in nodes.clone() {
+                    if u != v {
+                        edges.push(Edge::new(u, v));
+                    }
+                }
+            }
+        }
+        for (u, v) in [
+            (0, 4),
+            (1, 5),
+            (6, 2),
+            (7, 3),
+            (3, 8),
+            (8, 4),
+            (7, 8),
+            (8, 0),
+        ] {
+            edges.push(Edge::new(u, v));
+        }
+        let specs = GraphSpecs::directed_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    /// A digraph on `0..20`.
+    fn get_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..20 {
+            for v in 0..20 {
+                if u != v && (u * u + v * 3 + u * v + 1) % 5 < 2 {
+                    edges.push(Edge::new(u, v));
+                }
+            }
+        }
+        let nodes = (0..20).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+    }
+}
@@ -0,0 +1,316 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::core, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_core_number_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::core_number(&graph).unwrap();
+        let expected = vec![
+            4, 4, 4, 4, 3, 3, 3, 4, 4, 2, 3, 1, 2, 4, 2, 2, 2, 2, 2, 3, 2, 2, 2, 3, 3, 3, 2, 3, 3,
+            3, 4, 3, 4, 4,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        let graph = get_graph();
+        let result = core::core_number(&graph).unwrap();
+        assert_eq!(to_vec(&result), vec![4, 4, 4, 4, 4, 3, 3, 2, 2, 0]);
+        let graph = get_formula_graph();
+        let result = core::core_number(&graph).unwrap();
+        let mut expected = vec![6; 60];
+        expected[8] = 2;
+        expected[49] = 2;
+        expected[21] = 4;
+        expected[36] = 4;
+        assert_eq!(to_vec(&result), expected);
+    }
+
+    #[test]
+    fn test_core_number_2() {
+        // values from NetworkX; in-degrees and out-degrees are added up
+        let graph = get_directed_graph();
+        let result = core::core_number(&graph).unwrap();
+        assert_eq!(to_vec(&result), vec![4, 4, 4, 4, 2, 2, 2, 1, 1]);
+        let main_core = core::k_core(&graph, None, Some(&result)).unwrap();
+        assert_eq!(sorted(main_core.get_all_node_names()), vec![0, 1, 2, 3]);
+        assert_eq!(main_core.number_of_edges(), 8);
+        let corona = core::k_corona(&graph, 2, None).unwrap();
+        assert_eq!(sorted(corona.get_all_node_names()), vec![4]);
+        let corona = core::k_corona(&graph, 1, None).unwrap();
+        assert_eq!(sorted(corona.get_all_node_names()), vec![7]);
+        let graph = get_directed_formula_graph();
+        let result = core::core_number(&graph).unwrap();
+        let expected = vec![
+            4, 5, 5, 5, 5, 4, 5, 4, 5, 2, 5, 4, 2, 2, 2, 5, 4, 4, 5, 5, 5, 5, 4, 5, 4, 5, 2, 5, 4,
+            2, 2, 2, 5, 4, 4, 5, 5, 5, 5, 4,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        let main_core = core::k_core(&graph, None, Some(&result)).unwrap();
+        assert_eq!(
+            sorted(main_core.get_all_node_names()),
+            vec![1, 2, 3, 4, 6, 8, 10, 15, 18, 19, 20, 21, 23, 25, 27, 32, 35, 36, 37, 38]
+        );
+        assert_eq!(main_core.number_of_edges(), 50);
+        let corona = core::k_corona(&graph, 2, None).unwrap();
+        assert_eq!(sorted(corona.get_all_node_names()), vec![12, 13, 29, 30]);
+    }
+
+    #[test]
+    fn test_core_number_3() {
+        // isolated nodes, empty graphs and self-loops
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        assert!(core::core_number(&graph).unwrap().is_empty());
+        assert_eq!(
+            core::k_core(&graph, None, None).unwrap().number_of_nodes(),
+            0
+        );
+        let nodes = (0..3).map(Node::from_name).collect();
+        let edges = vec![Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap();
+        let result = core::core_number(&graph).unwrap();
+        assert_eq!(to_vec(&result), vec![0, 1, 1]);
+        let result = core::onion_layers(&graph).unwrap();
+        assert_eq!(to_vec(&result), vec![1, 2, 2]);
+        let result = core::k_truss(&graph, 2).unwrap();
+        assert_eq!(sorted(result.get_all_node_names()), vec![1, 2]);
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        let edges = vec![Edge::new(0, 1), Edge::new(1, 1)];
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = core::core_number(&graph);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+        let result = core::onion_layers(&graph);
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::SelfLoopsFound
+        ));
+    }
+
+    #[test]
+    fn test_k_core_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::k_core(&graph, None, None).unwrap();
+        assert_eq!(
+            sorted(result.get_all_node_names()),
+            vec![0, 1, 2, 3, 7, 8, 13, 30, 32, 33]
+        );
+        assert_eq!(result.number_of_edges(), 25);
+        let result = core::k_core(&graph, Some(3), None).unwrap();
+        assert_eq!(result.number_of_nodes(), 22);
+        assert_eq!(result.number_of_edges(), 55);
+        let result = core::k_core(&graph, Some(2), None).unwrap();
+        assert_eq!(result.number_of_nodes(), 33);
+        assert_eq!(result.number_of_edges(), 77);
+        let result = core::k_core(&graph, Some(5), None).unwrap();
+        assert_eq!(result.number_of_nodes(), 0);
+    }
+
+    #[test]
+    fn test_k_shell_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::k_shell(&graph, None, None).unwrap();
+        assert_eq!(
+            sorted(result.get_all_node_names()),
+            vec![0, 1, 2, 3, 7, 8, 13, 30, 32, 33]
+        );
+        let result = core::k_shell(&graph, Some(2), None).unwrap();
+        assert_eq!(
+            sorted(result.get_all_node_names()),
+            vec![9, 12, 14, 15, 16, 17, 18, 20, 21, 22, 26]
+        );
+    }
+
+    #[test]
+    fn test_k_crust_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::k_crust(&graph, None, None).unwrap();
+        assert_eq!(result.number_of_nodes(), 24);
+        assert_eq!(result.number_of_edges(), 15);
+        let result = core::k_crust(&graph, Some(1), None).unwrap();
+        assert_eq!(sorted(result.get_all_node_names()), vec![11]);
+        let core_numbers: HashMap<i32, usize> = (0..33).map(|n| (n, 1)).collect();
+        let result = core::k_crust(&graph, None, Some(&core_numbers));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_k_corona_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let core_numbers = core::core_number(&graph).unwrap();
+        let result = core::k_corona(&graph, 1, Some(&core_numbers)).unwrap();
+        assert_eq!(sorted(result.get_all_node_names()), vec![11]);
+        let result = core::k_corona(&graph, 2, Some(&core_numbers)).unwrap();
+        assert_eq!(result.number_of_nodes(), 11);
+        let result = core::k_corona(&graph, 3, Some(&core_numbers)).unwrap();
+        assert_eq!(
+            sorted(result.get_all_node_names()),
+            vec![4, 5, 6, 10, 19, 24, 25, 28, 29]
+        );
+        let result = core::k_corona(&graph, 4, Some(&core_numbers)).unwrap();
+        assert_eq!(sorted(result.get_all_node_names()), vec![7, 30, 32, 33]);
+    }
+
+    #[test]
+    fn test_k_truss_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::k_truss(&graph, 3).unwrap();
+        assert_eq!(result.number_of_nodes(), 32);
+        assert_eq!(result.number_of_edges(), 67);
+        let result = core::k_truss(&graph, 4).unwrap();
+        assert_eq!(
+            sorted(result.get_all_node_names()),
+            vec![0, 1, 2, 3, 7, 8, 13, 23, 29, 30, 32, 33]
+        );
+        assert_eq!(result.number_of_edges(), 25);
+        let result = core::k_truss(&graph, 5).unwrap();
+        assert_eq!(sorted(result.get_all_node_names()), vec![0, 1, 2, 3, 7, 13]);
+        assert_eq!(result.number_of_edges(), 14);
+        let parameters = [
+            (get_graph(), [(3, 7, 15), (5, 5, 10), (6, 0, 0)]),
+            (
+                get_formula_graph(),
+                [(3, 55, 178), (4, 45, 137), (5, 26, 73)],
+            ),
+        ];
+        for (graph, trusses) in parameters {
+            for (k, nodes, edges) in trusses {
+                let result = core::k_truss(&graph, k).unwrap();
+                assert_eq!(result.number_of_nodes(), nodes);
+                assert_eq!(result.number_of_edges(), edges);
+            }
+        }
+        let graph = get_directed_graph();
+        let result = core::k_truss(&graph, 3);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_onion_layers_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = core::onion_layers(&graph).unwrap();
+        let expected = vec![
+            7, 6, 7, 6, 3, 3, 3, 5, 6, 2, 3, 1, 2, 6, 2, 2, 2, 2, 2, 3, 2, 2, 2, 4, 3, 3, 2, 4, 3,
+            3, 5, 4, 5, 5,
+        ];
+        assert_eq!(to_vec(&result), expected);
+        let graph = get_graph();
+        let result = core::onion_layers(&graph).unwrap();
+        assert_eq!(to_vec(&result), vec![4, 4, 4, 4, 4, 3, 3, 2, 2, 1]);
+        let graph = get_formula_graph();
+        let result = core::onion_layers(&graph).unwrap();
+        let expected = vec![
+            3, 10, 9, 7, 9, 5, 7, 4, 1, 6, 4, 5, 10, 4, 10, 9, 8, 9, 5, 7, 5, 2, 6, 3, 4, 9, 4, 10,
+            8, 8, 10, 4, 7, 6, 3, 6, 2, 4, 8, 5, 10, 6, 8, 11, 4, 10, 6, 5, 6, 1, 3, 7, 5, 9, 5, 9,
+            10, 3, 11, 7,
+        ];
+        assert_eq!(to_vec(&result), expected);
+    }
+
+    fn to_vec(values: &HashMap<i32, usize>) -> Vec<usize> {
+        (0..values.len() as i32).map(|n| values[&n]).collect()
+    }
+
+    fn sorted(nodes: Vec<&i32>) -> Vec<i32> {
+        let mut nodes: Vec<i32> = nodes.into_iter().cloned().collect();
+        nodes.sort();
+        nodes
+    }
+
+    /// A 5-clique on `0..5`, with `5` and `6` and a path through `7` and `8` attached to
+    /// it, and the isolated node `9`.
+    fn get_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (0, 5),
+            (1, 5),
+            (1, 6),
+            (2, 6),
+            (5, 6),
+            (6, 7),
+            (7, 8),
+            (3, 8),
+        ];
+        get_graph_from_edges(&edges, 10, GraphSpecs::undirected())
+    }
+
+    /// A dense digraph on `0..4`, a cycle on `4..7` pointing into it and a path out of `6`.
+    fn get_directed_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (0, 2),
+            (2, 3),
+            (3, 1),
+            (1, 3),
+            (3, 0),
+            (4, 3),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (6, 7),
+            (7, 8),
+        ];
+        get_graph_from_edges(&edges, 9, GraphSpecs::directed())
+    }
+
+    /// An undirected graph on `0..60`.
+    fn get_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..60 {
+            for v in (u + 1)..60 {
+                if (u * 3 + v * 5 + u * v) % 13 < 2 {
+                    edges.push(Edge::new(u, v));
+                }
+            }
+        }
+        let nodes = (0..60).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    /// A sparse digraph on `0..40`.
+    fn get_directed_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..40 {
+            for v in 0..40 {
+                if u != v && (u * 5 + v * 3 + u * v) % 17 < 1 {
+                    edges.push(Edge::new(u, v));
+                }
+            }
+        }
+        let nodes = (0..40).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+    }
+
+    fn get_graph_from_edges(edges: &[(i32, i32)], n: i32, specs: GraphSpecs) -> Graph<i32, ()> {
+        let edges = edges.iter().map(|(u, v)| Edge::new(*u, *v)).collect();
+        let nodes = (0..n).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+    }
+}
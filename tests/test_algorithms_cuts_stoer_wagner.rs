@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::cuts, generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_stoer_wagner_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let (cut_value, partition) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(cut_value, 3.0);
+        assert_valid_partition(&graph, &partition, cut_value, true);
+        let (cut_value, partition) = cuts::stoer_wagner(&graph, false).unwrap();
+        assert_eq!(cut_value, 1.0);
+        assert_eq!(partition.0.len().min(partition.1.len()), 1);
+        assert_valid_partition(&graph, &partition, cut_value, false);
+    }
+
+    #[test]
+    fn test_stoer_wagner_2() {
+        // two triangles joined by a single edge
+        let edges = vec![
+            Edge::with_weight(0, 1, 3.0),
+            Edge::with_weight(1, 2, 3.0),
+            Edge::with_weight(2, 0, 3.0),
+            Edge::with_weight(2, 3, 1.5),
+            Edge::with_weight(3, 4, 3.0),
+            Edge::with_weight(4, 5, 3.0),
+            Edge::with_weight(5, 3, 3.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let (cut_value, (partition1, partition2)) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(cut_value, 1.5);
+        let side1: HashSet<i32> = vec![0, 1, 2].into_iter().collect();
+        let side2: HashSet<i32> = vec![3, 4, 5].into_iter().collect();
+        assert!(
+            (partition1 == side1 && partition2 == side2)
+                || (partition1 == side2 && partition2 == side1)
+        );
+    }
+
+    #[test]
+    fn test_stoer_wagner_3() {
+        // the example from Stoer and Wagner; values from NetworkX
+        let edges = vec![
+            Edge::with_weight(1, 2, 2.0),
+            Edge::with_weight(1, 5, 3.0),
+            Edge::with_weight(2, 3, 3.0),
+            Edge::with_weight(2, 5, 2.0),
+            Edge::with_weight(2, 6, 2.0),
+            Edge::with_weight(3, 4, 4.0),
+            Edge::with_weight(3, 7, 2.0),
+            Edge::with_weight(4, 7, 2.0),
+            Edge::with_weight(4, 8, 2.0),
+            Edge::with_weight(5, 6, 3.0),
+            Edge::with_weight(6, 7, 1.0),
+            Edge::with_weight(7, 8, 3.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let (cut_value, partition) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(cut_value, 4.0);
+        assert_valid_partition(&graph, &partition, cut_value, true);
+        let side: HashSet<i32> = vec![1, 2, 5, 6].into_iter().collect();
+        assert!(partition.0 == side || partition.1 == side);
+        // value from NetworkX
+        let graph = get_formula_graph();
+        let (cut_value, partition) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(cut_value, 4.0);
+        assert_valid_partition(&graph, &partition, cut_value, true);
+    }
+
+    #[test]
+    fn test_stoer_wagner_4() {
+        // the weights of parallel edges are summed and self-loops are ignored
+        let edges = vec![
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("a", "b", 2.0),
+            Edge::with_weight("b", "c", 4.0),
+            Edge::with_weight("c", "c", 0.5),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            self_loops: true,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let (cut_value, (partition1, partition2)) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(cut_value, 3.0);
+        assert_eq!(partition1.len() + partition2.len(), 3);
+        assert!(partition1.len() == 1 || partition2.len() == 1);
+    }
+
+    #[test]
+    fn test_stoer_wagner_5() {
+        let specs = GraphSpecs::undirected_create_missing();
+        let edges = vec![Edge::new("a", "b"), Edge::new("c", "d")];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs.clone()).unwrap();
+        let result = cuts::stoer_wagner(&graph, false);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = cuts::stoer_wagner(&graph, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        let edges = vec![Edge::with_weight("a", "b", -1.0)];
+        let graph: Graph<&str, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, specs.clone()).unwrap();
+        let result = cuts::stoer_wagner(&graph, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::directed());
+        let result = cuts::stoer_wagner(&graph, true);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+    }
+
+    fn get_formula_graph() -> Graph<i32, ()> {
+        // a path with extra edges between some of its nodes
+        let mut edges = vec![];
+        for u in 0..30 {
+            for v in (u + 1)..30 {
+                if v == u + 1 || (u * v + u + v) % 4 == 0 {
+                    edges.push(Edge::with_weight(u, v, ((u * 7 + v * 3) % 5 + 1) as f64));
+                }
+            }
+        }
+        let nodes = (0..30).map(graphrs::Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    fn assert_valid_partition<T>(
+        graph: &Graph<T, ()>,
+        partition: &(HashSet<T>, HashSet<T>),
+        cut_value: f64,
+        weighted: bool,
+    ) where
+        T: std::hash::Hash + Eq + Clone + Ord + std::fmt::Display + Send + Sync + std::fmt::Debug,
+    {
+        assert!(!partition.0.is_empty() && !partition.1.is_empty());
+        assert_eq!(
+            partition.0.len() + partition.1.len(),
+            graph.number_of_nodes()
+        );
+        let nbunch1: Vec<T> = partition.0.iter().cloned().collect();
+        let nbunch2: Vec<T> = partition.1.iter().cloned().collect();
+        let size = cuts::cut_size(graph, &nbunch1, &nbunch2, weighted).unwrap();
+        assert_eq!(size, cut_value);
+    }
+}
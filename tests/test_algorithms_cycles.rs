@@ -0,0 +1,238 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::cycles, generators, Edge, Graph, GraphSpecs};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_simple_cycles_1() {
+        let graph = get_graph_with_self_loops();
+        let result: HashSet<Vec<i32>> = cycles::simple_cycles(&graph, None)
+            .unwrap()
+            .map(rotate_to_min)
+            .collect();
+        let expected: HashSet<Vec<i32>> =
+            vec![vec![0], vec![2], vec![0, 1, 2], vec![0, 2], vec![1, 2]]
+                .into_iter()
+                .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_simple_cycles_2() {
+        let graph = get_graph_with_self_loops();
+        let result: HashSet<Vec<i32>> = cycles::simple_cycles(&graph, Some(2))
+            .unwrap()
+            .map(rotate_to_min)
+            .collect();
+        let expected: HashSet<Vec<i32>> = vec![vec![0], vec![2], vec![0, 2], vec![1, 2]]
+            .into_iter()
+            .collect();
+        assert_eq!(result, expected);
+        let result: Vec<Vec<i32>> = cycles::simple_cycles(&graph, Some(1)).unwrap().collect();
+        assert_eq!(result.len(), 2);
+        let result: Vec<Vec<i32>> = cycles::simple_cycles(&graph, Some(0)).unwrap().collect();
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_simple_cycles_3() {
+        // the number of simple cycles in a complete directed graph with 5 nodes is
+        // 10 * 1! + 10 * 2! + 5 * 3! + 1 * 4! = 84
+        let graph = generators::classic::complete_graph(5, true);
+        let result: Vec<Vec<i32>> = cycles::simple_cycles(&graph, None).unwrap().collect();
+        assert_eq!(result.len(), 84);
+        let unique: HashSet<Vec<i32>> = result.into_iter().map(rotate_to_min).collect();
+        assert_eq!(unique.len(), 84);
+        let result: Vec<Vec<i32>> = cycles::simple_cycles(&graph, Some(3)).unwrap().collect();
+        assert_eq!(result.len(), 30);
+        assert!(result.iter().all(|c| c.len() <= 3));
+    }
+
+    #[test]
+    fn test_simple_cycles_4() {
+        let graph = generators::random::fast_gnp_random_graph(25, 0.1, true, Some(1)).unwrap();
+        let all: HashSet<Vec<i32>> = cycles::simple_cycles(&graph, None)
+            .unwrap()
+            .map(rotate_to_min)
+            .collect();
+        let bounded: HashSet<Vec<i32>> = cycles::simple_cycles(&graph, Some(4))
+            .unwrap()
+            .map(rotate_to_min)
+            .collect();
+        let expected: HashSet<Vec<i32>> = all.into_iter().filter(|c| c.len() <= 4).collect();
+        assert_eq!(bounded, expected);
+        for cycle in bounded {
+            for i in 0..cycle.len() {
+                let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                assert!(graph.get_edge(u, v).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_simple_cycles_5() {
+        let graph = generators::classic::complete_graph(4, false);
+        assert!(cycles::simple_cycles(&graph, None).is_err());
+    }
+
+    #[test]
+    fn test_find_cycle_1() {
+        let edges = vec![Edge::new(0, 1), Edge::new(0, 2), Edge::new(1, 2)];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        assert!(cycles::find_cycle(&graph, None).unwrap().is_none());
+        let graph = graph.to_undirected(None).unwrap();
+        let result = cycles::find_cycle(&graph, Some(0)).unwrap().unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].0, result[2].1);
+    }
+
+    #[test]
+    fn test_find_cycle_2() {
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 1),
+            Edge::new(3, 4),
+        ];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+                .unwrap();
+        let result = cycles::find_cycle(&graph, Some(0)).unwrap().unwrap();
+        assert_eq!(result, vec![(1, 2), (2, 3), (3, 1)]);
+        assert!(cycles::find_cycle(&graph, Some(4)).unwrap().is_none());
+        assert!(cycles::find_cycle(&graph, Some(5)).is_err());
+    }
+
+    #[test]
+    fn test_find_cycle_3() {
+        let graph = get_graph_with_self_loops();
+        let result = cycles::find_cycle(&graph, Some(1)).unwrap().unwrap();
+        assert_eq!(result[0].0, result[result.len() - 1].1);
+    }
+
+    #[test]
+    fn test_cycle_basis_1() {
+        let graph = get_two_squares_graph();
+        let result = cycles::cycle_basis(&graph, Some(0)).unwrap();
+        assert_eq!(result, vec![vec![3, 4, 5, 0], vec![1, 2, 3, 0]]);
+    }
+
+    #[test]
+    fn test_cycle_basis_2() {
+        let mut graph = get_two_squares_graph();
+        graph
+            .add_edges(vec![
+                Edge::new(10, 11),
+                Edge::new(11, 12),
+                Edge::new(12, 10),
+            ])
+            .unwrap();
+        let result = cycles::cycle_basis(&graph, None).unwrap();
+        assert_eq!(result.len(), 3);
+        let graph = generators::classic::complete_graph(4, true);
+        assert!(cycles::cycle_basis(&graph, None).is_err());
+    }
+
+    #[test]
+    fn test_minimum_cycle_basis_1() {
+        let graph = get_two_squares_graph();
+        let result: HashSet<Vec<i32>> = cycles::minimum_cycle_basis(&graph, false)
+            .unwrap()
+            .into_iter()
+            .map(sorted)
+            .collect();
+        let expected = vec![vec![0, 1, 2, 3], vec![0, 3, 4, 5]]
+            .into_iter()
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_minimum_cycle_basis_2() {
+        let edges = vec![
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, 1.0),
+            Edge::with_weight(2, 3, 1.0),
+            Edge::with_weight(3, 0, 1.0),
+            Edge::with_weight(0, 2, 10.0),
+        ];
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::undirected_create_missing())
+                .unwrap();
+        let result: HashSet<Vec<i32>> = cycles::minimum_cycle_basis(&graph, true)
+            .unwrap()
+            .into_iter()
+            .map(sorted)
+            .collect();
+        let expected = vec![vec![0, 1, 2, 3], vec![0, 1, 2]].into_iter().collect();
+        assert_eq!(result, expected);
+        let result: Vec<Vec<i32>> = cycles::minimum_cycle_basis(&graph, false).unwrap();
+        assert!(result.iter().all(|c| c.len() == 3));
+    }
+
+    #[test]
+    fn test_minimum_cycle_basis_3() {
+        let graph = generators::social::karate_club_graph();
+        let result = cycles::minimum_cycle_basis(&graph, false).unwrap();
+        // number of edges - number of nodes + number of components
+        assert_eq!(result.len(), 78 - 34 + 1);
+        let total: usize = result.iter().map(|c| c.len()).sum();
+        assert_eq!(total, 144);
+    }
+
+    fn get_graph_with_self_loops() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::new(0, 0),
+            Edge::new(0, 1),
+            Edge::new(0, 2),
+            Edge::new(1, 2),
+            Edge::new(2, 0),
+            Edge::new(2, 1),
+            Edge::new(2, 2),
+        ];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn get_two_squares_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::new(0, 1),
+            Edge::new(1, 2),
+            Edge::new(2, 3),
+            Edge::new(3, 0),
+            Edge::new(0, 3),
+            Edge::new(3, 4),
+            Edge::new(4, 5),
+            Edge::new(5, 0),
+        ];
+        let specs = GraphSpecs {
+            edge_dedupe_strategy: graphrs::EdgeDedupeStrategy::KeepLast,
+            ..GraphSpecs::undirected_create_missing()
+        };
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn rotate_to_min(cycle: Vec<i32>) -> Vec<i32> {
+        let position = cycle
+            .iter()
+            .position(|n| n == cycle.iter().min().unwrap())
+            .unwrap();
+        let mut rotated = cycle[position..].to_vec();
+        rotated.extend_from_slice(&cycle[..position]);
+        rotated
+    }
+
+    fn sorted(mut cycle: Vec<i32>) -> Vec<i32> {
+        cycle.sort();
+        cycle
+    }
+}
@@ -0,0 +1,190 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use super::utility::round;
+    use graphrs::{
+        algorithms::flow::{self, FlowAlgorithm},
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+    use std::collections::HashMap;
+
+    const ALGORITHMS: [FlowAlgorithm; 3] = [
+        FlowAlgorithm::EdmondsKarp,
+        FlowAlgorithm::Dinic,
+        FlowAlgorithm::PreflowPush,
+    ];
+
+    #[test]
+    fn test_maximum_flow_1() {
+        let graph = get_directed_graph();
+        for algorithm in ALGORITHMS {
+            let result = flow::maximum_flow(&graph, "x", "y", None, algorithm).unwrap();
+            assert_eq!(result.flow_value, 3.0);
+            assert_eq!(result.flows.len(), graph.number_of_edges());
+            assert_conservation(&graph, &result.flows, "x", "y", 3.0);
+        }
+    }
+
+    #[test]
+    fn test_maximum_flow_2() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        for algorithm in ALGORITHMS {
+            for (u, v, expected) in [(0, 33, 22.0), (5, 25, 11.0), (11, 30, 3.0)] {
+                let result = flow::maximum_flow(&graph, u, v, None, algorithm).unwrap();
+                assert_eq!(round(&result.flow_value, 6), expected);
+                assert_conservation(&graph, &result.flows, u, v, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_maximum_flow_3() {
+        // all algorithms agree on a random graph, using a capacity accessor
+        let graph = generators::random::fast_gnp_random_graph(80, 0.1, true, Some(1)).unwrap();
+        let capacity = |edge: &Edge<i32, ()>| ((edge.u * 7 + edge.v * 3) % 11 + 1) as f64;
+        let values: Vec<f64> = ALGORITHMS
+            .iter()
+            .map(|algorithm| {
+                let result =
+                    flow::maximum_flow(&graph, 0, 79, Some(&capacity), *algorithm).unwrap();
+                for ((u, v), f) in result.flows.iter() {
+                    let edge = graph.get_edge(*u, *v).unwrap();
+                    assert!(*f >= 0.0 && *f <= capacity(edge) + 1e-9);
+                }
+                result.flow_value
+            })
+            .collect();
+        assert!(values[0] > 0.0);
+        assert_eq!(values[0], values[1]);
+        assert_eq!(values[0], values[2]);
+        let cut = flow::minimum_cut(&graph, 0, 79, Some(&capacity), FlowAlgorithm::Dinic).unwrap();
+        let cut_capacity: f64 = cut
+            .cut_edges
+            .iter()
+            .map(|(u, v)| capacity(graph.get_edge(*u, *v).unwrap()))
+            .sum();
+        assert_eq!(cut_capacity, values[0]);
+    }
+
+    #[test]
+    fn test_maximum_flow_4() {
+        // unweighted edges have infinite capacity
+        let edges = vec![
+            Edge::new("s", "a"),
+            Edge::with_weight("a", "t", 2.0),
+            Edge::new("s", "b"),
+            Edge::new("b", "t"),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = flow::maximum_flow(&graph, "s", "t", None, FlowAlgorithm::Dinic);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::Unbounded));
+        let result = flow::maximum_flow(&graph, "s", "a", None, FlowAlgorithm::Dinic);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::Unbounded));
+        let result = flow::maximum_flow(&graph, "a", "t", None, FlowAlgorithm::Dinic).unwrap();
+        assert_eq!(result.flow_value, 2.0);
+        let result = flow::maximum_flow(&graph, "t", "s", None, FlowAlgorithm::Dinic).unwrap();
+        assert_eq!(result.flow_value, 0.0);
+    }
+
+    #[test]
+    fn test_maximum_flow_5() {
+        let graph = get_directed_graph();
+        let result = flow::maximum_flow(&graph, "x", "x", None, FlowAlgorithm::Dinic);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = flow::maximum_flow(&graph, "x", "z", None, FlowAlgorithm::Dinic);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let capacity = |_edge: &Edge<&str, ()>| -1.0;
+        let result = flow::maximum_flow(&graph, "x", "y", Some(&capacity), FlowAlgorithm::Dinic);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_minimum_cut_1() {
+        let graph = get_directed_graph();
+        for algorithm in ALGORITHMS {
+            let cut = flow::minimum_cut(&graph, "x", "y", None, algorithm).unwrap();
+            assert_eq!(cut.cut_value, 3.0);
+            assert_eq!(cut.cut_edges, vec![("c", "y"), ("x", "b")]);
+            assert_eq!(cut.partition.0, vec!["x", "a", "c"].into_iter().collect());
+            assert_eq!(
+                cut.partition.1,
+                vec!["b", "d", "e", "y"].into_iter().collect()
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_cut_2() {
+        // two triangles joined by a single edge
+        let edges = vec![
+            Edge::with_weight(0, 1, 3.0),
+            Edge::with_weight(1, 2, 3.0),
+            Edge::with_weight(2, 0, 3.0),
+            Edge::with_weight(2, 3, 1.5),
+            Edge::with_weight(3, 4, 3.0),
+            Edge::with_weight(4, 5, 3.0),
+            Edge::with_weight(5, 3, 3.0),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        for algorithm in ALGORITHMS {
+            let cut = flow::minimum_cut(&graph, 5, 0, None, algorithm).unwrap();
+            assert_eq!(cut.cut_value, 1.5);
+            assert_eq!(cut.cut_edges, vec![(3, 2)]);
+            assert_eq!(cut.partition.0, vec![3, 4, 5].into_iter().collect());
+            let result = flow::maximum_flow(&graph, 5, 0, None, algorithm).unwrap();
+            assert_eq!(result.flows[&(3, 2)], 1.5);
+            assert_eq!(result.flows[&(2, 3)], 0.0);
+        }
+    }
+
+    fn get_directed_graph() -> Graph<&'static str, ()> {
+        let edges = vec![
+            Edge::with_weight("x", "a", 3.0),
+            Edge::with_weight("x", "b", 1.0),
+            Edge::with_weight("a", "c", 3.0),
+            Edge::with_weight("b", "c", 5.0),
+            Edge::with_weight("b", "d", 4.0),
+            Edge::with_weight("d", "e", 2.0),
+            Edge::with_weight("c", "y", 2.0),
+            Edge::with_weight("e", "y", 3.0),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn assert_conservation<T>(
+        graph: &Graph<T, ()>,
+        flows: &HashMap<(T, T), f64>,
+        source: T,
+        target: T,
+        value: f64,
+    ) where
+        T: std::hash::Hash + Eq + Clone + Ord + std::fmt::Display + Send + Sync,
+    {
+        let mut net: HashMap<T, f64> = HashMap::new();
+        for ((u, v), f) in flows.iter() {
+            *net.entry(u.clone()).or_insert(0.0) -= f;
+            *net.entry(v.clone()).or_insert(0.0) += f;
+        }
+        for node in graph.get_all_node_names() {
+            let expected = match node {
+                n if *n == source => -value,
+                n if *n == target => value,
+                _ => 0.0,
+            };
+            let actual = net.get(node).cloned().unwrap_or(0.0);
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+}
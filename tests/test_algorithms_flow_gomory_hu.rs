@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            cuts,
+            flow::{self, FlowAlgorithm},
+        },
+        generators, Edge, ErrorKind, Graph, GraphSpecs,
+    };
+
+    #[test]
+    fn test_gomory_hu_tree_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let tree = flow::gomory_hu_tree(&graph, None, FlowAlgorithm::Dinic).unwrap();
+        for (u, v, expected) in [
+            (0, 33, 22.0),
+            (5, 25, 11.0),
+            (11, 30, 3.0),
+            (16, 27, 6.0),
+            (2, 8, 17.0),
+        ] {
+            assert_eq!(tree.min_cut(&u, &v).unwrap(), expected);
+            assert_eq!(tree.min_cut(&v, &u).unwrap(), expected);
+        }
+        let tree_graph = tree.to_graph();
+        assert_eq!(tree_graph.number_of_nodes(), 34);
+        assert_eq!(tree_graph.number_of_edges(), 33);
+    }
+
+    #[test]
+    fn test_gomory_hu_tree_2() {
+        // the tree agrees with a minimum cut computed for every pair of nodes
+        let graph = generators::random::fast_gnp_random_graph(25, 0.2, false, Some(2)).unwrap();
+        let capacity = |edge: &Edge<i32, ()>| ((edge.u * 7 + edge.v * 3) % 11 + 1) as f64;
+        for algorithm in [
+            FlowAlgorithm::EdmondsKarp,
+            FlowAlgorithm::Dinic,
+            FlowAlgorithm::PreflowPush,
+        ] {
+            let tree = flow::gomory_hu_tree(&graph, Some(&capacity), algorithm).unwrap();
+            for u in 0..25 {
+                for v in (u + 1)..25 {
+                    let cut = flow::minimum_cut(&graph, u, v, Some(&capacity), algorithm).unwrap();
+                    assert_eq!(tree.min_cut(&u, &v).unwrap(), cut.cut_value);
+                    let (side_u, side_v) = tree.min_cut_partition(&u, &v).unwrap();
+                    assert!(side_u.contains(&u) && side_v.contains(&v));
+                    assert_eq!(side_u.len() + side_v.len(), 25);
+                    let nbunch1: Vec<i32> = side_u.into_iter().collect();
+                    let nbunch2: Vec<i32> = side_v.into_iter().collect();
+                    let size: f64 = graph
+                        .get_all_edges()
+                        .iter()
+                        .filter(|e| {
+                            (nbunch1.contains(&e.u) && nbunch2.contains(&e.v))
+                                || (nbunch1.contains(&e.v) && nbunch2.contains(&e.u))
+                        })
+                        .map(|e| capacity(e))
+                        .sum();
+                    assert_eq!(size, cut.cut_value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gomory_hu_tree_3() {
+        // the smallest weight in the tree is the global minimum cut
+        let graph = generators::social::karate_club_graph();
+        let tree = flow::gomory_hu_tree(&graph, None, FlowAlgorithm::PreflowPush).unwrap();
+        let tree_graph = tree.to_graph();
+        let min_weight = tree_graph
+            .get_all_edges()
+            .iter()
+            .map(|e| e.weight)
+            .fold(f64::INFINITY, f64::min);
+        let (cut_value, _) = cuts::stoer_wagner(&graph, true).unwrap();
+        assert_eq!(min_weight, cut_value);
+    }
+
+    #[test]
+    fn test_gomory_hu_tree_4() {
+        let graph = generators::social::karate_club_graph();
+        let tree = flow::gomory_hu_tree(&graph, None, FlowAlgorithm::Dinic).unwrap();
+        let result = tree.min_cut(&0, &0);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = tree.min_cut(&0, &34);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let result = flow::gomory_hu_tree(&graph, None, FlowAlgorithm::Dinic);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = flow::gomory_hu_tree(&graph, None, FlowAlgorithm::Dinic);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+}
@@ -0,0 +1,292 @@
+mod utility;
+
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::flow, Edge, ErrorKind, Graph, GraphSpecs};
+    use std::collections::HashMap;
+
+    type Solver = fn(
+        &Graph<i32, ()>,
+        &HashMap<i32, f64>,
+        Option<&dyn Fn(&Edge<i32, ()>) -> f64>,
+        Option<&dyn Fn(&Edge<i32, ()>) -> f64>,
+    ) -> Result<flow::MinCostFlow<i32>, graphrs::Error>;
+
+    const SOLVERS: [Solver; 2] = [flow::min_cost_flow, flow::network_simplex];
+
+    #[test]
+    fn test_min_cost_flow_1() {
+        // values from NetworkX
+        let graph = get_graph(false);
+        let demand = get_demand();
+        let capacities = get_capacities();
+        let capacity = |edge: &Edge<i32, ()>| capacities[&(edge.u, edge.v)];
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.cost, 28.0);
+            assert_valid_flow(&graph, &demand, &capacity, &result);
+        }
+        let graph = get_formula_graph(false);
+        let demand = get_formula_demand();
+        let capacity = |edge: &Edge<i32, ()>| ((edge.u * 7 + edge.v * 3) % 9 + 1) as f64;
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.cost, -398.0);
+            assert_valid_flow(&graph, &demand, &capacity, &result);
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_2() {
+        // values from NetworkX
+        let graph = get_graph(true);
+        let demand = get_demand();
+        let capacities = get_capacities();
+        let capacity = |edge: &Edge<i32, ()>| capacities[&(edge.u, edge.v)];
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.cost, 44.0);
+            assert_valid_flow(&graph, &demand, &capacity, &result);
+        }
+        let graph = get_formula_graph(true);
+        let demand = get_formula_demand();
+        let capacity = |edge: &Edge<i32, ()>| ((edge.u * 7 + edge.v * 3) % 9 + 1) as f64;
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.cost, 37.0);
+            assert_valid_flow(&graph, &demand, &capacity, &result);
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_3() {
+        // a cost accessor and infinite capacities
+        let edges = vec![
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, 1.0),
+            Edge::with_weight(0, 2, 5.0),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let demand: HashMap<i32, f64> = vec![(0, -4.0), (2, 4.0)].into_iter().collect();
+        let cost = |edge: &Edge<i32, ()>| edge.weight * 2.0;
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, None, Some(&cost)).unwrap();
+            assert_eq!(result.cost, 16.0);
+            assert_eq!(result.flows[&(0, 2)], 0.0);
+            assert_eq!(result.flows[&(1, 2)], 4.0);
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_4() {
+        // infeasible: not enough capacity to meet the demand
+        let edges = vec![Edge::with_weight(0, 1, 1.0), Edge::with_weight(1, 2, 1.0)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let demand: HashMap<i32, f64> = vec![(0, -4.0), (2, 4.0)].into_iter().collect();
+        let capacity = |_edge: &Edge<i32, ()>| 3.0;
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Infeasible));
+            let demand: HashMap<i32, f64> = vec![(0, -4.0), (2, 3.0)].into_iter().collect();
+            let result = solver(&graph, &demand, Some(&capacity), None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Infeasible));
+            let demand: HashMap<i32, f64> = vec![(2, -1.0), (0, 1.0)].into_iter().collect();
+            let result = solver(&graph, &demand, Some(&capacity), None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Infeasible));
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_5() {
+        // unbounded: a negative cost cycle with infinite capacity
+        let edges = vec![
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, -3.0),
+            Edge::with_weight(2, 1, 1.0),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let demand: HashMap<i32, f64> = vec![(0, -1.0), (2, 1.0)].into_iter().collect();
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, None, None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Unbounded));
+            // with finite capacities the negative cycle is saturated
+            let capacity = |_edge: &Edge<i32, ()>| 2.0;
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.cost, -4.0);
+            assert_eq!(result.flows[&(2, 1)], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_6() {
+        let graph = get_graph(false).to_undirected(None).unwrap();
+        let demand = get_demand();
+        for solver in SOLVERS {
+            assert!(solver(&graph, &demand, None, None).is_err());
+        }
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::directed());
+        let demand = HashMap::new();
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, None, None);
+            assert!(matches!(
+                result.unwrap_err().kind,
+                ErrorKind::InvalidArgument
+            ));
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_7() {
+        // both infeasible and unbounded: as in NetworkX, feasibility is checked first
+        let edges = vec![
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(1, 2, -3.0),
+            Edge::with_weight(2, 1, 1.0),
+        ];
+        let nodes = (0..4).map(graphrs::Node::from_name).collect();
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap();
+        let demand: HashMap<i32, f64> = vec![(0, -1.0), (3, 1.0)].into_iter().collect();
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, None, None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Infeasible));
+        }
+        // a self-loop with a negative cost and infinite capacity
+        let edges = vec![Edge::with_weight(0, 1, 1.0), Edge::with_weight(3, 3, -1.0)];
+        let nodes = (0..4).map(graphrs::Node::from_name).collect();
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed()
+        };
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap();
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, None, None);
+            assert!(matches!(result.unwrap_err().kind, ErrorKind::Infeasible));
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_8() {
+        // no flow over an edge with a negative cost gives a cost of 0.0, not -0.0
+        let edges = vec![Edge::with_weight(0, 1, -2.0)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let demand = HashMap::new();
+        let capacity = |_edge: &Edge<i32, ()>| 3.0;
+        for solver in SOLVERS {
+            let result = solver(&graph, &demand, Some(&capacity), None).unwrap();
+            assert_eq!(result.flows[&(0, 1)], 0.0);
+            assert!(result.cost == 0.0 && result.cost.is_sign_positive());
+        }
+    }
+
+    /// Edges `(u, v, cost, capacity)` of a network with two sources, `0` and `1`, and two
+    /// sinks, `6` and `7`; the costs of `2 -> 3`, `5 -> 6` and `7 -> 2` are negative.
+    const EDGES: [(i32, i32, f64, f64); 14] = [
+        (0, 2, 2.0, 4.0),
+        (0, 3, 4.0, 2.0),
+        (1, 3, 1.0, 3.0),
+        (1, 4, 3.0, 4.0),
+        (2, 3, -2.0, 3.0),
+        (2, 5, 5.0, 3.0),
+        (3, 5, 1.0, 5.0),
+        (3, 6, 6.0, 2.0),
+        (4, 1, 1.0, 2.0),
+        (4, 6, 2.0, 3.0),
+        (5, 6, -1.0, 2.0),
+        (5, 7, 3.0, 4.0),
+        (6, 7, 1.0, 3.0),
+        (7, 2, -3.0, 1.0),
+    ];
+
+    fn get_graph(non_negative: bool) -> Graph<i32, ()> {
+        let edges = EDGES
+            .iter()
+            .map(|(u, v, cost, _)| {
+                let cost = match non_negative {
+                    true => cost.abs(),
+                    false => *cost,
+                };
+                Edge::with_weight(*u, *v, cost)
+            })
+            .collect();
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    fn get_capacities() -> HashMap<(i32, i32), f64> {
+        EDGES
+            .iter()
+            .map(|(u, v, _, capacity)| ((*u, *v), *capacity))
+            .collect()
+    }
+
+    fn get_demand() -> HashMap<i32, f64> {
+        vec![(0, -4.0), (1, -3.0), (6, 3.0), (7, 4.0)]
+            .into_iter()
+            .collect()
+    }
+
+    /// A network on `0..40` with costs between `-3` and `9`, or their absolute values if
+    /// `non_negative`.
+    fn get_formula_graph(non_negative: bool) -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..40 {
+            for v in 0..40 {
+                if u != v && (u * u + v * 3 + u * v) % 7 < 2 {
+                    let weight = ((u * 5 + v * 11) % 13 - 3) as f64;
+                    let weight = match non_negative {
+                        true => weight.abs(),
+                        false => weight,
+                    };
+                    edges.push(Edge::with_weight(u, v, weight));
+                }
+            }
+        }
+        let nodes = (0..40).map(graphrs::Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+    }
+
+    fn get_formula_demand() -> HashMap<i32, f64> {
+        vec![
+            (0, -3.0),
+            (1, -3.0),
+            (2, -3.0),
+            (3, -3.0),
+            (5, -3.0),
+            (35, 3.0),
+            (36, 3.0),
+            (37, 3.0),
+            (38, 3.0),
+            (39, 3.0),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn assert_valid_flow(
+        graph: &Graph<i32, ()>,
+        demand: &HashMap<i32, f64>,
+        capacity: &dyn Fn(&Edge<i32, ()>) -> f64,
+        result: &flow::MinCostFlow<i32>,
+    ) {
+        let mut net: HashMap<i32, f64> = HashMap::new();
+        let mut cost = 0.0;
+        for ((u, v), f) in result.flows.iter() {
+            let edge = graph.get_edge(*u, *v).unwrap();
+            assert!(*f >= 0.0 && *f <= capacity(edge));
+            cost += f * edge.weight;
+            *net.entry(*u).or_insert(0.0) -= f;
+            *net.entry(*v).or_insert(0.0) += f;
+        }
+        assert_eq!(cost, result.cost);
+        for node in graph.get_all_node_names() {
+            let expected = demand.get(node).cloned().unwrap_or(0.0);
+            assert_eq!(net.get(node).cloned().unwrap_or(0.0), expected);
+        }
+    }
+}
@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::matching, generators, Edge, ErrorKind, Graph, GraphSpecs, MissingNodeStrategy,
+        Node,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_max_weight_matching_1() {
+        // a 5-cycle with paths and edges off it; values from NetworkX
+        let graph = get_weighted_graph(&[
+            (0, 1, 6.0),
+            (1, 2, 5.0),
+            (2, 3, 6.0),
+            (3, 4, 5.0),
+            (4, 0, 4.0),
+            (0, 5, 3.0),
+            (2, 6, 7.0),
+            (5, 6, 1.0),
+            (6, 7, 4.0),
+            (7, 8, 8.0),
+            (8, 9, 2.0),
+            (9, 5, 3.0),
+            (3, 9, 2.0),
+            (4, 8, 5.0),
+        ]);
+        let expected: HashSet<(i32, i32)> = vec![(0, 1), (2, 6), (3, 4), (5, 9), (7, 8)]
+            .into_iter()
+            .collect();
+        for max_cardinality in [false, true] {
+            let result = matching::max_weight_matching(&graph, max_cardinality, true).unwrap();
+            assert!(matching::is_matching(&graph, &result).unwrap());
+            assert_eq!(total_weight(&graph, &result), 29.0);
+            assert_eq!(sorted_pairs(&result), expected);
+        }
+        assert_eq!(matching::max_cardinality_matching(&graph).unwrap().len(), 5);
+        // the heaviest matching has fewer edges than the largest
+        let graph = get_weighted_graph(&[(0, 1, 1.0), (1, 2, 3.0), (2, 3, 1.0)]);
+        let result = matching::max_weight_matching(&graph, false, true).unwrap();
+        assert_eq!(sorted_pairs(&result), vec![(1, 2)].into_iter().collect());
+        let result = matching::max_weight_matching(&graph, true, true).unwrap();
+        assert_eq!(
+            sorted_pairs(&result),
+            vec![(0, 1), (2, 3)].into_iter().collect()
+        );
+        assert_eq!(matching::max_cardinality_matching(&graph).unwrap().len(), 2);
+        let parameters = [
+            ((30, 3, 5, 1, 7, 2, 17), (207.0, 14), (201.0, 15)),
+            ((40, 1, 3, 1, 5, 1, 23), (312.0, 16), (312.0, 16)),
+            ((25, 2, 7, 3, 11, 4, 9), (89.0, 12), (89.0, 12)),
+            ((50, 5, 1, 2, 13, 2, 31), (616.0, 24), (596.0, 25)),
+        ];
+        for (graph_parameters, expected, expected_max_cardinality) in parameters {
+            let graph = get_formula_graph(graph_parameters);
+            let result = matching::max_weight_matching(&graph, false, true).unwrap();
+            assert!(matching::is_matching(&graph, &result).unwrap());
+            assert_eq!((total_weight(&graph, &result), result.len()), expected);
+            let result = matching::max_weight_matching(&graph, true, true).unwrap();
+            assert!(matching::is_matching(&graph, &result).unwrap());
+            assert_eq!(
+                (total_weight(&graph, &result), result.len()),
+                expected_max_cardinality
+            );
+            let result = matching::max_cardinality_matching(&graph).unwrap();
+            assert_eq!(result.len(), expected_max_cardinality.1);
+        }
+    }
+
+    #[test]
+    fn test_max_weight_matching_2() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        let result = matching::max_weight_matching(&graph, false, true).unwrap();
+        assert_eq!(total_weight(&graph, &result), 49.0);
+        assert_eq!(result.len(), 12);
+        assert!(result.iter().all(|(u, v)| u < v));
+        let result = matching::max_cardinality_matching(&graph).unwrap();
+        assert_eq!(result.len(), 13);
+        assert!(matching::is_maximal_matching(&graph, &result).unwrap());
+    }
+
+    #[test]
+    fn test_max_weight_matching_3() {
+        // compare with an exhaustive search on small graphs
+        for seed in 0..40 {
+            let graph = get_random_graph(9, 0.45, seed);
+            let edges = get_edges(&graph);
+            for max_cardinality in [false, true] {
+                let result = matching::max_weight_matching(&graph, max_cardinality, true).unwrap();
+                assert!(matching::is_matching(&graph, &result).unwrap());
+                let actual = (result.len(), total_weight(&graph, &result));
+                let (size, weight) = get_best_by_search(&edges, &mut vec![false; 9], 0);
+                match max_cardinality {
+                    true => assert_eq!(actual, (size, weight)),
+                    false => {
+                        let weight = get_max_weight_by_search(&edges, &mut vec![false; 9], 0);
+                        assert_eq!(actual.1, weight);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_weight_matching_4() {
+        // self-loops are ignored and the heaviest of multiple edges is used
+        let edges = vec![
+            Edge::with_weight(0, 0, 100.0),
+            Edge::with_weight(0, 1, 1.0),
+            Edge::with_weight(0, 1, 8.0),
+            Edge::with_weight(1, 2, 5.0),
+            Edge::with_weight(2, 3, 2.0),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            self_loops: true,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = matching::max_weight_matching(&graph, false, true).unwrap();
+        assert_eq!(result, vec![(0, 1), (2, 3)].into_iter().collect());
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        assert!(matching::max_weight_matching(&graph, true, true)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_max_weight_matching_errors() {
+        let edges = vec![Edge::new(0, 1), Edge::with_weight(1, 2, 2.0)];
+        let specs = GraphSpecs::undirected_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = matching::max_weight_matching(&graph, false, true);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::EdgeWeightNotSpecified
+        ));
+        assert_eq!(matching::max_cardinality_matching(&graph).unwrap().len(), 1);
+        let edges = vec![Edge::new(0, 1)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let result = matching::max_cardinality_matching(&graph);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+        let result = matching::maximal_matching(&graph);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_maximal_matching_1() {
+        for seed in 0..10 {
+            let graph = get_random_graph(30, 0.1, seed);
+            let result = matching::maximal_matching(&graph).unwrap();
+            assert!(matching::is_maximal_matching(&graph, &result).unwrap());
+            let maximum = matching::max_cardinality_matching(&graph).unwrap();
+            assert!(2 * result.len() >= maximum.len());
+        }
+    }
+
+    #[test]
+    fn test_is_matching_1() {
+        let graph = generators::social::karate_club_graph();
+        let matching: HashSet<(i32, i32)> = vec![(1, 0), (32, 33)].into_iter().collect();
+        assert!(matching::is_matching(&graph, &matching).unwrap());
+        assert!(!matching::is_maximal_matching(&graph, &matching).unwrap());
+        let matching: HashSet<(i32, i32)> = vec![(0, 0)].into_iter().collect();
+        assert!(!matching::is_matching(&graph, &matching).unwrap());
+        let matching: HashSet<(i32, i32)> = vec![(1, 0), (0, 1)].into_iter().collect();
+        assert!(!matching::is_matching(&graph, &matching).unwrap());
+        let matching: HashSet<(i32, i32)> = vec![(0, 34)].into_iter().collect();
+        let result = matching::is_matching(&graph, &matching);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+        // the direction of edges matters in a directed graph
+        let edges = vec![Edge::new(0, 1), Edge::new(2, 1)];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<i32, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let matching: HashSet<(i32, i32)> = vec![(0, 1)].into_iter().collect();
+        assert!(matching::is_maximal_matching(&graph, &matching).unwrap());
+        let matching: HashSet<(i32, i32)> = vec![(1, 0)].into_iter().collect();
+        assert!(!matching::is_matching(&graph, &matching).unwrap());
+    }
+
+    fn total_weight(graph: &Graph<i32, ()>, matching: &HashSet<(i32, i32)>) -> f64 {
+        matching
+            .iter()
+            .map(|(u, v)| graph.get_edge(*u, *v).unwrap().weight)
+            .sum()
+    }
+
+    fn get_edges(graph: &Graph<i32, ()>) -> Vec<(usize, usize, f64)> {
+        graph
+            .get_all_edges()
+            .iter()
+            .map(|e| (e.u as usize, e.v as usize, e.weight))
+            .collect()
+    }
+
+    /// Returns the largest (size, weight) of a matching, compared lexicographically.
+    fn get_best_by_search(
+        edges: &[(usize, usize, f64)],
+        matched: &mut Vec<bool>,
+        next: usize,
+    ) -> (usize, f64) {
+        if next == edges.len() {
+            return (0, 0.0);
+        }
+        let mut best = get_best_by_search(edges, matched, next + 1);
+        let (u, v, w) = edges[next];
+        if !matched[u] && !matched[v] {
+            matched[u] = true;
+            matched[v] = true;
+            let (size, weight) = get_best_by_search(edges, matched, next + 1);
+            if (size + 1, weight + w) > best {
+                best = (size + 1, weight + w);
+            }
+            matched[u] = false;
+            matched[v] = false;
+        }
+        best
+    }
+
+    fn get_max_weight_by_search(
+        edges: &[(usize, usize, f64)],
+        matched: &mut Vec<bool>,
+        next: usize,
+    ) -> f64 {
+        if next == edges.len() {
+            return 0.0;
+        }
+        let mut best = get_max_weight_by_search(edges, matched, next + 1);
+        let (u, v, w) = edges[next];
+        if !matched[u] && !matched[v] {
+            matched[u] = true;
+            matched[v] = true;
+            best = best.max(w + get_max_weight_by_search(edges, matched, next + 1));
+            matched[u] = false;
+            matched[v] = false;
+        }
+        best
+    }
+
+    fn get_random_graph(n: i32, p: f64, seed: u64) -> Graph<i32, ()> {
+        let graph = generators::random::fast_gnp_random_graph(n, p, false, Some(seed)).unwrap();
+        let edges = graph
+            .get_all_edges()
+            .iter()
+            .map(|e| Edge::with_weight(e.u, e.v, ((e.u * 5 + e.v * 3 + seed as i32) % 7) as f64))
+            .collect();
+        let nodes = (0..n).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    fn get_formula_graph(
+        (n, a, b, c, modulus, threshold, weight_modulus): (i32, i32, i32, i32, i32, i32, i32),
+    ) -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..n {
+            for v in (u + 1)..n {
+                if (u * a + v * b + u * v * c) % modulus < threshold {
+                    let weight = ((u * 7 + v * 13) % weight_modulus + 1) as f64;
+                    edges.push(Edge::with_weight(u, v, weight));
+                }
+            }
+        }
+        let nodes = (0..n).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+
+    fn get_weighted_graph(edges: &[(i32, i32, f64)]) -> Graph<i32, ()> {
+        let edges = edges
+            .iter()
+            .map(|(u, v, weight)| Edge::with_weight(*u, *v, *weight))
+            .collect();
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    fn sorted_pairs(matching: &HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+        matching
+            .iter()
+            .map(|(u, v)| (*u.min(v), *u.max(v)))
+            .collect()
+    }
+}
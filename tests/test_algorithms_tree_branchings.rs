@@ -0,0 +1,269 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::tree, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    #[test]
+    fn test_spanning_arborescence_1() {
+        // values from NetworkX
+        let graph = get_graph(&[]);
+        let arborescence = tree::minimum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(total_weight(&arborescence), -12.0);
+        let mut edges: Vec<(i32, i32)> = arborescence
+            .get_all_edges()
+            .iter()
+            .map(|e| (e.u, e.v))
+            .collect();
+        edges.sort();
+        assert_eq!(
+            edges,
+            vec![(1, 2), (3, 4), (3, 7), (5, 1), (5, 3), (5, 6), (7, 0)]
+        );
+        let arborescence = tree::maximum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(total_weight(&arborescence), 32.0);
+        for (shift, minimum, maximum) in [(3.0, -51.0, 128.0), (6.0, -108.0, 71.0)] {
+            let graph = get_formula_graph(shift);
+            let arborescence = tree::minimum_spanning_arborescence(&graph, None).unwrap();
+            assert_is_arborescence(&arborescence);
+            assert_eq!(total_weight(&arborescence), minimum);
+            let arborescence = tree::maximum_spanning_arborescence(&graph, None).unwrap();
+            assert_is_arborescence(&arborescence);
+            assert_eq!(total_weight(&arborescence), maximum);
+        }
+    }
+
+    #[test]
+    fn test_spanning_arborescence_2() {
+        // rooted arborescences; values from NetworkX with the root's incoming edges removed
+        let parameters = [
+            (
+                get_graph(&[]),
+                [(0, -4.0, 26.0), (3, -11.0, 23.0), (6, 2.0, 32.0)],
+            ),
+            (
+                get_formula_graph(3.0),
+                [(0, -46.0, 127.0), (5, -49.0, 125.0), (13, -47.0, 125.0)],
+            ),
+        ];
+        for (graph, roots) in parameters {
+            for (root, minimum, maximum) in roots {
+                let arborescence = tree::minimum_spanning_arborescence(&graph, Some(root)).unwrap();
+                assert_is_arborescence(&arborescence);
+                assert_eq!(get_roots(&arborescence), vec![root]);
+                assert_eq!(total_weight(&arborescence), minimum);
+                let arborescence = tree::maximum_spanning_arborescence(&graph, Some(root)).unwrap();
+                assert_eq!(get_roots(&arborescence), vec![root]);
+                assert_eq!(total_weight(&arborescence), maximum);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spanning_arborescence_3() {
+        // node 8 has no incoming edges, so it's the root; values from NetworkX
+        let graph = get_graph(&[8]);
+        let arborescence = tree::minimum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(get_roots(&arborescence), vec![8]);
+        assert_eq!(total_weight(&arborescence), -16.0);
+        let arborescence = tree::maximum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(total_weight(&arborescence), 28.0);
+        // a sparser graph that needs several rounds of contractions
+        let graph = get_sparse_graph(&[15, 38]);
+        let arborescence = tree::minimum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(total_weight(&arborescence), -401.0);
+        let arborescence = tree::maximum_spanning_arborescence(&graph, None).unwrap();
+        assert_is_arborescence(&arborescence);
+        assert_eq!(total_weight(&arborescence), 391.0);
+    }
+
+    #[test]
+    fn test_spanning_arborescence_4() {
+        // nodes 8 and 9 have no incoming edges
+        let graph = get_graph(&[8, 9]);
+        let result = tree::minimum_spanning_arborescence(&graph, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Infeasible));
+        let result = tree::maximum_spanning_arborescence(&graph, Some(8));
+        let error = result.err().unwrap();
+        assert!(matches!(error.kind, ErrorKind::Infeasible));
+        assert!(error.message.contains("9"));
+        // nodes 15 and 38 have no incoming edges
+        let graph = get_sparse_graph(&[]);
+        let result = tree::minimum_spanning_arborescence(&graph, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::Infeasible));
+        let result = tree::maximum_spanning_arborescence(&graph, Some(15));
+        let error = result.err().unwrap();
+        assert!(matches!(error.kind, ErrorKind::Infeasible));
+        assert!(error.message.contains("38"));
+        let result = tree::minimum_spanning_arborescence(&graph, Some(100));
+        assert!(matches!(
+            result.err().unwrap().kind,
+            ErrorKind::NodeNotFound
+        ));
+        let graph: Graph<i32, ()> = Graph::new(GraphSpecs::undirected());
+        let result = tree::minimum_spanning_arborescence(&graph, None);
+        assert!(matches!(result.err().unwrap().kind, ErrorKind::WrongMethod));
+    }
+
+    #[test]
+    fn test_branching_1() {
+        // values from NetworkX
+        let graph = get_graph(&[8, 9]);
+        let branching = tree::minimum_branching(&graph).unwrap();
+        assert_is_branching(&branching);
+        assert_eq!(branching.number_of_edges(), 7);
+        assert_eq!(total_weight(&branching), -23.0);
+        let branching = tree::maximum_branching(&graph).unwrap();
+        assert_is_branching(&branching);
+        assert_eq!(branching.number_of_edges(), 8);
+        assert_eq!(total_weight(&branching), 35.0);
+        let graph = get_graph(&[]);
+        let branching = tree::maximum_branching(&graph).unwrap();
+        assert_eq!(total_weight(&branching), 32.0);
+        let graph = get_sparse_graph(&[]);
+        let branching = tree::minimum_branching(&graph).unwrap();
+        assert_is_branching(&branching);
+        assert_eq!(branching.number_of_edges(), 56);
+        assert_eq!(total_weight(&branching), -413.0);
+        let branching = tree::maximum_branching(&graph).unwrap();
+        assert_is_branching(&branching);
+        assert_eq!(branching.number_of_edges(), 57);
+        assert_eq!(total_weight(&branching), 401.0);
+        let graph = get_formula_graph(3.0);
+        let branching = tree::maximum_branching(&graph).unwrap();
+        assert_eq!(total_weight(&branching), 128.0);
+    }
+
+    #[test]
+    fn test_branching_2() {
+        // unweighted edges have a weight of one and self-loops are ignored
+        let edges = vec![
+            Edge::new("a", "b"),
+            Edge::new("b", "c"),
+            Edge::new("c", "a"),
+            Edge::with_weight("c", "c", 10.0),
+        ];
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::directed_create_missing()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        let branching = tree::maximum_branching(&graph).unwrap();
+        assert_eq!(branching.number_of_nodes(), 3);
+        assert_eq!(branching.number_of_edges(), 2);
+        let branching = tree::minimum_branching(&graph).unwrap();
+        assert_eq!(branching.number_of_edges(), 0);
+        let arborescence = tree::minimum_spanning_arborescence(&graph, Some("b")).unwrap();
+        let mut edges: Vec<(&str, &str)> = arborescence
+            .get_all_edges()
+            .iter()
+            .map(|e| (e.u, e.v))
+            .collect();
+        edges.sort();
+        assert_eq!(edges, vec![("b", "c"), ("c", "a")]);
+    }
+
+    fn total_weight(graph: &Graph<i32, ()>) -> f64 {
+        graph.get_all_edges().iter().map(|e| e.weight).sum()
+    }
+
+    fn get_roots(graph: &Graph<i32, ()>) -> Vec<i32> {
+        graph
+            .get_all_node_names()
+            .into_iter()
+            .filter(|n| graph.get_predecessor_nodes(**n).unwrap().is_empty())
+            .cloned()
+            .collect()
+    }
+
+    fn assert_is_branching(graph: &Graph<i32, ()>) {
+        // every node has at most one incoming edge, and following them never loops
+        for node in graph.get_all_node_names() {
+            let mut v = *node;
+            for _ in 0..graph.number_of_nodes() {
+                let predecessors = graph.get_predecessor_nodes(v).unwrap();
+                assert!(predecessors.len() <= 1);
+                match predecessors.first() {
+                    None => break,
+                    Some(p) => v = p.name,
+                }
+            }
+            assert!(graph.get_predecessor_nodes(v).unwrap().is_empty());
+        }
+    }
+
+    fn assert_is_arborescence(graph: &Graph<i32, ()>) {
+        assert_is_branching(graph);
+        assert_eq!(get_roots(graph).len(), 1);
+    }
+
+    /// A weighted digraph on `0..8` with nested cycles, plus whichever of `8` and `9` are in
+    /// `sources`, which only have outgoing edges.
+    fn get_graph(sources: &[i32]) -> Graph<i32, ()> {
+        let mut edges = vec![
+            Edge::with_weight(0, 1, 4.0),
+            Edge::with_weight(0, 5, 7.0),
+            Edge::with_weight(1, 2, -2.0),
+            Edge::with_weight(1, 4, 6.0),
+            Edge::with_weight(2, 0, 3.0),
+            Edge::with_weight(2, 3, 5.0),
+            Edge::with_weight(3, 4, -1.0),
+            Edge::with_weight(3, 7, -2.0),
+            Edge::with_weight(4, 1, 0.0),
+            Edge::with_weight(4, 5, 2.0),
+            Edge::with_weight(5, 1, -4.0),
+            Edge::with_weight(5, 3, 1.0),
+            Edge::with_weight(5, 6, -3.0),
+            Edge::with_weight(6, 2, 3.0),
+            Edge::with_weight(6, 7, 4.0),
+            Edge::with_weight(7, 0, -1.0),
+            Edge::with_weight(7, 6, 2.0),
+        ];
+        if sources.contains(&8) {
+            edges.push(Edge::with_weight(8, 0, 2.0));
+            edges.push(Edge::with_weight(8, 3, -5.0));
+        }
+        if sources.contains(&9) {
+            edges.push(Edge::with_weight(9, 4, -6.0));
+            edges.push(Edge::with_weight(9, 7, 5.0));
+        }
+        Graph::new_from_nodes_and_edges(vec![], edges, GraphSpecs::directed_create_missing())
+            .unwrap()
+    }
+
+    /// A weighted digraph on `0..20`, with the weights shifted down by `shift`.
+    fn get_formula_graph(shift: f64) -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..20 {
+            for v in 0..20 {
+                if u != v && (u * u + v * 3 + u * v + 1) % 5 < 2 {
+                    let weight = ((u * 7 + v * 3) % 11) as f64 - shift;
+                    edges.push(Edge::with_weight(u, v, weight));
+                }
+            }
+        }
+        let nodes = (0..20).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+    }
+
+    /// A sparse weighted digraph on `0..60`, without the nodes in `removed`; nodes `15` and `38`
+    /// have no incoming edges.
+    fn get_sparse_graph(removed: &[i32]) -> Graph<i32, ()> {
+        let included = |v: &i32| !removed.contains(v);
+        let mut edges = vec![];
+        for u in (0..60).filter(included) {
+            for v in (0..60).filter(included) {
+                if u != v && (u * 31 + v * 17 + u * v) % 23 < 2 {
+                    let weight = ((u * 13 + v * 7) % 19) as f64 - 9.0;
+                    edges.push(Edge::with_weight(u, v, weight));
+                }
+            }
+        }
+        let nodes = (0..60).filter(included).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+    }
+}
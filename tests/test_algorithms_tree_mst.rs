@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::{
+            components,
+            tree::mst::{self, SpanningTreeAlgorithm},
+        },
+        generators, Edge, Graph, GraphSpecs, MissingNodeStrategy, Node,
+    };
+    use std::collections::HashSet;
+
+    const ALGORITHMS: [SpanningTreeAlgorithm; 3] = [
+        SpanningTreeAlgorithm::Kruskal,
+        SpanningTreeAlgorithm::Prim,
+        SpanningTreeAlgorithm::Boruvka,
+    ];
+
+    #[test]
+    fn test_spanning_tree_1() {
+        // values from NetworkX
+        let graph = generators::social::karate_club_graph();
+        for algorithm in ALGORITHMS {
+            let tree = mst::spanning_tree(&graph, false, algorithm).unwrap();
+            assert_eq!(tree.number_of_nodes(), 34);
+            assert_eq!(tree.number_of_edges(), 33);
+            assert_eq!(total_weight(&tree), 68.0);
+            assert_eq!(
+                components::number_of_connected_components(&tree).unwrap(),
+                1
+            );
+            let tree = mst::spanning_tree(&graph, true, algorithm).unwrap();
+            assert_eq!(tree.number_of_edges(), 33);
+            assert_eq!(total_weight(&tree), 120.0);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_2() {
+        // spanning forests of disconnected graphs; values from NetworkX
+        let parameters = [
+            (get_forest_graph(), 7, 11.0, 20.0),
+            (get_formula_graph(), 38, 22.5, 199.0),
+        ];
+        for (graph, num_edges, minimum, maximum) in parameters {
+            let mut edge_sets = vec![];
+            for algorithm in ALGORITHMS {
+                let tree = mst::spanning_tree(&graph, false, algorithm).unwrap();
+                assert_eq!(tree.number_of_edges(), num_edges);
+                assert_eq!(total_weight(&tree), minimum);
+                assert_eq!(
+                    components::number_of_connected_components(&tree).unwrap(),
+                    2
+                );
+                let tree = mst::spanning_tree(&graph, true, algorithm).unwrap();
+                assert_eq!(total_weight(&tree), maximum);
+                let edges: HashSet<(i32, i32)> = mst::spanning_edges(&graph, false, algorithm)
+                    .unwrap()
+                    .map(|e| (e.u, e.v))
+                    .collect();
+                edge_sets.push(edges);
+            }
+            // ties are broken the same way by every algorithm
+            assert_eq!(edge_sets[0], edge_sets[1]);
+            assert_eq!(edge_sets[0], edge_sets[2]);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_3() {
+        // a large random graph, so that Borůvka's search is split across threads
+        let graph = generators::random::fast_gnp_random_graph(2000, 0.005, false, Some(1)).unwrap();
+        let edges: Vec<Edge<i32, ()>> = graph
+            .get_all_edges()
+            .iter()
+            .map(|e| Edge {
+                u: e.u,
+                v: e.v,
+                attributes: None,
+                weight: ((e.u * 31 + e.v * 17) % 101) as f64,
+            })
+            .collect();
+        let nodes = graph.get_all_nodes().into_iter().cloned().collect();
+        let edges = edges.into_iter().map(std::sync::Arc::new).collect();
+        let graph: Graph<i32, ()> =
+            Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap();
+        let weights: Vec<f64> = ALGORITHMS
+            .iter()
+            .map(|algorithm| total_weight(&mst::spanning_tree(&graph, false, *algorithm).unwrap()))
+            .collect();
+        assert_eq!(weights[0], weights[1]);
+        assert_eq!(weights[0], weights[2]);
+    }
+
+    #[test]
+    fn test_spanning_edges_1() {
+        // Kruskal returns the edges in order of weight
+        let graph = generators::social::karate_club_graph();
+        let weights: Vec<f64> = mst::spanning_edges(&graph, false, SpanningTreeAlgorithm::Kruskal)
+            .unwrap()
+            .map(|e| e.weight)
+            .collect();
+        assert_eq!(weights.len(), 33);
+        assert!(weights.windows(2).all(|w| w[0] <= w[1]));
+        let weights: Vec<f64> = mst::spanning_edges(&graph, true, SpanningTreeAlgorithm::Kruskal)
+            .unwrap()
+            .map(|e| e.weight)
+            .collect();
+        assert!(weights.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_spanning_tree_directed() {
+        // reciprocal edges are collapsed to the lightest (heaviest) edge
+        let edges = vec![
+            Edge::with_weight("a", "b", 1.0),
+            Edge::with_weight("b", "a", 5.0),
+            Edge::with_weight("b", "c", 2.0),
+            Edge::with_weight("a", "c", 3.0),
+        ];
+        let specs = GraphSpecs::directed_create_missing();
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        for algorithm in ALGORITHMS {
+            let tree = mst::spanning_tree(&graph, false, algorithm).unwrap();
+            assert!(!tree.specs.directed);
+            assert_eq!(total_weight(&tree), 3.0);
+            let tree = mst::spanning_tree(&graph, true, algorithm).unwrap();
+            assert_eq!(total_weight(&tree), 8.0);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_multi() {
+        // unweighted edges have a weight of one, self-loops are ignored and only the
+        // lightest of multiple edges can be in a minimum spanning tree
+        let edges = vec![
+            Edge::with_weight("a", "b", 4.0),
+            Edge::with_weight("a", "b", 0.5),
+            Edge::new("b", "c"),
+            Edge::with_weight("c", "c", -10.0),
+            Edge::with_weight("c", "a", 3.0),
+        ];
+        let specs = GraphSpecs {
+            missing_node_strategy: MissingNodeStrategy::Create,
+            self_loops: true,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph: Graph<&str, ()> = Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap();
+        for algorithm in ALGORITHMS {
+            let edges: Vec<(&str, &str, f64)> = mst::spanning_edges(&graph, false, algorithm)
+                .unwrap()
+                .map(|e| (e.u, e.v, e.weight))
+                .collect();
+            assert_eq!(edges.len(), 2);
+            assert!(edges.contains(&("a", "b", 0.5)));
+            assert!(edges.iter().any(|e| e.0 == "b" && e.1 == "c"));
+            let tree = mst::spanning_tree(&graph, true, algorithm).unwrap();
+            assert_eq!(total_weight(&tree), 7.0);
+        }
+        let graph: Graph<&str, ()> = Graph::new(GraphSpecs::undirected());
+        let tree = mst::spanning_tree(&graph, false, SpanningTreeAlgorithm::Prim).unwrap();
+        assert_eq!(tree.number_of_nodes(), 0);
+    }
+
+    fn total_weight<T>(graph: &Graph<T, ()>) -> f64
+    where
+        T: std::hash::Hash + Eq + Clone + Ord + std::fmt::Display + Send + Sync,
+    {
+        graph.get_all_edges().iter().map(|e| e.weight).sum()
+    }
+
+    /// A weighted graph with two components, `0..6` and a triangle of equal weights on `6..9`.
+    fn get_forest_graph() -> Graph<i32, ()> {
+        let edges = vec![
+            Edge::with_weight(0, 1, 2.0),
+            Edge::with_weight(0, 2, 3.0),
+            Edge::with_weight(0, 3, 2.0),
+            Edge::with_weight(1, 2, 1.0),
+            Edge::with_weight(1, 3, 4.0),
+            Edge::with_weight(2, 3, 2.0),
+            Edge::with_weight(2, 4, 5.0),
+            Edge::with_weight(3, 4, 1.0),
+            Edge::with_weight(3, 5, 3.0),
+            Edge::with_weight(4, 5, 2.0),
+            Edge::with_weight(6, 7, 1.5),
+            Edge::with_weight(6, 8, 1.5),
+            Edge::with_weight(7, 8, 1.5),
+        ];
+        let specs = GraphSpecs::undirected_create_missing();
+        Graph::new_from_nodes_and_edges(vec![], edges, specs).unwrap()
+    }
+
+    /// A weighted graph on `0..40` with two components.
+    fn get_formula_graph() -> Graph<i32, ()> {
+        let mut edges = vec![];
+        for u in 0..40 {
+            for v in (u + 1)..40 {
+                if (u * u + v * 3 + u * v) % 7 < 2 {
+                    edges.push(Edge::with_weight(
+                        u,
+                        v,
+                        ((u * 5 + v * 11) % 13) as f64 / 2.0,
+                    ));
+                }
+            }
+        }
+        let nodes = (0..40).map(Node::from_name).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::undirected()).unwrap()
+    }
+}
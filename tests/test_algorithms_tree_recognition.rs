@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{algorithms::tree, generators, Edge, ErrorKind, Graph, GraphSpecs, Node};
+
+    #[test]
+    fn test_is_tree_1() {
+        let graph = generators::social::karate_club_graph();
+        assert!(!tree::is_tree(&graph).unwrap());
+        assert!(!tree::is_forest(&graph).unwrap());
+        let graph = get_graph(
+            GraphSpecs::undirected(),
+            &[(0, 1), (1, 2), (1, 3), (3, 4)],
+            5,
+        );
+        assert!(tree::is_tree(&graph).unwrap());
+        assert!(tree::is_forest(&graph).unwrap());
+        let graph = get_graph(GraphSpecs::undirected(), &[(0, 1), (1, 2), (3, 4)], 5);
+        assert!(!tree::is_tree(&graph).unwrap());
+        assert!(tree::is_forest(&graph).unwrap());
+        let graph = get_graph(GraphSpecs::undirected(), &[], 1);
+        assert!(tree::is_tree(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_tree_2() {
+        // multiple edges and self-loops are cycles
+        let specs = GraphSpecs {
+            self_loops: true,
+            ..GraphSpecs::multi_undirected()
+        };
+        let graph = get_graph(specs.clone(), &[(0, 1), (1, 2), (1, 2)], 3);
+        assert!(!tree::is_tree(&graph).unwrap());
+        assert!(!tree::is_forest(&graph).unwrap());
+        let graph = get_graph(specs, &[(0, 1), (1, 2), (2, 2)], 3);
+        assert!(!tree::is_forest(&graph).unwrap());
+        let graph = get_graph(GraphSpecs::undirected(), &[], 0);
+        let result = tree::is_tree(&graph);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+    }
+
+    #[test]
+    fn test_is_arborescence_1() {
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (0, 2), (2, 3), (2, 4)], 5);
+        assert!(tree::is_tree(&graph).unwrap());
+        assert!(tree::is_arborescence(&graph).unwrap());
+        assert!(tree::is_branching(&graph).unwrap());
+        // a tree, ignoring direction, but node 3 has two incoming edges
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (0, 2), (2, 3), (4, 3)], 5);
+        assert!(tree::is_tree(&graph).unwrap());
+        assert!(!tree::is_arborescence(&graph).unwrap());
+        assert!(!tree::is_branching(&graph).unwrap());
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (2, 3), (2, 4)], 5);
+        assert!(!tree::is_arborescence(&graph).unwrap());
+        assert!(tree::is_branching(&graph).unwrap());
+        // a directed cycle
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (1, 2), (2, 0)], 3);
+        assert!(!tree::is_branching(&graph).unwrap());
+        // two edges in opposite directions form a cycle
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (1, 0)], 2);
+        assert!(!tree::is_forest(&graph).unwrap());
+        let graph = get_graph(GraphSpecs::undirected(), &[(0, 1)], 2);
+        let result = tree::is_arborescence(&graph);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::WrongMethod));
+    }
+
+    fn get_graph(specs: GraphSpecs, edges: &[(i32, i32)], n: i32) -> Graph<i32, ()> {
+        let nodes = (0..n).map(Node::from_name).collect();
+        let edges = edges.iter().map(|(u, v)| Edge::new(*u, *v)).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+
+    use graphrs::{
+        algorithms::tree::{NestedTree, RootedTree},
+        generators, Edge, ErrorKind, Graph, GraphSpecs, Node,
+    };
+
+    #[test]
+    fn test_rooted_tree_1() {
+        let graph = get_graph(
+            GraphSpecs::undirected(),
+            &[(0, 1), (1, 2), (1, 3), (3, 4)],
+            5,
+        );
+        let tree = RootedTree::from_graph(&graph, 1).unwrap();
+        assert_eq!(*tree.root(), 1);
+        assert_eq!(tree.number_of_nodes(), 5);
+        assert_eq!(tree.parent(&1).unwrap(), None);
+        assert_eq!(tree.parent(&4).unwrap(), Some(3));
+        assert_eq!(tree.children(&1).unwrap(), vec![0, 2, 3]);
+        assert_eq!(tree.depth(&4).unwrap(), 2);
+        assert_eq!(tree.subtree_size(&1).unwrap(), 5);
+        assert_eq!(tree.subtree_size(&3).unwrap(), 2);
+        let parents = tree.parents();
+        assert_eq!(parents.len(), 5);
+        assert_eq!(parents[&0], Some(1));
+        assert_eq!(parents[&1], None);
+        assert_eq!(tree.lowest_common_ancestor(&0, &4).unwrap(), 1);
+        assert_eq!(tree.lowest_common_ancestor(&4, &3).unwrap(), 3);
+        assert_eq!(tree.lowest_common_ancestor(&2, &2).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_rooted_tree_2() {
+        // compare the lowest common ancestors with walking up the tree
+        let n = 300;
+        let edges: Vec<(i32, i32)> = (1..n).map(|v| ((v * 7919 + 13) % v, v)).collect();
+        let graph = get_graph(GraphSpecs::directed(), &edges, n);
+        let tree = RootedTree::from_graph(&graph, 0).unwrap();
+        for u in (0..n).step_by(7) {
+            for v in (0..n).step_by(11) {
+                let lca = tree.lowest_common_ancestor(&u, &v).unwrap();
+                assert_eq!(lca, get_lca_by_walking(&tree, u, v));
+            }
+        }
+        let sizes: usize = (0..n).map(|v| tree.subtree_size(&v).unwrap()).sum();
+        let depths: usize = (0..n).map(|v| tree.depth(&v).unwrap()).sum();
+        assert_eq!(sizes, depths + n as usize);
+    }
+
+    #[test]
+    fn test_rooted_tree_3() {
+        let graph = get_graph(GraphSpecs::undirected(), &[], 1);
+        let tree = RootedTree::from_graph(&graph, 0).unwrap();
+        assert_eq!(tree.lowest_common_ancestor(&0, &0).unwrap(), 0);
+        assert_eq!(
+            tree.to_nested(),
+            NestedTree {
+                name: 0,
+                children: vec![]
+            }
+        );
+        assert!(matches!(
+            tree.depth(&1).unwrap_err().kind,
+            ErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_rooted_tree_errors() {
+        let graph = generators::social::karate_club_graph();
+        let result = RootedTree::from_graph(&graph, 0);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        // an arborescence rooted at 0, not 2
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 1), (0, 2)], 3);
+        let result = RootedTree::from_graph(&graph, 2);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ErrorKind::InvalidArgument
+        ));
+        let result = RootedTree::from_graph(&graph, 3);
+        assert!(matches!(result.unwrap_err().kind, ErrorKind::NodeNotFound));
+    }
+
+    #[test]
+    fn test_to_nested_1() {
+        let graph = get_graph(GraphSpecs::directed(), &[(0, 2), (0, 1), (2, 3)], 4);
+        let tree = RootedTree::from_graph(&graph, 0).unwrap();
+        let leaf = |name| NestedTree {
+            name,
+            children: vec![],
+        };
+        let expected = NestedTree {
+            name: 0,
+            children: vec![
+                leaf(1),
+                NestedTree {
+                    name: 2,
+                    children: vec![leaf(3)],
+                },
+            ],
+        };
+        assert_eq!(tree.to_nested(), expected);
+    }
+
+    #[test]
+    fn test_to_nested_2() {
+        // a path deep enough to overflow the stack if the tree were built recursively
+        let n = 100_000;
+        let edges: Vec<(i32, i32)> = (1..n).map(|v| (v - 1, v)).collect();
+        let graph = get_graph(GraphSpecs::directed(), &edges, n);
+        let tree = RootedTree::from_graph(&graph, 0).unwrap();
+        assert_eq!(tree.depth(&(n - 1)).unwrap(), (n - 1) as usize);
+        assert_eq!(tree.lowest_common_ancestor(&(n - 1), &5).unwrap(), 5);
+        let mut nested = tree.to_nested();
+        let mut depth = 0;
+        while let Some(child) = nested.children.pop() {
+            nested = child;
+            depth += 1;
+        }
+        assert_eq!(depth, n - 1);
+    }
+
+    fn get_lca_by_walking(tree: &RootedTree<i32>, u: i32, v: i32) -> i32 {
+        let (mut u, mut v) = (u, v);
+        while tree.depth(&u).unwrap() > tree.depth(&v).unwrap() {
+            u = tree.parent(&u).unwrap().unwrap();
+        }
+        while tree.depth(&v).unwrap() > tree.depth(&u).unwrap() {
+            v = tree.parent(&v).unwrap().unwrap();
+        }
+        while u != v {
+            u = tree.parent(&u).unwrap().unwrap();
+            v = tree.parent(&v).unwrap().unwrap();
+        }
+        u
+    }
+
+    fn get_graph(specs: GraphSpecs, edges: &[(i32, i32)], n: i32) -> Graph<i32, ()> {
+        let nodes = (0..n).map(Node::from_name).collect();
+        let edges = edges.iter().map(|(u, v)| Edge::new(*u, *v)).collect();
+        Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+    }
+}
@@ -1,3 +1,6 @@
+use graphrs::{Edge, Graph, GraphSpecs, Node};
+use std::collections::HashMap;
+
 #[allow(dead_code)]
 
 /// rounds an &64 to a specified number of `decimal_places`
@@ -5,3 +8,72 @@ pub fn round(number: &f64, decimal_places: u32) -> f64 {
     let x = (10.0 as i32).pow(decimal_places) as f64;
     (number * x).round() / x
 }
+
+#[allow(dead_code)]
+
+/// asserts that `result` has a value for each of the nodes `0..expected.len()`, and that the
+/// value of node `i` is `expected[i]` to 8 decimal places
+pub fn assert_values(result: &HashMap<i32, f64>, expected: &[f64]) {
+    assert_eq!(result.len(), expected.len());
+    for (node, value) in expected.iter().enumerate() {
+        assert_eq!(round(&result[&(node as i32)], 8), round(value, 8));
+    }
+}
+
+#[allow(dead_code)]
+
+/// a weighted digraph with the dangling node `8` and the source node `9`
+pub fn get_directed_graph() -> Graph<i32, ()> {
+    let edges = vec![
+        (0, 1, 2.0),
+        (0, 2, 1.0),
+        (0, 3, 3.0),
+        (1, 2, 3.0),
+        (1, 3, 1.0),
+        (2, 0, 2.0),
+        (2, 4, 4.0),
+        (2, 6, 1.0),
+        (3, 4, 2.0),
+        (3, 5, 1.0),
+        (4, 1, 1.0),
+        (4, 5, 2.0),
+        (4, 6, 3.0),
+        (5, 3, 2.0),
+        (5, 6, 1.0),
+        (6, 1, 2.0),
+        (6, 7, 2.0),
+        (7, 5, 3.0),
+        (7, 8, 1.0),
+        (9, 0, 1.0),
+        (9, 7, 2.0),
+    ];
+    let edges = edges
+        .into_iter()
+        .map(|(u, v, weight)| Edge::with_weight(u, v, weight))
+        .collect();
+    let nodes = (0..10).map(Node::from_name).collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, GraphSpecs::directed()).unwrap()
+}
+
+#[allow(dead_code)]
+
+/// a weighted, undirected multigraph on `0..4` with the parallel edges `0-1` of weights `5` and
+/// `1`; with `multi_edges` set to `false` they're a single edge with their total weight of `6`
+pub fn get_parallel_edges_graph(multi_edges: bool) -> Graph<i32, ()> {
+    let mut edges = match multi_edges {
+        true => vec![Edge::with_weight(0, 1, 5.0), Edge::with_weight(0, 1, 1.0)],
+        false => vec![Edge::with_weight(0, 1, 6.0)],
+    };
+    edges.extend([
+        Edge::with_weight(0, 2, 3.0),
+        Edge::with_weight(1, 2, 2.0),
+        Edge::with_weight(1, 3, 4.0),
+        Edge::with_weight(2, 3, 1.0),
+    ]);
+    let specs = match multi_edges {
+        true => GraphSpecs::multi_undirected(),
+        false => GraphSpecs::undirected(),
+    };
+    let nodes = (0..4).map(Node::from_name).collect();
+    Graph::new_from_nodes_and_edges(nodes, edges, specs).unwrap()
+}